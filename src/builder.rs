@@ -0,0 +1,296 @@
+use crate::{
+    cli::Configuration,
+    constant::{CRASH_REPORT_HISTORY, DEFAULT_STACK_SIZE},
+    coverage::Coverage,
+    flags::Flags,
+    host::HostFunctions,
+    profile::Profile,
+    program::Program,
+    register::Register,
+    stack::Stack,
+    utils::{OutputWriter, Writer},
+    Interpreter,
+};
+use std::{
+    collections::{BTreeSet, VecDeque},
+    io::{stderr, stdin, stdout, BufRead, BufReader, Read, Write},
+    time::Duration,
+};
+
+/// Builds an interpreter without going through the command line arguments.
+///
+/// Unless otherwise specified, the interpreter uses a stack of 4 megabytes, prints to stdout,
+/// reads debug actions from stdin and responds to them on stderr.
+pub struct InterpreterBuilder {
+    stack_size: usize,
+    output: Box<dyn Writer>,
+    input: Box<dyn BufRead>,
+    debug_output: Box<dyn Writer>,
+    print_instructions_executed: bool,
+    debug: bool,
+    max_instructions: Option<u64>,
+    timeout: Option<Duration>,
+    profile: bool,
+    coverage: bool,
+}
+
+impl InterpreterBuilder {
+    pub(super) fn new() -> Self {
+        Self {
+            stack_size: DEFAULT_STACK_SIZE,
+            output: Box::new(stdout()),
+            input: Box::new(BufReader::new(stdin())),
+            debug_output: Box::new(stderr()),
+            print_instructions_executed: false,
+            debug: false,
+            max_instructions: None,
+            timeout: None,
+            profile: false,
+            coverage: false,
+        }
+    }
+
+    /// Sets the size of the stack in bytes.
+    pub fn stack_size(mut self, stack_size: usize) -> Self {
+        self.stack_size = stack_size;
+        self
+    }
+
+    /// Sets the output that print instructions write to.
+    pub fn output(mut self, output: impl Write + 'static) -> Self {
+        self.output = Box::new(OutputWriter(output));
+        self
+    }
+
+    /// Sets the output without wrapping it, such that the interpreter can make use of the type of the output.
+    pub(crate) fn writer(mut self, output: Box<dyn Writer>) -> Self {
+        self.output = output;
+        self
+    }
+
+    /// Sets the input that debug actions are read from.
+    pub fn input(mut self, input: impl Read + 'static) -> Self {
+        self.input = Box::new(BufReader::new(input));
+        self
+    }
+
+    /// Sets the output that the responses to debug actions write to.
+    pub fn debug_output(mut self, debug_output: impl Write + 'static) -> Self {
+        self.debug_output = Box::new(OutputWriter(debug_output));
+        self
+    }
+
+    /// Sets the output of debug actions without wrapping it, such that tests can inspect it.
+    #[cfg(test)]
+    pub(crate) fn debug_writer(mut self, debug_output: Box<dyn Writer>) -> Self {
+        self.debug_output = debug_output;
+        self
+    }
+
+    /// Sets whether the amount of executed instructions is printed when the program stops.
+    pub fn print_instructions_executed(mut self, print_instructions_executed: bool) -> Self {
+        self.print_instructions_executed = print_instructions_executed;
+        self
+    }
+
+    /// Sets whether the program is interpreted in debug mode.
+    pub fn debug(mut self, debug: bool) -> Self {
+        self.debug = debug;
+        self
+    }
+
+    /// Sets the maximum amount of instructions the program may execute before it is stopped with an error.
+    pub fn max_instructions(mut self, max_instructions: u64) -> Self {
+        self.max_instructions = Some(max_instructions);
+        self
+    }
+
+    /// Sets the maximum duration the program may run for before it is stopped with an error.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets whether execution counts are recorded, such that a profile of the program can be reported.
+    pub fn profile(mut self, profile: bool) -> Self {
+        self.profile = profile;
+        self
+    }
+
+    /// Sets whether the executed lines and branches are recorded, such that the coverage of the program can be reported.
+    pub fn coverage(mut self, coverage: bool) -> Self {
+        self.coverage = coverage;
+        self
+    }
+
+    pub fn build(self) -> Interpreter {
+        let config = Configuration {
+            print_instructions_executed: self.print_instructions_executed,
+            instructions_executed: 0,
+            output: self.output,
+            input: self.input,
+            debug_output: self.debug_output,
+            debug: self.debug,
+            max_instructions: self.max_instructions,
+            timeout: self.timeout,
+            deadline: None,
+            profile: self.profile.then(Profile::new),
+            coverage: self.coverage.then(Coverage::new),
+        };
+
+        Interpreter {
+            registers: [0; Register::VARIANT_COUNT],
+            program_counter: 1,
+            flags: Flags::new(),
+            running: true,
+            stack: Stack::new(self.stack_size),
+            config,
+            host_functions: HostFunctions::new(),
+            program: Program::default(),
+            breakpoints: BTreeSet::new(),
+            call_stack: Vec::new(),
+            history: VecDeque::with_capacity(CRASH_REPORT_HISTORY),
+        }
+    }
+}
+
+#[cfg(test)]
+mod build {
+    use crate::{
+        error::{ExecuteError, InterpreterError},
+        register::Register,
+        Interpreter,
+    };
+    use std::{
+        cell::RefCell,
+        io::{Result, Write},
+        rc::Rc,
+        time::Duration,
+    };
+
+    /// An output that can be inspected after it has been handed to the interpreter.
+    #[derive(Clone, Default)]
+    struct SharedOutput(Rc<RefCell<Vec<u8>>>);
+
+    impl Write for SharedOutput {
+        fn write(&mut self, buf: &[u8]) -> Result<usize> {
+            self.0.borrow_mut().write(buf)
+        }
+
+        fn flush(&mut self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn output_is_written_to_given_sink() -> std::result::Result<(), InterpreterError> {
+        let output = SharedOutput::default();
+        let mut i = Interpreter::builder()
+            .stack_size(64)
+            .output(output.clone())
+            .print_instructions_executed(true)
+            .build();
+        let program = ["prvb 42", "prnl", "stop"].join("\n");
+        let expected = "42\nInstructions Executed: 3\n";
+
+        i.run(&program)?;
+        let actual = String::from_utf8(output.0.borrow().clone()).unwrap();
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn stack_size_is_used() {
+        let mut i = Interpreter::builder()
+            .stack_size(2)
+            .output(Vec::new())
+            .build();
+        let program = ["pshq 1", "pshb 1", "stop"].join("\n");
+        let expected = Err(InterpreterError::Execute(2, ExecuteError::StackOverflow));
+
+        let actual = i.run(&program);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn debug_actions_are_read_from_given_input() -> std::result::Result<(), InterpreterError> {
+        let mut i = Interpreter::builder()
+            .output(Vec::new())
+            .input("next\nnext\n".as_bytes())
+            .debug_output(Vec::new())
+            .debug(true)
+            .build();
+        let program = ["setb ra 1", "setb rb 2", "setb rc 3", "stop"].join("\n");
+
+        i.run(&program)?;
+
+        assert_eq!(i.register(Register::A), 1);
+        assert_eq!(i.register(Register::B), 2);
+        assert_eq!(i.register(Register::C), 0);
+        assert_eq!(i.program_counter(), 3);
+        assert_eq!(i.instructions_executed(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn max_instructions_stops_runaway_program() {
+        let mut i = Interpreter::builder()
+            .output(Vec::new())
+            .max_instructions(100)
+            .build();
+        let expected = Err(InterpreterError::InstructionLimitReached(1, 100));
+
+        let actual = i.run("jmp 1");
+
+        assert_eq!(actual, expected);
+        assert_eq!(i.instructions_executed(), 100);
+    }
+
+    #[test]
+    fn timeout_stops_runaway_program() {
+        let mut i = Interpreter::builder()
+            .output(Vec::new())
+            .timeout(Duration::ZERO)
+            .build();
+
+        let actual = i.run("jmp 1");
+
+        assert!(matches!(actual, Err(InterpreterError::Timeout(1, _))));
+    }
+
+    #[test]
+    fn profile_records_executed_instructions() -> std::result::Result<(), InterpreterError> {
+        let mut i = Interpreter::builder()
+            .output(Vec::new())
+            .profile(true)
+            .build();
+
+        i.run("setb ra 1\nstop")?;
+        let actual = i.profile().map(|profile| profile.total());
+
+        assert_eq!(actual, Some(2));
+
+        Ok(())
+    }
+
+    #[test]
+    fn coverage_records_executed_lines() -> std::result::Result<(), InterpreterError> {
+        let mut i = Interpreter::builder()
+            .output(Vec::new())
+            .coverage(true)
+            .build();
+
+        i.run("jmp 3\nsetb ra 1\nstop")?;
+        let coverage = i.coverage().expect("interpreter should record coverage");
+
+        assert_eq!(coverage.hits(1), 1);
+        assert_eq!(coverage.hits(2), 0);
+        assert_eq!(coverage.hits(3), 1);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,591 @@
+//! The binary encoding of instructions.
+//!
+//! An instruction is encoded as its opcode followed by its parameters, where sized instructions
+//! encode their size variant as a byte before the parameters. Operands are encoded as a tag byte,
+//! followed by either the index of the register or the immediate value in little endian.
+
+use crate::{
+    constant::{Byte, Half, Quarter, Word},
+    error::BytecodeError,
+    instruction::{
+        Addition, And, BitExtract, BitInsert, BitTest, BitTestAndComplement, BitTestAndReset,
+        BitTestAndSet, ByteSwap, Compare, Discard, Division, DivisionRemainder, Duplicate,
+        Instruction, Jump, LeadingZeros, LoadStack, Multiplication, Not, Or, Peek, Pop, PopCount,
+        PrintFormat, PrintRegister, PrintStack, PrintValue, Push, Relative, Remainder, Set,
+        StoreStack, Subtraction, Swap, Test, TrailingZeros, WideningMultiplication, Xor,
+    },
+    operand::Operand,
+    program::DecodedLine,
+    register::Register,
+};
+
+/// The opcode of a comment line, which is outside the range of the instruction opcodes.
+const COMMENT: Byte = 0xFE;
+
+/// The opcode of a line that could not be decoded, which is followed by the source of the line.
+const INVALID: Byte = 0xFF;
+
+const OPERAND_REGISTER: Byte = 0;
+const OPERAND_IMMEDIATE: Byte = 1;
+
+const JUMPS: [Jump; 11] = [
+    Jump::Unconditional,
+    Jump::IfZero,
+    Jump::IfNotZero,
+    Jump::IfSign,
+    Jump::IfNotSign,
+    Jump::IfOverflow,
+    Jump::IfNotOverflow,
+    Jump::IfGreater,
+    Jump::IfLesser,
+    Jump::IfGreaterOrEqual,
+    Jump::IfLesserOrEqual,
+];
+
+const RELATIVES: [Option<Relative>; 3] = [None, Some(Relative::Positive), Some(Relative::Negative)];
+
+const PRINT_FORMATS: [PrintFormat; 6] = [
+    PrintFormat::Decimal,
+    PrintFormat::Signed,
+    PrintFormat::Hexadecimal,
+    PrintFormat::Binary,
+    PrintFormat::Octal,
+    PrintFormat::Character,
+];
+
+pub trait Encode {
+    fn encode(&self, bytes: &mut Vec<u8>);
+}
+
+pub trait Decode: Sized {
+    fn decode(reader: &mut Reader) -> Result<Self, BytecodeError>;
+}
+
+/// Reads values from the front of the bytecode.
+pub struct Reader<'a>(pub &'a [u8]);
+
+impl<'a> Reader<'a> {
+    pub fn take(&mut self, amount: usize) -> Result<&'a [u8], BytecodeError> {
+        let (taken, rest) = self
+            .0
+            .split_at_checked(amount)
+            .ok_or(BytecodeError::Truncated)?;
+        self.0 = rest;
+
+        Ok(taken)
+    }
+
+    pub fn byte(&mut self) -> Result<Byte, BytecodeError> {
+        Byte::decode(self)
+    }
+
+    pub fn word(&mut self) -> Result<Word, BytecodeError> {
+        Word::decode(self)
+    }
+}
+
+/// Encodes the value as its index in the list of all variants.
+fn encode_variant<T: PartialEq>(variants: &[T], value: &T, bytes: &mut Vec<u8>) {
+    let index = variants
+        .iter()
+        .position(|variant| variant == value)
+        .expect("all variants should be listed");
+    bytes.push(index as Byte);
+}
+
+/// Decodes a value from its index in the list of all variants.
+fn decode_variant<T: Clone>(variants: &[T], reader: &mut Reader) -> Result<T, BytecodeError> {
+    let index = reader.byte()?;
+    variants
+        .get(index as usize)
+        .cloned()
+        .ok_or(BytecodeError::InvalidValue(index))
+}
+
+macro_rules! integer {
+    ($($t:ty),+) => {
+        $(
+            impl Encode for $t {
+                fn encode(&self, bytes: &mut Vec<u8>) {
+                    bytes.extend_from_slice(&self.to_le_bytes());
+                }
+            }
+
+            impl Decode for $t {
+                fn decode(reader: &mut Reader) -> Result<Self, BytecodeError> {
+                    let bytes = reader.take(size_of::<$t>())?;
+                    let bytes = bytes.try_into().expect("exactly the size of the type is taken");
+
+                    Ok(<$t>::from_le_bytes(bytes))
+                }
+            }
+        )+
+    };
+}
+
+integer!(Byte, Quarter, Half, Word);
+
+impl Encode for Register {
+    fn encode(&self, bytes: &mut Vec<u8>) {
+        encode_variant(&Register::ALL, self, bytes);
+    }
+}
+
+impl Decode for Register {
+    fn decode(reader: &mut Reader) -> Result<Self, BytecodeError> {
+        decode_variant(&Register::ALL, reader)
+    }
+}
+
+impl Encode for Jump {
+    fn encode(&self, bytes: &mut Vec<u8>) {
+        encode_variant(&JUMPS, self, bytes);
+    }
+}
+
+impl Decode for Jump {
+    fn decode(reader: &mut Reader) -> Result<Self, BytecodeError> {
+        decode_variant(&JUMPS, reader)
+    }
+}
+
+impl Encode for Option<Relative> {
+    fn encode(&self, bytes: &mut Vec<u8>) {
+        encode_variant(&RELATIVES, self, bytes);
+    }
+}
+
+impl Decode for Option<Relative> {
+    fn decode(reader: &mut Reader) -> Result<Self, BytecodeError> {
+        decode_variant(&RELATIVES, reader)
+    }
+}
+
+impl Encode for PrintFormat {
+    fn encode(&self, bytes: &mut Vec<u8>) {
+        encode_variant(&PRINT_FORMATS, self, bytes);
+    }
+}
+
+impl Decode for PrintFormat {
+    fn decode(reader: &mut Reader) -> Result<Self, BytecodeError> {
+        decode_variant(&PRINT_FORMATS, reader)
+    }
+}
+
+impl<T: Encode> Encode for Operand<T> {
+    fn encode(&self, bytes: &mut Vec<u8>) {
+        match self {
+            Operand::Register(register) => {
+                bytes.push(OPERAND_REGISTER);
+                register.encode(bytes);
+            }
+            Operand::Immediate(value) => {
+                bytes.push(OPERAND_IMMEDIATE);
+                value.encode(bytes);
+            }
+        }
+    }
+}
+
+impl<T: Decode> Decode for Operand<T> {
+    fn decode(reader: &mut Reader) -> Result<Self, BytecodeError> {
+        match reader.byte()? {
+            OPERAND_REGISTER => Ok(Operand::Register(Register::decode(reader)?)),
+            OPERAND_IMMEDIATE => Ok(Operand::Immediate(T::decode(reader)?)),
+            unknown => Err(BytecodeError::InvalidValue(unknown)),
+        }
+    }
+}
+
+impl Encode for String {
+    fn encode(&self, bytes: &mut Vec<u8>) {
+        (self.len() as Word).encode(bytes);
+        bytes.extend_from_slice(self.as_bytes());
+    }
+}
+
+impl Decode for String {
+    fn decode(reader: &mut Reader) -> Result<Self, BytecodeError> {
+        let length = reader.word()?;
+        let bytes = reader.take(length as usize)?;
+
+        String::from_utf8(bytes.to_vec()).map_err(|_| BytecodeError::InvalidText)
+    }
+}
+
+/// Implements the encoding of an instruction with a variant for each size,
+/// where the parameters are named only to repeat them for each variant.
+macro_rules! sized {
+    ($name:ident) => {
+        impl Encode for $name {
+            fn encode(&self, bytes: &mut Vec<u8>) {
+                let size = match self {
+                    $name::Byte => 0,
+                    $name::Quarter => 1,
+                    $name::Half => 2,
+                    $name::Word => 3,
+                };
+                bytes.push(size);
+            }
+        }
+
+        impl Decode for $name {
+            fn decode(reader: &mut Reader) -> Result<Self, BytecodeError> {
+                match reader.byte()? {
+                    0 => Ok($name::Byte),
+                    1 => Ok($name::Quarter),
+                    2 => Ok($name::Half),
+                    3 => Ok($name::Word),
+                    unknown => Err(BytecodeError::InvalidValue(unknown)),
+                }
+            }
+        }
+    };
+    ($name:ident($($parameter:ident),+)) => {
+        impl Encode for $name {
+            fn encode(&self, bytes: &mut Vec<u8>) {
+                match self {
+                    $name::Byte($($parameter),+) => {
+                        bytes.push(0);
+                        $($parameter.encode(bytes);)+
+                    }
+                    $name::Quarter($($parameter),+) => {
+                        bytes.push(1);
+                        $($parameter.encode(bytes);)+
+                    }
+                    $name::Half($($parameter),+) => {
+                        bytes.push(2);
+                        $($parameter.encode(bytes);)+
+                    }
+                    $name::Word($($parameter),+) => {
+                        bytes.push(3);
+                        $($parameter.encode(bytes);)+
+                    }
+                }
+            }
+        }
+
+        impl Decode for $name {
+            fn decode(reader: &mut Reader) -> Result<Self, BytecodeError> {
+                match reader.byte()? {
+                    0 => Ok($name::Byte($(sized!(@decode $parameter, reader)),+)),
+                    1 => Ok($name::Quarter($(sized!(@decode $parameter, reader)),+)),
+                    2 => Ok($name::Half($(sized!(@decode $parameter, reader)),+)),
+                    3 => Ok($name::Word($(sized!(@decode $parameter, reader)),+)),
+                    unknown => Err(BytecodeError::InvalidValue(unknown)),
+                }
+            }
+        }
+    };
+    (@decode $parameter:ident, $reader:ident) => {
+        Decode::decode($reader)?
+    };
+}
+
+sized!(Addition(register, operand));
+sized!(And(register, operand));
+sized!(BitExtract(register, start, length));
+sized!(BitInsert(register, operand, start, length));
+sized!(BitTest(operand, index));
+sized!(BitTestAndComplement(register, index));
+sized!(BitTestAndReset(register, index));
+sized!(BitTestAndSet(register, index));
+sized!(ByteSwap(register));
+sized!(Compare(lhs, rhs));
+sized!(Discard);
+sized!(Division(register, operand));
+sized!(DivisionRemainder(quotient, remainder, operand));
+sized!(Duplicate);
+sized!(LeadingZeros(register));
+sized!(LoadStack(register, offset));
+sized!(Multiplication(register, operand));
+sized!(Not(register));
+sized!(Or(register, operand));
+sized!(Peek(register));
+sized!(Pop(register));
+sized!(PopCount(register));
+sized!(PrintRegister(register));
+sized!(PrintValue(operand, format));
+sized!(Push(operand));
+sized!(Remainder(register, operand));
+sized!(Set(register, operand));
+sized!(StoreStack(offset, operand));
+sized!(Subtraction(register, operand));
+sized!(Swap);
+sized!(Test(lhs, rhs));
+sized!(TrailingZeros(register));
+sized!(WideningMultiplication(high, low, operand));
+sized!(Xor(register, operand));
+
+/// The size variant of printing a string, which is only available when printing from the stack.
+const PRINT_STACK_STR: Byte = 4;
+
+impl Encode for PrintStack {
+    fn encode(&self, bytes: &mut Vec<u8>) {
+        let (size, amount) = match self {
+            PrintStack::Byte(amount) => (0, amount),
+            PrintStack::Quarter(amount) => (1, amount),
+            PrintStack::Half(amount) => (2, amount),
+            PrintStack::Word(amount) => (3, amount),
+            PrintStack::Str(amount) => (PRINT_STACK_STR, amount),
+        };
+
+        bytes.push(size);
+        amount.encode(bytes);
+    }
+}
+
+impl Decode for PrintStack {
+    fn decode(reader: &mut Reader) -> Result<Self, BytecodeError> {
+        let variant = match reader.byte()? {
+            0 => PrintStack::Byte,
+            1 => PrintStack::Quarter,
+            2 => PrintStack::Half,
+            3 => PrintStack::Word,
+            PRINT_STACK_STR => PrintStack::Str,
+            unknown => return Err(BytecodeError::InvalidValue(unknown)),
+        };
+
+        Ok(variant(Operand::decode(reader)?))
+    }
+}
+
+/// Implements the encoding of [`Instruction`], where each variant is assigned an opcode.
+///
+/// Opcodes must never be reassigned, as that would change the meaning of existing bytecode.
+macro_rules! opcodes {
+    ($($opcode:literal => $variant:ident $(($($parameter:ident),+))?),+ $(,)?) => {
+        impl Encode for Instruction {
+            fn encode(&self, bytes: &mut Vec<u8>) {
+                match self {
+                    $(
+                        Instruction::$variant $(($($parameter),+))? => {
+                            bytes.push($opcode);
+                            $($($parameter.encode(bytes);)+)?
+                        }
+                    )+
+                }
+            }
+        }
+
+        impl Decode for Instruction {
+            fn decode(reader: &mut Reader) -> Result<Self, BytecodeError> {
+                match reader.byte()? {
+                    $(
+                        $opcode => Ok(Instruction::$variant $(($(sized!(@decode $parameter, reader)),+))?),
+                    )+
+                    unknown => Err(BytecodeError::UnknownOpcode(unknown)),
+                }
+            }
+        }
+    };
+}
+
+opcodes! {
+    0 => Addition(instruction),
+    1 => And(instruction),
+    2 => BitExtract(instruction),
+    3 => BitInsert(instruction),
+    4 => BitTest(instruction),
+    5 => BitTestAndComplement(instruction),
+    6 => BitTestAndReset(instruction),
+    7 => BitTestAndSet(instruction),
+    8 => ByteSwap(instruction),
+    9 => LeadingZeros(instruction),
+    10 => PopCount(instruction),
+    11 => TrailingZeros(instruction),
+    12 => Call(destination),
+    13 => Compare(instruction),
+    14 => ConditionalMove(condition, instruction),
+    15 => Discard(instruction),
+    16 => Division(instruction),
+    17 => DivisionRemainder(instruction),
+    18 => Duplicate(instruction),
+    19 => GetProgramCounter(register),
+    20 => GetStackPointer(register),
+    21 => HostCall(name),
+    22 => Jump(condition, destination, relative),
+    23 => JumpTable(register, base, relative, count),
+    24 => LoadStack(instruction),
+    25 => Multiplication(instruction),
+    26 => Not(instruction),
+    27 => Or(instruction),
+    28 => Peek(instruction),
+    29 => Pop(instruction),
+    30 => PrintRegister(instruction),
+    31 => PrintNewline,
+    32 => PrintSpace,
+    33 => PrintStack(instruction),
+    34 => PrintValue(instruction),
+    35 => Push(instruction),
+    36 => Remainder(instruction),
+    37 => Return,
+    38 => Set(instruction),
+    39 => SetCondition(condition, register),
+    40 => SetStackPointer(pointer),
+    41 => Stop,
+    42 => StoreStack(instruction),
+    43 => Subtraction(instruction),
+    44 => Swap(instruction),
+    45 => Test(instruction),
+    46 => WideningMultiplication(instruction),
+    47 => Xor(instruction),
+}
+
+impl Encode for DecodedLine {
+    fn encode(&self, bytes: &mut Vec<u8>) {
+        match self {
+            DecodedLine::Comment => bytes.push(COMMENT),
+            DecodedLine::Instruction(instruction) => instruction.encode(bytes),
+            DecodedLine::Invalid(source) => {
+                bytes.push(INVALID);
+                source.encode(bytes);
+            }
+        }
+    }
+}
+
+impl Decode for DecodedLine {
+    fn decode(reader: &mut Reader) -> Result<Self, BytecodeError> {
+        match reader.0.first() {
+            Some(&COMMENT) => {
+                reader.byte()?;
+                Ok(DecodedLine::Comment)
+            }
+            Some(&INVALID) => {
+                reader.byte()?;
+                Ok(DecodedLine::Invalid(String::decode(reader)?))
+            }
+            _ => Ok(DecodedLine::Instruction(Instruction::decode(reader)?)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod round_trip {
+    use super::{Decode, Encode, Reader};
+    use crate::{decode::decode, error::BytecodeError, instruction::Instruction};
+    use std::collections::HashSet;
+
+    /// A line for each variant of [`Instruction`], using every kind of parameter.
+    const LINES: [&str; 48] = [
+        "addw ra 18446744073709551615",
+        "andq rb rc",
+        "bexth rc 3 rd",
+        "binsb rd re 1 2",
+        "btw 9 63",
+        "btcb ra 1",
+        "btrq ra rb",
+        "btsh ra 31",
+        "bswapw rh",
+        "clzb rg",
+        "popcntq rf",
+        "ctzh re",
+        "call 12",
+        "cmpb ra 255",
+        "cmovnzq ra rb",
+        "dropw",
+        "divh ra 7",
+        "divremw ra rb rc",
+        "dupq",
+        "getpc ra",
+        "getsp rb",
+        "sys clock",
+        "jle -2",
+        "jtab ra +1 3",
+        "ldsw ra 8",
+        "mulb ra 3",
+        "noth rb",
+        "orw ra rb",
+        "peekq rc",
+        "popb rd",
+        "prrh re",
+        "prnl",
+        "prsp",
+        "prss 5",
+        "prvb ra s",
+        "pshq 65535",
+        "remw ra rb",
+        "ret",
+        "setb ra 1",
+        "setge rc",
+        "setsp rh",
+        "stop",
+        "stsh 4 ra",
+        "subq ra 1",
+        "swapb",
+        "tstw ra 1",
+        "mulxh ra rb rc",
+        "xorb ra ra",
+    ];
+
+    fn round_trip(instruction: &Instruction) -> Result<Instruction, BytecodeError> {
+        let mut bytes = Vec::new();
+        instruction.encode(&mut bytes);
+
+        let mut reader = Reader(&bytes);
+        let decoded = Instruction::decode(&mut reader)?;
+        assert!(reader.0.is_empty(), "all bytes should be decoded");
+
+        Ok(decoded)
+    }
+
+    #[test]
+    fn every_instruction() -> Result<(), BytecodeError> {
+        for line in LINES {
+            let instruction = decode(line).expect("sample line should decode");
+
+            let actual = round_trip(&instruction)?;
+
+            assert_eq!(actual, instruction, "'{line}' did not round trip");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn every_opcode_is_covered() {
+        let opcodes: HashSet<u8> = LINES
+            .iter()
+            .map(|line| {
+                let mut bytes = Vec::new();
+                decode(line)
+                    .expect("sample line should decode")
+                    .encode(&mut bytes);
+                bytes.first().copied().expect("opcode should be encoded")
+            })
+            .collect();
+
+        assert_eq!(opcodes.len(), Instruction::VARIANT_COUNT);
+    }
+
+    #[test]
+    fn unknown_opcode() {
+        let bytes = [200];
+
+        let actual = Instruction::decode(&mut Reader(&bytes));
+
+        assert_eq!(actual, Err(BytecodeError::UnknownOpcode(200)));
+    }
+
+    #[test]
+    fn invalid_register() {
+        // a pop of a byte into the ninth register
+        let bytes = [29, 0, 8];
+
+        let actual = Instruction::decode(&mut Reader(&bytes));
+
+        assert_eq!(actual, Err(BytecodeError::InvalidValue(8)));
+    }
+
+    #[test]
+    fn truncated_immediate() {
+        // a push of a word with only four bytes of the immediate value
+        let bytes = [35, 3, 1, 0, 0, 0, 0];
+
+        let actual = Instruction::decode(&mut Reader(&bytes));
+
+        assert_eq!(actual, Err(BytecodeError::Truncated));
+    }
+}
@@ -0,0 +1,304 @@
+//! Assembly of programs into bytecode, such that they can be executed without being decoded again.
+//!
+//! All values are stored in little endian, in the following order:
+//! - the magic bytes, the format version and the flags
+//! - the checksum of everything that follows it
+//! - the amount of lines followed by the encoding of each line
+//! - if the line table flag is set, the source code of each line followed by the amount of functions
+//!   and the first line and name of each function
+//!
+//! Every line of the preprocessed program is kept, such that line numbers and jump destinations
+//! are the same as when the source code is executed.
+
+use crate::{
+    constant::{Byte, Word},
+    error::BytecodeError,
+    preprocess::preprocess,
+    program::{DecodedLine, Functions, Program},
+    utils::fnv1a,
+    Interpreter, InterpreterError,
+};
+use encoding::{Decode, Encode, Reader};
+
+mod encoding;
+
+const MAGIC: &[u8; 4] = b"KBYC";
+const VERSION: Byte = 1;
+
+/// Set if the source code and functions of the program are embedded, which are used for error reporting.
+const LINE_TABLE: Byte = 0b1;
+
+/// Preprocesses and decodes the source code into bytecode.
+///
+/// Lines that cannot be decoded are kept as is, such that the error occurs if the line is executed,
+/// just like when the source code is executed.
+///
+/// # Errors
+/// Returns an error if the source code could not be preprocessed.
+pub fn assemble(source_code: &str, line_table: bool) -> Result<Vec<u8>, InterpreterError> {
+    let (final_source_code, functions) =
+        preprocess(source_code).map_err(InterpreterError::PreProcess)?;
+
+    let mut body = Vec::new();
+    (final_source_code.len() as Word).encode(&mut body);
+    for line in &final_source_code {
+        DecodedLine::new(line).encode(&mut body);
+    }
+
+    if line_table {
+        for line in &final_source_code {
+            line.encode(&mut body);
+        }
+
+        let mut functions: Vec<_> = functions.into_iter().collect();
+        functions.sort();
+        (functions.len() as Word).encode(&mut body);
+        for (line, name) in functions {
+            (line as Word).encode(&mut body);
+            name.to_string().encode(&mut body);
+        }
+    }
+
+    let mut bytes = Vec::with_capacity(MAGIC.len() + 10 + body.len());
+    bytes.extend_from_slice(MAGIC);
+    bytes.push(VERSION);
+    bytes.push(if line_table { LINE_TABLE } else { 0 });
+    fnv1a(&body).encode(&mut bytes);
+    bytes.extend_from_slice(&body);
+
+    Ok(bytes)
+}
+
+/// Returns true if the bytes start with the magic bytes of bytecode.
+pub fn is_bytecode(bytes: &[u8]) -> bool {
+    bytes.starts_with(MAGIC)
+}
+
+impl Interpreter {
+    /// Loads assembled bytecode as the program, such that it can be executed like loaded source code.
+    ///
+    /// # Errors
+    /// Returns an error if the bytecode is malformed or was assembled with an unsupported format version.
+    /// The interpreter is left untouched in case of an error.
+    pub fn load_bytecode(&mut self, bytecode: &[u8]) -> Result<(), InterpreterError> {
+        let program = read(bytecode).map_err(InterpreterError::Bytecode)?;
+        self.start(program);
+
+        Ok(())
+    }
+}
+
+fn read(bytecode: &[u8]) -> Result<Program, BytecodeError> {
+    let mut reader = Reader(bytecode);
+
+    if reader.take(MAGIC.len())? != MAGIC {
+        return Err(BytecodeError::InvalidMagic);
+    }
+
+    let version = reader.byte()?;
+    if version != VERSION {
+        return Err(BytecodeError::UnsupportedVersion(version));
+    }
+
+    let flags = reader.byte()?;
+    let checksum = reader.word()?;
+    if fnv1a(reader.0) != checksum {
+        return Err(BytecodeError::ChecksumMismatch);
+    }
+
+    let line_count = reader.word()?;
+    let start = reader.0;
+    let mut lines = Vec::new();
+    for _ in 0..line_count {
+        lines.push(DecodedLine::decode(&mut reader)?);
+    }
+    let entries = start
+        .get(..start.len() - reader.0.len())
+        .expect("the entries are a prefix of the remaining bytes");
+    let program_checksum = fnv1a(entries);
+
+    let (source, functions) = if flags & LINE_TABLE != 0 {
+        let mut source = Vec::new();
+        for _ in 0..line_count {
+            source.push(String::decode(&mut reader)?);
+        }
+
+        let function_count = reader.word()?;
+        let mut functions = Functions::new();
+        for _ in 0..function_count {
+            let line = reader.word()? as usize;
+            let name = String::decode(&mut reader)?;
+            functions.insert(line, name.into());
+        }
+
+        (Some(source.into_boxed_slice()), functions)
+    } else {
+        (None, Functions::new())
+    };
+
+    if !reader.0.is_empty() {
+        return Err(BytecodeError::TrailingBytes);
+    }
+
+    Ok(Program::decoded(
+        lines.into_boxed_slice(),
+        source,
+        functions,
+        program_checksum,
+    ))
+}
+
+#[cfg(test)]
+mod load {
+    use super::{assemble, is_bytecode};
+    use crate::{
+        error::{BytecodeError, DecodeError, InterpreterError},
+        register::Register,
+        Interpreter,
+    };
+
+    fn program() -> String {
+        [
+            "// sums the numbers from one to five",
+            "setb ra 5",
+            "call add_ra",
+            "subb ra 1",
+            "jnz -2",
+            "prrb rb",
+            "stop",
+            "fn add_ra:",
+            "addb rb ra",
+            "ret",
+        ]
+        .join("\n")
+    }
+
+    #[test]
+    fn runs_like_source_code() -> Result<(), InterpreterError> {
+        let bytecode = assemble(&program(), true)?;
+        let mut i = Interpreter::new_test();
+
+        i.load_bytecode(&bytecode)?;
+        i.run_loaded()?;
+
+        assert_eq!(i.register(Register::B), 15);
+        assert_eq!(i.program.get(2)?, "setb ra 5");
+        assert_eq!(i.program.function(9).as_deref(), Some("add_ra"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn without_line_table() -> Result<(), InterpreterError> {
+        let with_line_table = assemble(&program(), true)?;
+        let bytecode = assemble(&program(), false)?;
+        let mut i = Interpreter::new_test();
+
+        i.load_bytecode(&bytecode)?;
+        i.run_loaded()?;
+
+        assert!(bytecode.len() < with_line_table.len());
+        assert_eq!(i.register(Register::B), 15);
+        assert_eq!(i.program.get(1)?, "//");
+        assert_eq!(i.program.get(3)?, "call 9");
+        assert_eq!(i.program.function(9), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn decode_error_occurs_when_executed() -> Result<(), InterpreterError> {
+        let program = ["jmp 3", "setb rz 1", "setb rb 1", "setb rz 1"].join("\n");
+        let bytecode = assemble(&program, false)?;
+        let mut i = Interpreter::new_test();
+        let expected = Err(InterpreterError::Decode(
+            4,
+            DecodeError::InvalidRegister("rz".to_string()),
+        ));
+
+        i.load_bytecode(&bytecode)?;
+        let actual = i.run_loaded();
+
+        assert_eq!(actual, expected);
+        assert_eq!(i.register(Register::B), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn is_detected() -> Result<(), InterpreterError> {
+        let bytecode = assemble(&program(), true)?;
+
+        assert!(is_bytecode(&bytecode));
+        assert!(!is_bytecode(program().as_bytes()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_magic() {
+        let mut i = Interpreter::new_test();
+        let expected = Err(InterpreterError::Bytecode(BytecodeError::InvalidMagic));
+
+        let actual = i.load_bytecode(program().as_bytes());
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn unsupported_version() -> Result<(), InterpreterError> {
+        let mut bytecode = assemble(&program(), true)?;
+        *bytecode.get_mut(4).expect("bytecode should have a version") = 200;
+        let mut i = Interpreter::new_test();
+        let expected = Err(InterpreterError::Bytecode(
+            BytecodeError::UnsupportedVersion(200),
+        ));
+
+        let actual = i.load_bytecode(&bytecode);
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn checksum_mismatch() -> Result<(), InterpreterError> {
+        let mut bytecode = assemble(&program(), true)?;
+        *bytecode.last_mut().expect("bytecode should not be empty") ^= 1;
+        let mut i = Interpreter::new_test();
+        let expected = Err(InterpreterError::Bytecode(BytecodeError::ChecksumMismatch));
+
+        let actual = i.load_bytecode(&bytecode);
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn truncated() -> Result<(), InterpreterError> {
+        let bytecode = assemble(&program(), true)?;
+        let mut i = Interpreter::new_test();
+        let expected = Err(InterpreterError::Bytecode(BytecodeError::Truncated));
+
+        let actual = i.load_bytecode(bytecode.get(..8).expect("bytecode should have a header"));
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn failed_load_keeps_program() -> Result<(), InterpreterError> {
+        let mut i = Interpreter::new_test();
+        i.load("setb ra 1\nstop")?;
+
+        let actual = i.load_bytecode(b"KBYC");
+        i.run_loaded()?;
+
+        assert!(actual.is_err());
+        assert_eq!(i.register(Register::A), 1);
+
+        Ok(())
+    }
+}
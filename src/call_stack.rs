@@ -0,0 +1,121 @@
+//! Tracking of the active function calls, independent of the return addresses on the stack.
+
+use crate::{constant::Word, Interpreter};
+use std::{fmt::Display, sync::Arc};
+
+/// A call that has not yet returned.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CallFrame {
+    /// The name of the called function, if the destination of the call is the start of a function.
+    pub function: Option<Arc<str>>,
+
+    /// The line the call was made from.
+    pub call_site: usize,
+
+    /// The line the call transferred control to.
+    pub entry: Word,
+
+    /// The stack pointer at the start of the call, which is just above the pushed return address.
+    ///
+    /// The function may move its return address, such as when it removes its arguments from the stack.
+    pub stack_pointer: usize,
+}
+
+impl CallFrame {
+    /// The return address pushed onto the stack by the call.
+    pub fn return_address(&self) -> Word {
+        self.call_site as Word + 1
+    }
+}
+
+/// The active calls, rendered from the innermost to the outermost through its [`Display`] implementation.
+pub struct Backtrace<'a> {
+    frames: &'a [CallFrame],
+    line: usize,
+}
+
+impl Interpreter {
+    /// Gets the calls that have not yet returned, with the innermost call last.
+    pub fn call_stack(&self) -> &[CallFrame] {
+        &self.call_stack
+    }
+
+    /// Gets the backtrace of the active calls, with the innermost frame being at the specified line.
+    pub fn backtrace(&self, line: usize) -> Backtrace<'_> {
+        Backtrace {
+            frames: &self.call_stack,
+            line,
+        }
+    }
+}
+
+impl Display for Backtrace<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut line = self.line;
+
+        // each frame is the function of the current line, and the call site is the line of the frame below
+        for (depth, frame) in self.frames.iter().rev().enumerate() {
+            let function = frame.function.as_deref().unwrap_or("?");
+            writeln!(f, "  #{depth} line {line} in {function}")?;
+            line = frame.call_site;
+        }
+
+        writeln!(f, "  #{} line {line}", self.frames.len())
+    }
+}
+
+#[cfg(test)]
+mod backtrace {
+    use crate::{error::InterpreterError, Interpreter};
+
+    #[test]
+    fn nested_calls() -> Result<(), InterpreterError> {
+        let mut i = Interpreter::new_test();
+        let program = [
+            "call outer",
+            "stop",
+            "fn outer:",
+            "call inner",
+            "ret",
+            "fn inner:",
+            "stop",
+        ]
+        .join("\n");
+        let expected = "  #0 line 7 in inner\n  #1 line 4 in outer\n  #2 line 1\n";
+
+        i.run(&program)?;
+        let actual = i.backtrace(7).to_string();
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn call_to_line_without_function() -> Result<(), InterpreterError> {
+        let mut i = Interpreter::new_test();
+        let program = ["call 2", "stop"].join("\n");
+        let expected = "  #0 line 2 in ?\n  #1 line 1\n";
+
+        i.run(&program)?;
+        let actual = i.backtrace(2).to_string();
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn outside_of_any_call() -> Result<(), InterpreterError> {
+        let mut i = Interpreter::new_test();
+        let program = "stop";
+        let expected = "  #0 line 1\n";
+
+        i.run(program)?;
+        let actual = i.backtrace(1).to_string();
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
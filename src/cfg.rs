@@ -0,0 +1,735 @@
+//! Control flow graph of the loaded program, made of basic blocks and the transfers of control between them.
+//!
+//! A basic block is a sequence of instructions that is only entered at its first instruction, and only left after
+//! its last one. Comments do not belong to any block, and lines that cannot be decoded end the block before them.
+//! Transfers of control to a register cannot be resolved, so they have no edges.
+
+use crate::{
+    constant::Word,
+    disassembly::destination_line,
+    instruction::{Instruction, Jump, Relative},
+    operand::Operand,
+    program::{DecodedLine, Program},
+    Interpreter,
+};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Display,
+};
+
+/// The basic blocks of a program, the edges between them, and the functions they are grouped into.
+pub struct ControlFlowGraph {
+    lines: Vec<DecodedLine>,
+    blocks: Vec<Block>,
+    edges: Vec<Edge>,
+    functions: Vec<Function>,
+}
+
+pub struct Block {
+    /// The instructions of the block by their line, in the order they are executed.
+    pub instructions: Vec<(usize, Instruction)>,
+
+    /// The index of the function the block belongs to, or none if it precedes every function.
+    pub function: Option<usize>,
+}
+
+pub struct Edge {
+    /// The index of the block control is transferred from.
+    pub from: usize,
+
+    /// The index of the block control is transferred to.
+    pub to: usize,
+
+    pub kind: EdgeKind,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum EdgeKind {
+    /// Continuing on the following line, including when a conditional jump is not taken.
+    FallThrough,
+    /// A jump that is taken, by its condition and whether its destination is relative.
+    Jump(Jump, Option<Relative>),
+    /// A jump to an entry of a jump table, by the index of the entry.
+    JumpTable(Word),
+    Call,
+    /// Returning to the line following a call of the function.
+    Return,
+}
+
+/// A function, which is either named in the source code or only known as the destination of a call.
+pub struct Function {
+    /// The name of the function, or the line it starts on if it has no name.
+    pub name: String,
+
+    /// The first line of the function.
+    pub entry: usize,
+}
+
+impl Block {
+    /// Returns the line of the first instruction.
+    pub fn start(&self) -> usize {
+        self.instructions.first().map_or(0, |(line, _)| *line)
+    }
+
+    /// Returns the line of the last instruction.
+    pub fn end(&self) -> usize {
+        self.instructions.last().map_or(0, |(line, _)| *line)
+    }
+
+    /// Returns true if the block ends by transferring control to a register, which has no edges.
+    pub fn has_unknown_destination(&self) -> bool {
+        matches!(
+            self.instructions.last(),
+            Some((
+                _,
+                Instruction::Jump(_, Operand::Register(_), _)
+                    | Instruction::JumpTable(_, Operand::Register(_), _, _)
+                    | Instruction::JumpTable(_, _, _, Operand::Register(_))
+                    | Instruction::Call(Operand::Register(_))
+            ))
+        )
+    }
+}
+
+impl Interpreter {
+    /// Builds the control flow graph of the loaded program.
+    pub fn control_flow_graph(&self) -> ControlFlowGraph {
+        ControlFlowGraph::new(&self.program)
+    }
+}
+
+impl ControlFlowGraph {
+    pub(crate) fn new(program: &Program) -> Self {
+        let lines = program.decoded_lines();
+        let instructions = || {
+            lines
+                .iter()
+                .enumerate()
+                .filter_map(|(index, line)| match line {
+                    DecodedLine::Instruction(instruction) => Some((index + 1, instruction)),
+                    _ => None,
+                })
+        };
+
+        let mut entries: BTreeMap<usize, String> = program
+            .functions()
+            .iter()
+            .map(|(&entry, name)| (entry, name.to_string()))
+            .collect();
+        for (line, instruction) in instructions() {
+            for (destination, kind) in transfers(&lines, line, instruction) {
+                if kind == EdgeKind::Call {
+                    entries
+                        .entry(destination)
+                        .or_insert_with(|| format!("line {destination}"));
+                }
+            }
+        }
+
+        let mut leaders = BTreeSet::new();
+        leaders.extend(resolve(&lines, 1));
+        leaders.extend(entries.keys().filter_map(|&entry| resolve(&lines, entry)));
+        for (line, instruction) in instructions() {
+            let destinations = transfers(&lines, line, instruction);
+            leaders.extend(
+                destinations
+                    .iter()
+                    .filter_map(|(destination, _)| resolve(&lines, *destination)),
+            );
+            if !instruction.increment() {
+                leaders.extend(resolve(&lines, line + 1));
+            }
+        }
+
+        let functions: Vec<Function> = entries
+            .into_iter()
+            .map(|(entry, name)| Function { name, entry })
+            .collect();
+
+        let mut blocks: Vec<Block> = Vec::new();
+        let mut current: Option<Block> = None;
+        for (index, line) in lines.iter().enumerate() {
+            let line_number = index + 1;
+            match line {
+                DecodedLine::Comment => {}
+                DecodedLine::Invalid(_) => blocks.extend(current.take()),
+                DecodedLine::Instruction(instruction) => {
+                    if leaders.contains(&line_number) {
+                        blocks.extend(current.take());
+                    }
+
+                    let function = functions
+                        .iter()
+                        .rposition(|function| function.entry <= line_number);
+                    current
+                        .get_or_insert_with(|| Block {
+                            instructions: Vec::new(),
+                            function,
+                        })
+                        .instructions
+                        .push((line_number, instruction.clone()));
+
+                    if !instruction.increment() {
+                        blocks.extend(current.take());
+                    }
+                }
+            }
+        }
+        blocks.extend(current);
+
+        let block_at = |line: usize| blocks.iter().position(|block| block.start() == line);
+        let calls: Vec<(usize, usize)> = instructions()
+            .flat_map(|(line, instruction)| {
+                transfers(&lines, line, instruction)
+                    .into_iter()
+                    .filter(|(_, kind)| *kind == EdgeKind::Call)
+                    .map(move |(destination, _)| (line, destination))
+            })
+            .collect();
+
+        let mut edges = Vec::new();
+        for (from, block) in blocks.iter().enumerate() {
+            let Some((line, instruction)) = block.instructions.last() else {
+                continue;
+            };
+
+            let mut destinations = destinations(&lines, *line, instruction);
+            if *instruction == Instruction::Return {
+                let entry = block
+                    .function
+                    .and_then(|function| functions.get(function))
+                    .map(|function| function.entry);
+                for (call_site, destination) in &calls {
+                    if Some(*destination) == entry {
+                        destinations.push((call_site + 1, EdgeKind::Return));
+                    }
+                }
+            }
+
+            for (destination, kind) in destinations {
+                if let Some(to) = resolve(&lines, destination).and_then(block_at) {
+                    edges.push(Edge { from, to, kind });
+                }
+            }
+        }
+
+        Self {
+            lines,
+            blocks,
+            edges,
+            functions,
+        }
+    }
+
+    pub fn blocks(&self) -> &[Block] {
+        &self.blocks
+    }
+
+    pub fn edges(&self) -> &[Edge] {
+        &self.edges
+    }
+
+    pub fn functions(&self) -> &[Function] {
+        &self.functions
+    }
+
+    /// Returns the decoded lines of the program the graph is built from.
+    pub(crate) fn lines(&self) -> &[DecodedLine] {
+        &self.lines
+    }
+
+    /// Returns the index of the block that is executed when control is transferred to the line, if any.
+    pub(crate) fn block_at(&self, line: usize) -> Option<usize> {
+        let line = resolve(&self.lines, line)?;
+        self.blocks.iter().position(|block| block.start() == line)
+    }
+
+    /// Gets the graph in the DOT language, where the blocks of each function are grouped into a cluster.
+    pub fn dot(&self) -> Dot<'_> {
+        Dot(self)
+    }
+
+    /// Gets the graph as JSON, with the functions, blocks and edges in separate lists.
+    pub fn json(&self) -> Json<'_> {
+        Json(self)
+    }
+
+    /// Returns the name of the function the block belongs to, if any.
+    fn function_name(&self, block: &Block) -> Option<&str> {
+        let function = self.functions.get(block.function?)?;
+        Some(&function.name)
+    }
+}
+
+/// Returns the lines execution may continue on after the instruction, which includes the following line
+/// unless the instruction always transfers control.
+///
+/// Returns are not included, as their destination depends on the calls of the function.
+pub(crate) fn destinations(
+    lines: &[DecodedLine],
+    line: usize,
+    instruction: &Instruction,
+) -> Vec<(usize, EdgeKind)> {
+    let mut destinations = transfers(lines, line, instruction);
+    if instruction.increment() || instruction.is_conditional_jump() {
+        destinations.push((line + 1, EdgeKind::FallThrough));
+    }
+
+    destinations
+}
+
+/// Returns the lines control may be transferred to by the instruction, other than the following line.
+///
+/// Returns are not included, as their destination depends on the calls of the function.
+fn transfers(
+    lines: &[DecodedLine],
+    line: usize,
+    instruction: &Instruction,
+) -> Vec<(usize, EdgeKind)> {
+    let within = |destination: Word| {
+        (destination != 0 && destination as usize <= lines.len()).then_some(destination as usize)
+    };
+
+    match instruction {
+        Instruction::Jump(condition, destination, relative) => {
+            destination_line(line, destination, relative)
+                .and_then(within)
+                .map(|destination| {
+                    (
+                        destination,
+                        EdgeKind::Jump(condition.clone(), relative.clone()),
+                    )
+                })
+                .into_iter()
+                .collect()
+        }
+        Instruction::JumpTable(_, base, relative, Operand::Immediate(count)) => {
+            let Some(first) = destination_line(line, base, relative) else {
+                return Vec::new();
+            };
+
+            // entries past the end of the program are not included, which bounds large counts
+            (0..*count)
+                .map_while(|index| {
+                    let destination = within(first.checked_add(index)?)?;
+                    Some((destination, EdgeKind::JumpTable(index)))
+                })
+                .collect()
+        }
+        Instruction::Call(Operand::Immediate(destination)) => within(*destination)
+            .map(|destination| (destination, EdgeKind::Call))
+            .into_iter()
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Returns the line of the instruction that is executed when control is transferred to the line,
+/// which skips any comments. Returns none if the line is not part of the program or cannot be decoded.
+fn resolve(lines: &[DecodedLine], line: usize) -> Option<usize> {
+    let start = line.checked_sub(1)?;
+    let offset = lines
+        .get(start..)?
+        .iter()
+        .position(|line| !matches!(line, DecodedLine::Comment))?;
+
+    match lines.get(start + offset)? {
+        DecodedLine::Instruction(_) => Some(start + offset + 1),
+        DecodedLine::Comment | DecodedLine::Invalid(_) => None,
+    }
+}
+
+/// Escapes the text such that it can be used in a string of DOT, which has no escapes for control characters,
+/// so they are written as character references instead.
+fn escape_dot(text: &str) -> String {
+    escape(text, |c| format!("&#{};", c as u32))
+}
+
+/// Escapes the text such that it can be used in a string of JSON.
+fn escape_json(text: &str) -> String {
+    escape(text, |c| format!("\\u{:04x}", c as u32))
+}
+
+/// Escapes quotes, backslashes and newlines the same way in DOT and JSON, and the other control characters
+/// by the escape of the language.
+fn escape(text: &str, control: impl Fn(char) -> String) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            c if c.is_control() => escaped.push_str(&control(c)),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+/// The control flow graph in the DOT language.
+pub struct Dot<'a>(&'a ControlFlowGraph);
+
+impl Display for Dot<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let graph = self.0;
+
+        let write_block = |f: &mut std::fmt::Formatter<'_>, indent: &str, id: usize| {
+            let Some(block) = graph.blocks.get(id) else {
+                return Ok(());
+            };
+
+            write!(f, "{indent}b{id} [label=\"")?;
+            for (line, instruction) in &block.instructions {
+                write!(f, "{line}: {}\\l", escape_dot(&instruction.to_string()))?;
+            }
+            writeln!(f, "\"];")
+        };
+
+        writeln!(f, "digraph cfg {{")?;
+        writeln!(f, "    node [shape=box, fontname=\"monospace\"];")?;
+
+        for (id, block) in graph.blocks.iter().enumerate() {
+            if block.function.is_none() {
+                write_block(f, "    ", id)?;
+            }
+        }
+
+        for (index, function) in graph.functions.iter().enumerate() {
+            writeln!(f, "    subgraph cluster_{index} {{")?;
+            writeln!(f, "        label=\"{}\";", escape_dot(&function.name))?;
+            for (id, block) in graph.blocks.iter().enumerate() {
+                if block.function == Some(index) {
+                    write_block(f, "        ", id)?;
+                }
+            }
+            writeln!(f, "    }}")?;
+        }
+
+        for edge in &graph.edges {
+            let attributes = match &edge.kind {
+                EdgeKind::FallThrough => String::new(),
+                // the jump is the last instruction of the block, which shows the condition and destination
+                EdgeKind::Jump(_, _) => {
+                    let jump = graph
+                        .blocks
+                        .get(edge.from)
+                        .and_then(|block| block.instructions.last())
+                        .map(|(_, instruction)| instruction.to_string())
+                        .unwrap_or_default();
+                    format!(" [label=\"{}\"]", escape_dot(&jump))
+                }
+                EdgeKind::JumpTable(entry) => format!(" [label=\"jtab {entry}\"]"),
+                EdgeKind::Call => " [label=\"call\", style=dashed]".to_string(),
+                EdgeKind::Return => " [label=\"ret\", style=dotted]".to_string(),
+            };
+            writeln!(f, "    b{} -> b{}{attributes};", edge.from, edge.to)?;
+        }
+
+        writeln!(f, "}}")
+    }
+}
+
+/// The control flow graph as JSON.
+pub struct Json<'a>(&'a ControlFlowGraph);
+
+impl Display for Json<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let graph = self.0;
+        let separator = |index: usize, len: usize| if index + 1 < len { "," } else { "" };
+
+        writeln!(f, "{{")?;
+
+        writeln!(f, "  \"functions\": [")?;
+        for (index, function) in graph.functions.iter().enumerate() {
+            writeln!(
+                f,
+                "    {{\"name\": \"{}\", \"entry\": {}}}{}",
+                escape_json(&function.name),
+                function.entry,
+                separator(index, graph.functions.len())
+            )?;
+        }
+        writeln!(f, "  ],")?;
+
+        writeln!(f, "  \"blocks\": [")?;
+        for (id, block) in graph.blocks.iter().enumerate() {
+            let function = match graph.function_name(block) {
+                Some(name) => format!("\"{}\"", escape_json(name)),
+                None => "null".to_string(),
+            };
+            let instructions: Vec<String> = block
+                .instructions
+                .iter()
+                .map(|(line, instruction)| {
+                    format!(
+                        "{{\"line\": {line}, \"instruction\": \"{}\"}}",
+                        escape_json(&instruction.to_string())
+                    )
+                })
+                .collect();
+
+            writeln!(
+                f,
+                "    {{\"id\": {id}, \"function\": {function}, \"start\": {}, \"end\": {}, \"instructions\": [{}]}}{}",
+                block.start(),
+                block.end(),
+                instructions.join(", "),
+                separator(id, graph.blocks.len())
+            )?;
+        }
+        writeln!(f, "  ],")?;
+
+        writeln!(f, "  \"edges\": [")?;
+        for (index, edge) in graph.edges.iter().enumerate() {
+            let kind = match &edge.kind {
+                EdgeKind::FallThrough => "\"kind\": \"fallthrough\"".to_string(),
+                EdgeKind::Jump(condition, relative) => {
+                    let relative = match relative {
+                        Some(Relative::Positive) => "\"positive\"",
+                        Some(Relative::Negative) => "\"negative\"",
+                        None => "null",
+                    };
+                    format!(
+                        "\"kind\": \"jump\", \"jump\": \"{condition}\", \"relative\": {relative}"
+                    )
+                }
+                EdgeKind::JumpTable(entry) => {
+                    format!("\"kind\": \"jump-table\", \"entry\": {entry}")
+                }
+                EdgeKind::Call => "\"kind\": \"call\"".to_string(),
+                EdgeKind::Return => "\"kind\": \"return\"".to_string(),
+            };
+            writeln!(
+                f,
+                "    {{\"from\": {}, \"to\": {}, {kind}}}{}",
+                edge.from,
+                edge.to,
+                separator(index, graph.edges.len())
+            )?;
+        }
+        writeln!(f, "  ]")?;
+
+        writeln!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod graph {
+    use super::EdgeKind;
+    use crate::{
+        bytecode::assemble,
+        error::InterpreterError,
+        instruction::{Jump, Relative},
+        Interpreter,
+    };
+
+    fn program() -> String {
+        [
+            "// sums the numbers from one to five",
+            "setb ra 5",
+            "call add_ra",
+            "subb ra 1",
+            "jnz -2",
+            "stop",
+            "fn add_ra:",
+            "addb rb ra",
+            "ret",
+        ]
+        .join("\n")
+    }
+
+    /// Returns the edges as the start lines of the blocks they connect.
+    fn edges(i: &Interpreter) -> Vec<(usize, usize, EdgeKind)> {
+        let graph = i.control_flow_graph();
+        let start = |id: usize| graph.blocks().get(id).map_or(0, |block| block.start());
+
+        graph
+            .edges()
+            .iter()
+            .map(|edge| (start(edge.from), start(edge.to), edge.kind.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn basic_blocks() -> Result<(), InterpreterError> {
+        let mut i = Interpreter::new_test();
+        i.load(&program())?;
+
+        let graph = i.control_flow_graph();
+        let actual: Vec<(usize, usize, Option<usize>)> = graph
+            .blocks()
+            .iter()
+            .map(|block| (block.start(), block.end(), block.function))
+            .collect();
+
+        let expected = [
+            (2, 2, None),
+            (3, 3, None),
+            (4, 5, None),
+            (6, 6, None),
+            (8, 9, Some(0)),
+        ];
+        assert_eq!(actual, expected);
+        assert_eq!(
+            graph.functions().first().map(|f| f.name.as_str()),
+            Some("add_ra")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn edges_of_calls_and_jumps() -> Result<(), InterpreterError> {
+        let mut i = Interpreter::new_test();
+        i.load(&program())?;
+        let expected = vec![
+            (2, 3, EdgeKind::FallThrough),
+            (3, 8, EdgeKind::Call),
+            (
+                4,
+                3,
+                EdgeKind::Jump(Jump::IfNotZero, Some(Relative::Negative)),
+            ),
+            (4, 6, EdgeKind::FallThrough),
+            (8, 4, EdgeKind::Return),
+        ];
+
+        let actual = edges(&i);
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn jump_into_block_splits_it() -> Result<(), InterpreterError> {
+        let mut i = Interpreter::new_test();
+        i.load(&["setb ra 1", "addb ra 1", "jmp 2"].join("\n"))?;
+        let expected = vec![
+            (1, 2, EdgeKind::FallThrough),
+            (2, 2, EdgeKind::Jump(Jump::Unconditional, None)),
+        ];
+
+        let actual = edges(&i);
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn jump_table() -> Result<(), InterpreterError> {
+        let mut i = Interpreter::new_test();
+        i.load(&["jtab ra +1 2", "stop", "stop"].join("\n"))?;
+        let expected = vec![
+            (1, 2, EdgeKind::JumpTable(0)),
+            (1, 3, EdgeKind::JumpTable(1)),
+        ];
+
+        let actual = edges(&i);
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn bytecode_without_line_table() -> Result<(), InterpreterError> {
+        let mut i = Interpreter::new_test();
+        i.load_bytecode(&assemble(&program(), false)?)?;
+
+        let graph = i.control_flow_graph();
+
+        assert_eq!(
+            graph.functions().first().map(|f| f.name.as_str()),
+            Some("line 8")
+        );
+        assert_eq!(edges(&i).len(), 5);
+
+        Ok(())
+    }
+
+    #[test]
+    fn dot() -> Result<(), InterpreterError> {
+        let mut i = Interpreter::new_test();
+        i.load(&program())?;
+        let expected = [
+            "digraph cfg {",
+            "    node [shape=box, fontname=\"monospace\"];",
+            "    b0 [label=\"2: setb ra 5\\l\"];",
+            "    b1 [label=\"3: call 8\\l\"];",
+            "    b2 [label=\"4: subb ra 1\\l5: jnz -2\\l\"];",
+            "    b3 [label=\"6: stop\\l\"];",
+            "    subgraph cluster_0 {",
+            "        label=\"add_ra\";",
+            "        b4 [label=\"8: addb rb ra\\l9: ret\\l\"];",
+            "    }",
+            "    b0 -> b1;",
+            "    b1 -> b4 [label=\"call\", style=dashed];",
+            "    b2 -> b1 [label=\"jnz -2\"];",
+            "    b2 -> b3;",
+            "    b4 -> b2 [label=\"ret\", style=dotted];",
+            "}",
+            "",
+        ]
+        .join("\n");
+
+        let actual = i.control_flow_graph().dot().to_string();
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn json() -> Result<(), InterpreterError> {
+        let mut i = Interpreter::new_test();
+        i.load(&["setb ra 1", "call inc", "stop", "fn inc:", "jmp +1", "ret"].join("\n"))?;
+        let expected = [
+            "{",
+            "  \"functions\": [",
+            "    {\"name\": \"inc\", \"entry\": 5}",
+            "  ],",
+            "  \"blocks\": [",
+            "    {\"id\": 0, \"function\": null, \"start\": 1, \"end\": 2, \"instructions\": [{\"line\": 1, \"instruction\": \"setb ra 1\"}, {\"line\": 2, \"instruction\": \"call 5\"}]},",
+            "    {\"id\": 1, \"function\": null, \"start\": 3, \"end\": 3, \"instructions\": [{\"line\": 3, \"instruction\": \"stop\"}]},",
+            "    {\"id\": 2, \"function\": \"inc\", \"start\": 5, \"end\": 5, \"instructions\": [{\"line\": 5, \"instruction\": \"jmp +1\"}]},",
+            "    {\"id\": 3, \"function\": \"inc\", \"start\": 6, \"end\": 6, \"instructions\": [{\"line\": 6, \"instruction\": \"ret\"}]}",
+            "  ],",
+            "  \"edges\": [",
+            "    {\"from\": 0, \"to\": 2, \"kind\": \"call\"},",
+            "    {\"from\": 2, \"to\": 3, \"kind\": \"jump\", \"jump\": \"jmp\", \"relative\": \"positive\"},",
+            "    {\"from\": 3, \"to\": 1, \"kind\": \"return\"}",
+            "  ]",
+            "}",
+            "",
+        ]
+        .join("\n");
+
+        let actual = i.control_flow_graph().json().to_string();
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod escapes {
+    use super::{escape_dot, escape_json};
+
+    #[test]
+    fn control_characters_in_dot() {
+        let actual = escape_dot("a\"\\\n\tb\u{7}");
+
+        assert_eq!(actual, "a\\\"\\\\\\n&#9;b&#7;");
+    }
+
+    #[test]
+    fn control_characters_in_json() {
+        let actual = escape_json("a\"\\\n\tb\u{7}");
+
+        assert_eq!(actual, "a\\\"\\\\\\n\\u0009b\\u0007");
+    }
+}
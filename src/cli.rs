@@ -1,34 +1,48 @@
 use crate::{
     constant::{GIGA_BYTE, KILO_BYTE, MEGA_BYTE},
+    coverage::Coverage,
     error::{ArgumentError, InterpreterError},
+    lint::Rule,
+    profile::Profile,
     utils::Writer,
+    Interpreter, InterpreterBuilder,
+};
+use clap::{Parser, Subcommand, ValueEnum};
+use std::{
+    fs::File,
+    io::{stdout, BufRead, Cursor},
+    path::PathBuf,
+    time::{Duration, Instant},
 };
-use clap::Parser;
-use std::{fs::File, io::stdout, path::PathBuf};
 
 pub struct Configuration {
     pub print_instructions_executed: bool,
     pub instructions_executed: u64,
     pub output: Box<dyn Writer>,
+    pub input: Box<dyn BufRead>,
+    /// The output the responses to debug actions are written to, which is kept apart from the output of the program.
+    pub debug_output: Box<dyn Writer>,
     pub debug: bool,
+    pub max_instructions: Option<u64>,
+    pub timeout: Option<Duration>,
+    /// The time the loaded program times out at, which is set once it starts executing.
+    pub deadline: Option<Instant>,
+    pub profile: Option<Profile>,
+    pub coverage: Option<Coverage>,
 }
 
-impl Configuration {
-    #[cfg(test)]
-    pub fn new_test() -> Self {
-        Self {
-            print_instructions_executed: false,
-            instructions_executed: 0,
-            output: Box::new(Vec::new()),
-            debug: false,
-        }
-    }
-}
-
-impl TryFrom<Arguments> for Configuration {
+impl TryFrom<Arguments> for InterpreterBuilder {
     type Error = InterpreterError;
 
     fn try_from(args: Arguments) -> Result<Self, Self::Error> {
+        let stack_size = parse_stack_size(&args.stack_size).map_err(InterpreterError::Argument)?;
+        let timeout = args
+            .timeout
+            .as_deref()
+            .map(parse_duration)
+            .transpose()
+            .map_err(InterpreterError::Argument)?;
+
         let output = match args.output {
             Some(path) => File::options()
                 .create(true)
@@ -40,16 +54,89 @@ impl TryFrom<Arguments> for Configuration {
             None => Box::new(stdout()),
         };
 
-        let c = Self {
-            print_instructions_executed: args.instructions,
-            instructions_executed: 0,
-            output,
-            debug: args.debug,
-        };
-        Ok(c)
+        let mut builder = Interpreter::builder()
+            .stack_size(stack_size)
+            .writer(output)
+            .print_instructions_executed(args.instructions)
+            .debug(args.debug)
+            .profile(args.profile.is_some())
+            .coverage(args.coverage.is_some());
+        if let Some(max_instructions) = args.max_instructions {
+            builder = builder.max_instructions(max_instructions);
+        }
+        if let Some(timeout) = timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(script) = args.script {
+            let actions = std::fs::read(&script).map_err(|err| {
+                let err = match err.kind() {
+                    std::io::ErrorKind::NotFound => ArgumentError::FileNotFound(script),
+                    std::io::ErrorKind::PermissionDenied => ArgumentError::LackingPermissions(
+                        "read the specified script file".to_string(),
+                    ),
+                    _ => ArgumentError::UnknownProgramFileIssue(err.to_string()),
+                };
+                InterpreterError::Argument(err)
+            })?;
+            builder = builder.input(Cursor::new(actions));
+        }
+
+        Ok(builder)
     }
 }
 
+// the run command may be left out, such that `kasm <FILE>` runs the file as it did before there were commands
+#[derive(Debug, Parser, PartialEq)]
+#[command(args_conflicts_with_subcommands = true, arg_required_else_help = true)]
+pub struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    run: Option<Arguments>,
+}
+
+impl Cli {
+    /// Returns the command to carry out, which is the run command if none was given.
+    pub fn command(self) -> Command {
+        match (self.command, self.run) {
+            (Some(command), _) => command,
+            (None, Some(args)) => Command::Run(args),
+            (None, None) => unreachable!("clap requires either a command or the arguments of run"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Subcommand)]
+pub enum Command {
+    /// Runs a program, from either source code or bytecode
+    Run(Arguments),
+
+    /// Assembles a program into bytecode, which can be run without being decoded again
+    Assemble(AssembleArguments),
+
+    /// Prints a program, from either source code or bytecode, as canonical source code with line numbers
+    Disasm(DisasmArguments),
+
+    /// Formats the source code of programs in place
+    Fmt(FmtArguments),
+
+    /// Reports common mistakes in the source code of programs, and fails if there are any
+    Lint(LintArguments),
+
+    /// Prints the control flow graph of a program, from either source code or bytecode
+    Cfg(CfgArguments),
+
+    /// Reports the depth of the stack a program requires, and fails if it can underflow or functions are unbalanced
+    Stack(StackArguments),
+
+    /// Starts an interactive session, where each entered line is executed immediately
+    Repl(ReplArguments),
+
+    /// Runs a program in debug mode, with actions read from stdin or a script and responses written to stderr
+    Debug(DebugArguments),
+}
+
 #[derive(Debug, Parser, PartialEq)]
 pub struct Arguments {
     #[arg(required = true, value_name = "FILE")]
@@ -67,11 +154,171 @@ pub struct Arguments {
     #[arg(long = "debug", short = 'd')]
     debug: bool,
 
+    /// Reads the debug actions from the specified file instead of stdin, one per line
+    #[arg(long = "script", value_name = "FILE", requires = "debug")]
+    script: Option<PathBuf>,
+
+    /// The size of the stack; requires a size suffix: b/B = byte, k/K = kilobyte, m/M = megabyte, g/G = gigabyte
+    #[arg(long = "stack", short = 's', value_name = "SIZE", default_value = "4m")]
+    pub(super) stack_size: String,
+
+    /// Stops the program with an error once it has executed the specified amount of instructions
+    #[arg(long = "max-instructions", value_name = "N")]
+    max_instructions: Option<u64>,
+
+    /// Stops the program with an error if it runs longer than the duration; requires a suffix: ms, s, m or h
+    #[arg(long = "timeout", value_name = "DURATION")]
+    timeout: Option<String>,
+
+    /// Restores the state from the specified snapshot file before running, and resumes execution from there
+    #[arg(long = "resume", value_name = "FILE")]
+    pub resume: Option<PathBuf>,
+
+    /// Writes a snapshot of the state to the specified file, if the program fails during execution
+    #[arg(long = "snapshot-on-error", value_name = "FILE")]
+    pub snapshot_on_error: Option<PathBuf>,
+
+    /// Writes a report of the state to stderr, or the specified file, if the program fails during execution
+    #[arg(long = "crash-report", value_name = "FILE", require_equals = true)]
+    pub crash_report: Option<Option<PathBuf>>,
+
+    /// Writes execution counts per line, mnemonic and function to the specified file, and folded call stacks to the file with a '.folded' suffix
+    #[arg(long = "profile", value_name = "FILE")]
+    pub profile: Option<PathBuf>,
+
+    /// Writes the executed lines and branches to the specified file in the LCOV format, and the annotated source to the file with an '.annotated' suffix
+    #[arg(long = "coverage", value_name = "FILE")]
+    pub coverage: Option<PathBuf>,
+}
+
+#[derive(Debug, Parser, PartialEq)]
+pub struct AssembleArguments {
+    #[arg(required = true, value_name = "FILE")]
+    pub file_name: PathBuf,
+
+    /// Writes the bytecode to the specified file, otherwise the file name with a '.kbc' extension is used
+    #[arg(long = "output", short = 'o', value_name = "FILE")]
+    pub output: Option<PathBuf>,
+
+    /// Leaves out the source code and function names, which are otherwise embedded for error reporting
+    #[arg(long = "no-line-table")]
+    pub no_line_table: bool,
+}
+
+impl AssembleArguments {
+    /// Returns the path the bytecode is written to.
+    pub fn output(&self) -> PathBuf {
+        self.output
+            .clone()
+            .unwrap_or_else(|| self.file_name.with_extension("kbc"))
+    }
+}
+
+#[derive(Debug, Parser, PartialEq)]
+pub struct DisasmArguments {
+    #[arg(required = true, value_name = "FILE")]
+    pub file_name: PathBuf,
+}
+
+#[derive(Debug, Parser, PartialEq)]
+pub struct FmtArguments {
+    #[arg(required = true, value_name = "FILE")]
+    pub file_names: Vec<PathBuf>,
+
+    /// Reports the files that are not formatted without changing them, and fails if there are any
+    #[arg(long = "check")]
+    pub check: bool,
+}
+
+#[derive(Debug, Parser, PartialEq)]
+pub struct LintArguments {
+    #[arg(required = true, value_name = "FILE")]
+    pub file_names: Vec<PathBuf>,
+
+    /// Disables the rule, which may be given multiple times
+    #[arg(long = "disable", value_name = "RULE")]
+    pub disabled: Vec<Rule>,
+}
+
+#[derive(Debug, Parser, PartialEq)]
+pub struct CfgArguments {
+    #[arg(required = true, value_name = "FILE")]
+    pub file_name: PathBuf,
+
+    /// The format the graph is printed in
+    #[arg(long = "format", value_enum, default_value_t = CfgFormat::Dot)]
+    pub format: CfgFormat,
+}
+
+#[derive(Debug, Parser, PartialEq)]
+pub struct StackArguments {
+    #[arg(required = true, value_name = "FILE")]
+    pub file_name: PathBuf,
+
+    /// Also prints each instruction with the depth of the stack before it, relative to the start of its function
+    #[arg(long = "lines")]
+    pub lines: bool,
+}
+
+#[derive(Debug, Parser, PartialEq)]
+pub struct DebugArguments {
+    #[arg(required = true, value_name = "FILE")]
+    pub file_name: PathBuf,
+
+    /// Reads the debug actions from the specified file instead of stdin, one per line
+    #[arg(long = "script", value_name = "FILE")]
+    pub script: Option<PathBuf>,
+
+    /// The size of the stack; requires a size suffix: b/B = byte, k/K = kilobyte, m/M = megabyte, g/G = gigabyte
+    #[arg(long = "stack", short = 's', value_name = "SIZE", default_value = "4m")]
+    pub(super) stack_size: String,
+}
+
+/// Debugging a program is the same as running it in debug mode.
+impl From<DebugArguments> for Arguments {
+    fn from(args: DebugArguments) -> Self {
+        Self {
+            file_name: args.file_name,
+            instructions: false,
+            output: None,
+            debug: true,
+            script: args.script,
+            stack_size: args.stack_size,
+            max_instructions: None,
+            timeout: None,
+            resume: None,
+            snapshot_on_error: None,
+            crash_report: None,
+            profile: None,
+            coverage: None,
+        }
+    }
+}
+
+#[derive(Debug, Parser, PartialEq)]
+pub struct ReplArguments {
     /// The size of the stack; requires a size suffix: b/B = byte, k/K = kilobyte, m/M = megabyte, g/G = gigabyte
     #[arg(long = "stack", short = 's', value_name = "SIZE", default_value = "4m")]
     pub(super) stack_size: String,
 }
 
+impl ReplArguments {
+    /// Returns the size of the stack in bytes.
+    ///
+    /// # Errors
+    /// Returns an error if the size is not a valid stack size.
+    pub fn stack_size(&self) -> Result<usize, ArgumentError> {
+        parse_stack_size(&self.stack_size)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+pub enum CfgFormat {
+    /// The DOT language, which can be rendered by Graphviz
+    Dot,
+    Json,
+}
+
 /// Parses the indicated stack size by the stack size flag.
 ///
 /// Ensures correct format and numeric values for the underlying architecture.
@@ -101,6 +348,30 @@ pub(super) fn parse_stack_size(s: &str) -> Result<usize, ArgumentError> {
     Ok(stack_size)
 }
 
+/// Parses the duration indicated by the timeout flag.
+///
+/// The duration is a whole number followed by a unit suffix, for example `500ms` or `10s`.
+pub(super) fn parse_duration(s: &str) -> Result<Duration, ArgumentError> {
+    let invalid = || ArgumentError::InvalidDuration(s.to_string());
+
+    let split = s.find(|c: char| !c.is_ascii_digit()).ok_or_else(invalid)?;
+    let (num, unit) = s.split_at_checked(split).ok_or_else(invalid)?;
+    let parsed_num = num.parse::<u64>().map_err(|_| invalid())?;
+
+    let seconds_per_unit = match unit {
+        "ms" => return Ok(Duration::from_millis(parsed_num)),
+        "s" => 1,
+        "m" => 60,
+        "h" => 60 * 60,
+        _ => return Err(invalid()),
+    };
+
+    parsed_num
+        .checked_mul(seconds_per_unit)
+        .map(Duration::from_secs)
+        .ok_or_else(invalid)
+}
+
 #[cfg(test)]
 mod regression {
     mod debug {
@@ -117,7 +388,15 @@ mod regression {
                 instructions: false,
                 output: None,
                 debug: false,
+                script: None,
                 stack_size: String::from("4m"),
+                max_instructions: None,
+                timeout: None,
+                resume: None,
+                snapshot_on_error: None,
+                crash_report: None,
+                profile: None,
+                coverage: None,
             };
 
             let actual = Arguments::parse_from(args);
@@ -133,7 +412,15 @@ mod regression {
                 instructions: false,
                 output: None,
                 debug: true,
+                script: None,
                 stack_size: String::from("4m"),
+                max_instructions: None,
+                timeout: None,
+                resume: None,
+                snapshot_on_error: None,
+                crash_report: None,
+                profile: None,
+                coverage: None,
             };
 
             let actual = Arguments::parse_from(args);
@@ -149,7 +436,15 @@ mod regression {
                 instructions: false,
                 output: None,
                 debug: true,
+                script: None,
                 stack_size: String::from("4m"),
+                max_instructions: None,
+                timeout: None,
+                resume: None,
+                snapshot_on_error: None,
+                crash_report: None,
+                profile: None,
+                coverage: None,
             };
 
             let actual = Arguments::parse_from(args);
@@ -172,7 +467,15 @@ mod regression {
                 instructions: false,
                 output: None,
                 debug: false,
+                script: None,
                 stack_size: String::from("4m"),
+                max_instructions: None,
+                timeout: None,
+                resume: None,
+                snapshot_on_error: None,
+                crash_report: None,
+                profile: None,
+                coverage: None,
             };
 
             let actual = Arguments::parse_from(args);
@@ -188,7 +491,15 @@ mod regression {
                 instructions: true,
                 output: None,
                 debug: false,
+                script: None,
                 stack_size: String::from("4m"),
+                max_instructions: None,
+                timeout: None,
+                resume: None,
+                snapshot_on_error: None,
+                crash_report: None,
+                profile: None,
+                coverage: None,
             };
 
             let actual = Arguments::parse_from(args);
@@ -204,7 +515,15 @@ mod regression {
                 instructions: true,
                 output: None,
                 debug: false,
+                script: None,
                 stack_size: String::from("4m"),
+                max_instructions: None,
+                timeout: None,
+                resume: None,
+                snapshot_on_error: None,
+                crash_report: None,
+                profile: None,
+                coverage: None,
             };
 
             let actual = Arguments::parse_from(args);
@@ -227,7 +546,15 @@ mod regression {
                 instructions: false,
                 output: None,
                 debug: false,
+                script: None,
                 stack_size: String::from("4m"),
+                max_instructions: None,
+                timeout: None,
+                resume: None,
+                snapshot_on_error: None,
+                crash_report: None,
+                profile: None,
+                coverage: None,
             };
 
             let actual = Arguments::parse_from(args);
@@ -243,7 +570,15 @@ mod regression {
                 instructions: false,
                 output: Some(PathBuf::from("file.txt")),
                 debug: false,
+                script: None,
                 stack_size: String::from("4m"),
+                max_instructions: None,
+                timeout: None,
+                resume: None,
+                snapshot_on_error: None,
+                crash_report: None,
+                profile: None,
+                coverage: None,
             };
 
             let actual = Arguments::parse_from(args);
@@ -259,13 +594,472 @@ mod regression {
                 instructions: false,
                 output: Some(PathBuf::from("file.txt")),
                 debug: false,
+                script: None,
+                stack_size: String::from("4m"),
+                max_instructions: None,
+                timeout: None,
+                resume: None,
+                snapshot_on_error: None,
+                crash_report: None,
+                profile: None,
+                coverage: None,
+            };
+
+            let actual = Arguments::parse_from(args);
+
+            assert_eq!(actual, expected);
+        }
+    }
+
+    mod max_instructions {
+        use std::path::PathBuf;
+
+        use crate::Arguments;
+        use clap::Parser;
+
+        #[test]
+        fn long() {
+            let args = ["", "file.kasm", "--max-instructions", "1000"];
+            let expected = Arguments {
+                file_name: PathBuf::from("file.kasm"),
+                instructions: false,
+                output: None,
+                debug: false,
+                script: None,
                 stack_size: String::from("4m"),
+                max_instructions: Some(1000),
+                timeout: None,
+                resume: None,
+                snapshot_on_error: None,
+                crash_report: None,
+                profile: None,
+                coverage: None,
             };
 
             let actual = Arguments::parse_from(args);
 
             assert_eq!(actual, expected);
         }
+
+        #[test]
+        fn not_a_number() {
+            let args = ["", "file.kasm", "--max-instructions", "many"];
+
+            let actual = Arguments::try_parse_from(args);
+
+            assert!(actual.is_err());
+        }
+    }
+
+    mod timeout {
+        use std::path::PathBuf;
+
+        use crate::Arguments;
+        use clap::Parser;
+
+        #[test]
+        fn long() {
+            let args = ["", "file.kasm", "--timeout", "10s"];
+            let expected = Arguments {
+                file_name: PathBuf::from("file.kasm"),
+                instructions: false,
+                output: None,
+                debug: false,
+                script: None,
+                stack_size: String::from("4m"),
+                max_instructions: None,
+                timeout: Some(String::from("10s")),
+                resume: None,
+                snapshot_on_error: None,
+                crash_report: None,
+                profile: None,
+                coverage: None,
+            };
+
+            let actual = Arguments::parse_from(args);
+
+            assert_eq!(actual, expected);
+        }
+    }
+
+    mod snapshot {
+        use std::path::PathBuf;
+
+        use crate::Arguments;
+        use clap::Parser;
+
+        #[test]
+        fn resume() {
+            let args = ["", "file.kasm", "--resume", "state.snap"];
+            let expected = Arguments {
+                file_name: PathBuf::from("file.kasm"),
+                instructions: false,
+                output: None,
+                debug: false,
+                script: None,
+                stack_size: String::from("4m"),
+                max_instructions: None,
+                timeout: None,
+                resume: Some(PathBuf::from("state.snap")),
+                snapshot_on_error: None,
+                crash_report: None,
+                profile: None,
+                coverage: None,
+            };
+
+            let actual = Arguments::parse_from(args);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn snapshot_on_error() {
+            let args = ["", "file.kasm", "--snapshot-on-error", "crash.snap"];
+            let expected = Arguments {
+                file_name: PathBuf::from("file.kasm"),
+                instructions: false,
+                output: None,
+                debug: false,
+                script: None,
+                stack_size: String::from("4m"),
+                max_instructions: None,
+                timeout: None,
+                resume: None,
+                snapshot_on_error: Some(PathBuf::from("crash.snap")),
+                crash_report: None,
+                profile: None,
+                coverage: None,
+            };
+
+            let actual = Arguments::parse_from(args);
+
+            assert_eq!(actual, expected);
+        }
+    }
+
+    mod crash_report {
+        use std::path::PathBuf;
+
+        use crate::Arguments;
+        use clap::Parser;
+
+        #[test]
+        fn stderr() {
+            let args = ["", "--crash-report", "file.kasm"];
+
+            let actual = Arguments::parse_from(args);
+
+            assert_eq!(actual.file_name, PathBuf::from("file.kasm"));
+            assert_eq!(actual.crash_report, Some(None));
+        }
+
+        #[test]
+        fn file() {
+            let args = ["", "file.kasm", "--crash-report=crash.txt"];
+
+            let actual = Arguments::parse_from(args);
+
+            assert_eq!(actual.crash_report, Some(Some(PathBuf::from("crash.txt"))));
+        }
+    }
+
+    mod profile {
+        use std::path::PathBuf;
+
+        use crate::Arguments;
+        use clap::Parser;
+
+        #[test]
+        fn profile() {
+            let args = ["", "file.kasm", "--profile", "profile.txt"];
+
+            let actual = Arguments::parse_from(args);
+
+            assert_eq!(actual.profile, Some(PathBuf::from("profile.txt")));
+        }
+    }
+
+    mod coverage {
+        use std::path::PathBuf;
+
+        use crate::Arguments;
+        use clap::Parser;
+
+        #[test]
+        fn coverage() {
+            let args = ["", "run.kasm", "--coverage", "out.lcov"];
+
+            let actual = Arguments::parse_from(args);
+
+            assert_eq!(actual.coverage, Some(PathBuf::from("out.lcov")));
+        }
+    }
+
+    mod command {
+        use std::path::PathBuf;
+
+        use crate::{
+            cli::{CfgFormat, Cli, Command},
+            lint::Rule,
+        };
+        use clap::Parser;
+
+        #[test]
+        fn run() {
+            let args = ["", "run", "file.kasm", "-i"];
+
+            let actual = Cli::parse_from(args);
+
+            let Command::Run(arguments) = actual.command() else {
+                panic!("expected the run command");
+            };
+            assert_eq!(arguments.file_name, PathBuf::from("file.kasm"));
+            assert!(arguments.instructions);
+        }
+
+        #[test]
+        fn assemble() {
+            let args = ["", "assemble", "prog.kasm", "-o", "out.kbc"];
+
+            let actual = Cli::parse_from(args);
+
+            let Command::Assemble(arguments) = actual.command() else {
+                panic!("expected the assemble command");
+            };
+            assert_eq!(arguments.output(), PathBuf::from("out.kbc"));
+            assert!(!arguments.no_line_table);
+        }
+
+        #[test]
+        fn assemble_default_output() {
+            let args = ["", "assemble", "dir/prog.kasm", "--no-line-table"];
+
+            let actual = Cli::parse_from(args);
+
+            let Command::Assemble(arguments) = actual.command() else {
+                panic!("expected the assemble command");
+            };
+            assert_eq!(arguments.output(), PathBuf::from("dir/prog.kbc"));
+            assert!(arguments.no_line_table);
+        }
+
+        #[test]
+        fn disasm() {
+            let args = ["", "disasm", "prog.kbc"];
+
+            let actual = Cli::parse_from(args);
+
+            let Command::Disasm(arguments) = actual.command() else {
+                panic!("expected the disasm command");
+            };
+            assert_eq!(arguments.file_name, PathBuf::from("prog.kbc"));
+        }
+
+        #[test]
+        fn fmt() {
+            let args = ["", "fmt", "a.kasm", "b.kasm", "--check"];
+
+            let actual = Cli::parse_from(args);
+
+            let Command::Fmt(arguments) = actual.command() else {
+                panic!("expected the fmt command");
+            };
+            let expected = [PathBuf::from("a.kasm"), PathBuf::from("b.kasm")];
+            assert_eq!(arguments.file_names, expected);
+            assert!(arguments.check);
+        }
+
+        #[test]
+        fn lint() {
+            let args = [
+                "",
+                "lint",
+                "a.kasm",
+                "--disable",
+                "unused-data",
+                "--disable",
+                "fall-through",
+            ];
+
+            let actual = Cli::parse_from(args);
+
+            let Command::Lint(arguments) = actual.command() else {
+                panic!("expected the lint command");
+            };
+            assert_eq!(arguments.file_names, [PathBuf::from("a.kasm")]);
+            assert_eq!(arguments.disabled, [Rule::UnusedData, Rule::FallThrough]);
+        }
+
+        #[test]
+        fn cfg() {
+            let args = ["", "cfg", "a.kasm", "--format", "json"];
+
+            let actual = Cli::parse_from(args);
+
+            let Command::Cfg(arguments) = actual.command() else {
+                panic!("expected the cfg command");
+            };
+            assert_eq!(arguments.file_name, PathBuf::from("a.kasm"));
+            assert_eq!(arguments.format, CfgFormat::Json);
+        }
+
+        #[test]
+        fn cfg_defaults_to_dot() {
+            let args = ["", "cfg", "a.kasm"];
+
+            let actual = Cli::parse_from(args);
+
+            let Command::Cfg(arguments) = actual.command() else {
+                panic!("expected the cfg command");
+            };
+            assert_eq!(arguments.format, CfgFormat::Dot);
+        }
+
+        #[test]
+        fn stack() {
+            let args = ["", "stack", "a.kasm", "--lines"];
+
+            let actual = Cli::parse_from(args);
+
+            let Command::Stack(arguments) = actual.command() else {
+                panic!("expected the stack command");
+            };
+            assert_eq!(arguments.file_name, PathBuf::from("a.kasm"));
+            assert!(arguments.lines);
+        }
+
+        #[test]
+        fn repl() {
+            let args = ["", "repl", "--stack", "1k"];
+
+            let actual = Cli::parse_from(args);
+
+            let Command::Repl(arguments) = actual.command() else {
+                panic!("expected the repl command");
+            };
+            assert_eq!(arguments.stack_size, "1k");
+        }
+
+        #[test]
+        fn debug_with_script() {
+            let args = ["", "debug", "a.kasm", "--script", "actions.txt"];
+
+            let actual = Cli::parse_from(args);
+
+            let Command::Debug(arguments) = actual.command() else {
+                panic!("expected the debug command");
+            };
+            assert_eq!(arguments.file_name, PathBuf::from("a.kasm"));
+            assert_eq!(arguments.script, Some(PathBuf::from("actions.txt")));
+            assert_eq!(arguments.stack_size, "4m");
+        }
+
+        #[test]
+        fn lint_unknown_rule() {
+            let args = ["", "lint", "a.kasm", "--disable", "unknown-rule"];
+
+            let actual = Cli::try_parse_from(args);
+
+            assert!(actual.is_err());
+        }
+
+        #[test]
+        fn run_is_default_command() {
+            let args = ["", "file.kasm", "--max-instructions", "10"];
+
+            let actual = Cli::parse_from(args);
+
+            let Command::Run(arguments) = actual.command() else {
+                panic!("expected the run command");
+            };
+            assert_eq!(arguments.file_name, PathBuf::from("file.kasm"));
+            assert_eq!(arguments.max_instructions, Some(10));
+        }
+
+        #[test]
+        fn missing_file() {
+            let args = [""];
+
+            let actual = Cli::try_parse_from(args);
+
+            assert!(actual.is_err());
+        }
+    }
+}
+
+#[cfg(test)]
+mod parse_duration {
+    use super::parse_duration;
+    use crate::error::ArgumentError;
+    use std::time::Duration;
+
+    #[test]
+    fn milliseconds() -> Result<(), ArgumentError> {
+        let actual = parse_duration("500ms")?;
+
+        assert_eq!(actual, Duration::from_millis(500));
+        Ok(())
+    }
+
+    #[test]
+    fn seconds() -> Result<(), ArgumentError> {
+        let actual = parse_duration("10s")?;
+
+        assert_eq!(actual, Duration::from_secs(10));
+        Ok(())
+    }
+
+    #[test]
+    fn minutes() -> Result<(), ArgumentError> {
+        let actual = parse_duration("2m")?;
+
+        assert_eq!(actual, Duration::from_secs(120));
+        Ok(())
+    }
+
+    #[test]
+    fn hours() -> Result<(), ArgumentError> {
+        let actual = parse_duration("1h")?;
+
+        assert_eq!(actual, Duration::from_secs(3600));
+        Ok(())
+    }
+
+    #[test]
+    fn missing_unit_error() {
+        let expected = Err(ArgumentError::InvalidDuration("10".to_string()));
+
+        let actual = parse_duration("10");
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn missing_number_error() {
+        let expected = Err(ArgumentError::InvalidDuration("s".to_string()));
+
+        let actual = parse_duration("s");
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn unknown_unit_error() {
+        let expected = Err(ArgumentError::InvalidDuration("10d".to_string()));
+
+        let actual = parse_duration("10d");
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn overflow_error() {
+        let input = format!("{}h", u64::MAX);
+        let expected = Err(ArgumentError::InvalidDuration(input.clone()));
+
+        let actual = parse_duration(&input);
+
+        assert_eq!(actual, expected);
     }
 }
 
@@ -20,6 +20,9 @@ pub const MEGA_BYTE: usize = KILO_BYTE * KILO_BYTE;
 
 pub const GIGA_BYTE: usize = KILO_BYTE * KILO_BYTE * KILO_BYTE;
 
+/// The size of the stack, unless another size is specified.
+pub const DEFAULT_STACK_SIZE: usize = 4 * MEGA_BYTE;
+
 #[cfg(test)]
 pub const TEST_STACK_SIZE: usize = Quarter::MAX as usize;
 
@@ -28,12 +31,51 @@ pub type DecodeTable = Map<&'static str, DecodeFn>;
 
 pub const COMMENT: &str = "//";
 
+/// The prefix of local labels, which are scoped to the function they are defined in.
+pub const LABEL_PREFIX: char = '.';
+
+/// The amount of executed instructions between each check of the timeout.
+pub const TIMEOUT_CHECK_INTERVAL: u64 = 1024;
+
+/// The amount of most recently executed lines included in a crash report.
+pub const CRASH_REPORT_HISTORY: usize = 16;
+
+/// The amount of bytes from the top of the stack included in a crash report.
+pub const CRASH_REPORT_STACK_BYTES: usize = 32;
+
 pub const DEBUG_HELP: &str = r"You can take the following actions:
   - 'next'/'n' will advance the interpretation by one instruction
-  - 'stop'/'s' will stop the interpretation\n
+  - 'continue'/'c' will advance the interpretation until a breakpoint is reached or the program stops
+  - 'break'/'b LINE' will pause the interpretation before the line is executed
+  - 'delete'/'d LINE' will remove the breakpoint at the line
+  - 'registers'/'r' will print the registers
+  - 'flags'/'f' will print the flags
+  - 'stack' will print the stack pointer and the bytes on top of the stack
+  - 'backtrace'/'bt' will print the calls that have not yet returned
+  - 'source FILE' will take the actions in the file, one per line
+  - 'stop'/'s' will stop the interpretation
 ";
 
 pub const DEBUG_INITIAL: &str = r"You are running the program in debug mode.
 Use the 'help'/'h' action for more information.";
 
+pub const REPL_HELP: &str = r"Each entered line is executed immediately, apart from the following commands:
+  - 'fn name:' starts the definition of a function, which is completed by an empty line
+  - ':registers'/':r' will print the registers
+  - ':flags'/':f' will print the flags
+  - ':stack'/':s' will print the stack pointer and the bytes on top of the stack
+  - ':load'/':l FILE' will replace the defined functions and constants with those of the file
+  - ':reset' will clear the registers, flags, stack and definitions
+  - ':quit'/':q' will exit
+";
+
+pub const REPL_INITIAL: &str = r"You are running an interactive session.
+Use the ':help'/':h' command for more information.";
+
+/// The amount of instructions a call entered in an interactive session may execute before it is stopped.
+pub const REPL_CALL_LIMIT: u64 = 1_000_000;
+
+/// The amount of bytes from the top of the stack printed by the stack command of the debugger and interactive session.
+pub const STACK_DUMP_BYTES: usize = 64;
+
 pub type Parameters<'a> = SplitAsciiWhitespace<'a>;
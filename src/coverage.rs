@@ -0,0 +1,338 @@
+//! Collection of the executed lines and branches, such that untested paths of a program can be found.
+
+use crate::{
+    decode,
+    program::{Line, Program},
+    Interpreter,
+};
+use std::{collections::HashMap, fmt::Display, path::Path};
+
+/// The lines and branches executed by a program.
+pub struct Coverage {
+    lines: HashMap<usize, u64>,
+    /// The amount of times each conditional jump was taken and not taken, by line.
+    branches: HashMap<usize, (u64, u64)>,
+}
+
+impl Coverage {
+    pub fn new() -> Self {
+        Self {
+            lines: HashMap::new(),
+            branches: HashMap::new(),
+        }
+    }
+
+    /// Records the execution of the line, and whether the jump was taken if the line is a conditional jump.
+    pub fn record(&mut self, line_number: usize, jump_taken: Option<bool>) {
+        *self.lines.entry(line_number).or_default() += 1;
+
+        if let Some(jump_taken) = jump_taken {
+            let (taken, not_taken) = self.branches.entry(line_number).or_default();
+            if jump_taken {
+                *taken += 1;
+            } else {
+                *not_taken += 1;
+            }
+        }
+    }
+
+    /// Returns the amount of times the line was executed.
+    pub fn hits(&self, line_number: usize) -> u64 {
+        self.lines.get(&line_number).copied().unwrap_or_default()
+    }
+
+    /// Returns the amount of times the conditional jump on the line was taken and not taken.
+    pub fn branch(&self, line_number: usize) -> (u64, u64) {
+        self.branches.get(&line_number).copied().unwrap_or_default()
+    }
+}
+
+impl Default for Coverage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The kind of a line of the program, with regards to coverage.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum LineKind {
+    /// Lines that are not instructions, such as comments, function names and empty lines.
+    NotExecutable,
+    Instruction,
+    ConditionalJump,
+}
+
+impl LineKind {
+    fn of(program: &Program, line_number: usize) -> Self {
+        let instruction = match program.line(line_number) {
+            Ok(Line::Source(line)) => decode::decode(line),
+            Ok(Line::Decoded(instruction)) => Ok(instruction.clone()),
+            Ok(Line::Comment) | Err(_) => return LineKind::NotExecutable,
+        };
+
+        match instruction {
+            Ok(instruction) if instruction.is_conditional_jump() => LineKind::ConditionalJump,
+            Ok(_) => LineKind::Instruction,
+            Err(_) => LineKind::NotExecutable,
+        }
+    }
+}
+
+/// The coverage in the LCOV tracefile format, which is consumed by coverage tools.
+pub struct Lcov<'a> {
+    coverage: &'a Coverage,
+    program: &'a Program,
+    source_file: &'a Path,
+}
+
+/// The source of the program annotated with the execution count of each line.
+///
+/// Lines that are never executed are marked with `#####`, and lines that cannot be executed with `-`.
+pub struct AnnotatedCoverage<'a> {
+    coverage: &'a Coverage,
+    program: &'a Program,
+}
+
+impl Interpreter {
+    /// Gets the recorded coverage, if the interpreter is configured to record it.
+    pub fn coverage(&self) -> Option<&Coverage> {
+        self.config.coverage.as_ref()
+    }
+
+    /// Gets the recorded coverage in the LCOV format, attributed to the specified source file.
+    pub fn lcov<'a>(&'a self, source_file: &'a Path) -> Option<Lcov<'a>> {
+        let coverage = self.config.coverage.as_ref()?;
+
+        Some(Lcov {
+            coverage,
+            program: &self.program,
+            source_file,
+        })
+    }
+
+    /// Gets the source of the program annotated with the recorded coverage.
+    pub fn annotated_coverage(&self) -> Option<AnnotatedCoverage<'_>> {
+        let coverage = self.config.coverage.as_ref()?;
+
+        Some(AnnotatedCoverage {
+            coverage,
+            program: &self.program,
+        })
+    }
+}
+
+impl Display for Lcov<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "TN:")?;
+        writeln!(f, "SF:{}", self.source_file.display())?;
+
+        let mut functions: Vec<(usize, &str)> = self
+            .program
+            .functions()
+            .iter()
+            .map(|(&line, name)| (line, name.as_ref()))
+            .collect();
+        functions.sort();
+        for (line, name) in &functions {
+            // the line of the function name precedes the first line of the function
+            writeln!(f, "FN:{},{name}", line - 1)?;
+        }
+        for (line, name) in &functions {
+            writeln!(f, "FNDA:{},{name}", self.coverage.hits(*line))?;
+        }
+        let functions_hit = functions
+            .iter()
+            .filter(|(line, _)| self.coverage.hits(*line) > 0)
+            .count();
+        writeln!(f, "FNF:{}", functions.len())?;
+        writeln!(f, "FNH:{functions_hit}")?;
+
+        let (mut branches_found, mut branches_hit) = (0, 0);
+        for line_number in 1..=self.program.len() {
+            if LineKind::of(self.program, line_number) != LineKind::ConditionalJump {
+                continue;
+            }
+
+            let (taken, not_taken) = self.coverage.branch(line_number);
+            for (branch, count) in [taken, not_taken].into_iter().enumerate() {
+                if self.coverage.hits(line_number) == 0 {
+                    writeln!(f, "BRDA:{line_number},0,{branch},-")?;
+                } else {
+                    writeln!(f, "BRDA:{line_number},0,{branch},{count}")?;
+                }
+
+                branches_found += 1;
+                if count > 0 {
+                    branches_hit += 1;
+                }
+            }
+        }
+        writeln!(f, "BRF:{branches_found}")?;
+        writeln!(f, "BRH:{branches_hit}")?;
+
+        let (mut lines_found, mut lines_hit) = (0, 0);
+        for line_number in 1..=self.program.len() {
+            if LineKind::of(self.program, line_number) == LineKind::NotExecutable {
+                continue;
+            }
+
+            let hits = self.coverage.hits(line_number);
+            writeln!(f, "DA:{line_number},{hits}")?;
+
+            lines_found += 1;
+            if hits > 0 {
+                lines_hit += 1;
+            }
+        }
+        writeln!(f, "LF:{lines_found}")?;
+        writeln!(f, "LH:{lines_hit}")?;
+
+        writeln!(f, "end_of_record")
+    }
+}
+
+impl Display for AnnotatedCoverage<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for line_number in 1..=self.program.len() {
+            let line = self.program.get(line_number).unwrap_or_default();
+            let hits = self.coverage.hits(line_number);
+
+            match LineKind::of(self.program, line_number) {
+                LineKind::NotExecutable => writeln!(f, "{:>9}:{line_number:>5}: {line}", "-")?,
+                _ if hits == 0 => writeln!(f, "{:>9}:{line_number:>5}: {line}", "#####")?,
+                LineKind::Instruction => writeln!(f, "{hits:>9}:{line_number:>5}: {line}")?,
+                LineKind::ConditionalJump => {
+                    let (taken, not_taken) = self.coverage.branch(line_number);
+                    writeln!(
+                        f,
+                        "{hits:>9}:{line_number:>5}: {line}  [taken {taken}, not taken {not_taken}]"
+                    )?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod report {
+    use crate::{coverage::Coverage, error::InterpreterError, Interpreter};
+    use std::path::Path;
+
+    fn covered() -> Result<Interpreter, InterpreterError> {
+        let mut i = Interpreter::new_test();
+        i.config.coverage = Some(Coverage::new());
+        let program = [
+            "// counts down from two",
+            "setb ra 2",
+            "call dec_ra",
+            "jnz -1",
+            "jiz +2",
+            "setb rb 1",
+            "stop",
+            "fn dec_ra:",
+            "subb ra 1",
+            "ret",
+        ]
+        .join("\n");
+
+        i.run(&program)?;
+
+        Ok(i)
+    }
+
+    #[test]
+    fn line_hits() -> Result<(), InterpreterError> {
+        let i = covered()?;
+        let coverage = i.coverage().expect("interpreter should record coverage");
+
+        assert_eq!(coverage.hits(1), 0);
+        assert_eq!(coverage.hits(2), 1);
+        assert_eq!(coverage.hits(3), 2);
+        assert_eq!(coverage.hits(6), 0);
+        assert_eq!(coverage.hits(9), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn branches() -> Result<(), InterpreterError> {
+        let i = covered()?;
+        let coverage = i.coverage().expect("interpreter should record coverage");
+
+        assert_eq!(coverage.branch(4), (1, 1));
+        assert_eq!(coverage.branch(5), (1, 0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn lcov() -> Result<(), InterpreterError> {
+        let i = covered()?;
+        let expected = [
+            "TN:",
+            "SF:program.kasm",
+            "FN:8,dec_ra",
+            "FNDA:2,dec_ra",
+            "FNF:1",
+            "FNH:1",
+            "BRDA:4,0,0,1",
+            "BRDA:4,0,1,1",
+            "BRDA:5,0,0,1",
+            "BRDA:5,0,1,0",
+            "BRF:4",
+            "BRH:3",
+            "DA:2,1",
+            "DA:3,2",
+            "DA:4,2",
+            "DA:5,1",
+            "DA:6,0",
+            "DA:7,1",
+            "DA:9,2",
+            "DA:10,2",
+            "LF:8",
+            "LH:7",
+            "end_of_record",
+            "",
+        ]
+        .join("\n");
+
+        let actual = i
+            .lcov(Path::new("program.kasm"))
+            .expect("interpreter should record coverage")
+            .to_string();
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn annotated() -> Result<(), InterpreterError> {
+        let i = covered()?;
+
+        let actual = i
+            .annotated_coverage()
+            .expect("interpreter should record coverage")
+            .to_string();
+
+        assert!(actual.contains("        -:    1: // counts down from two\n"));
+        assert!(actual.contains("        2:    4: jnz -1  [taken 1, not taken 1]\n"));
+        assert!(actual.contains("    #####:    6: setb rb 1\n"));
+        assert!(actual.contains("        -:    8: fn dec_ra:\n"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn not_recorded_by_default() -> Result<(), InterpreterError> {
+        let mut i = Interpreter::new_test();
+        i.run("stop")?;
+
+        assert!(i.coverage().is_none());
+        assert!(i.lcov(Path::new("program.kasm")).is_none());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,162 @@
+//! Post-mortem report of the machine state after a program failed during execution.
+
+use crate::{
+    constant::CRASH_REPORT_STACK_BYTES, error::InterpreterError, register::Register, Interpreter,
+};
+use std::fmt::Display;
+
+/// A report of the state of the interpreter at the time of an error.
+///
+/// The report is produced by [`Interpreter::crash_report`] and rendered through its [`Display`] implementation.
+pub struct CrashReport<'a> {
+    interpreter: &'a Interpreter,
+    error: &'a InterpreterError,
+}
+
+impl Interpreter {
+    /// Creates a report of the current state, describing the error that stopped the program.
+    ///
+    /// The report includes the failing line, the registers, the flags, the top of the stack,
+    /// the call stack, and the most recently executed lines.
+    pub fn crash_report<'a>(&'a self, error: &'a InterpreterError) -> CrashReport<'a> {
+        CrashReport {
+            interpreter: self,
+            error,
+        }
+    }
+}
+
+impl Display for CrashReport<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let i = self.interpreter;
+        let source = |line: usize| i.program.get(line).unwrap_or_default().trim();
+
+        writeln!(f, "crash report")?;
+        writeln!(f, "error: {}", self.error)?;
+        if let Some(line) = self.error.line() {
+            writeln!(f, "line {line}: {}", source(line))?;
+        }
+
+        writeln!(f, "\nregisters:")?;
+        for register in Register::ALL {
+            let value = i.register(register);
+            writeln!(f, "  {register}: {value:#018x} ({value})")?;
+        }
+
+        writeln!(
+            f,
+            "\nflags: sign={} overflow={} zero={}",
+            u8::from(i.flags.sign),
+            u8::from(i.flags.overflow),
+            u8::from(i.flags.zero)
+        )?;
+        writeln!(f, "stack pointer: {}", i.stack.sp())?;
+
+        let stack = i.stack.contents();
+        let shown = stack.len().min(CRASH_REPORT_STACK_BYTES);
+        writeln!(f, "\nstack (top {shown} of {} bytes):", stack.len())?;
+        for (address, byte) in stack.iter().enumerate().rev().take(shown) {
+            writeln!(f, "  {address:>8}: {byte:#04x} ({byte})")?;
+        }
+
+        writeln!(f, "\ncall stack:")?;
+        let current = self.error.line().unwrap_or(i.pc());
+        write!(f, "{}", i.backtrace(current))?;
+
+        writeln!(f, "\nlast executed lines:")?;
+        for &line in &i.history {
+            writeln!(f, "  {line:>5}: {}", source(line))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod report {
+    use crate::{
+        constant::CRASH_REPORT_HISTORY,
+        error::{ExecuteError, InterpreterError},
+        Interpreter,
+    };
+
+    #[test]
+    fn failing_line_and_registers() {
+        let mut i = Interpreter::new_test();
+        let program = ["setb ra 255", "popb rb"].join("\n");
+        let err = i.run(&program).expect_err("program should fail");
+
+        let actual = i.crash_report(&err).to_string();
+
+        assert_eq!(
+            err,
+            InterpreterError::Execute(2, ExecuteError::StackUnderflow)
+        );
+        assert!(actual.contains("error: failed to execute line 2: a stack underflow occured"));
+        assert!(actual.contains("line 2: popb rb"));
+        assert!(actual.contains("ra: 0x00000000000000ff (255)"));
+        assert!(actual.contains("rb: 0x0000000000000000 (0)"));
+        assert!(actual.contains("flags: sign=0 overflow=0 zero=0"));
+    }
+
+    #[test]
+    fn top_of_stack() {
+        let mut i = Interpreter::new_test();
+        let program = ["pshb 7", "pshb 9", "popw ra"].join("\n");
+        let err = i.run(&program).expect_err("program should fail");
+
+        let actual = i.crash_report(&err).to_string();
+
+        assert!(actual.contains("stack pointer: 2"));
+        assert!(actual
+            .contains("stack (top 2 of 2 bytes):\n         1: 0x09 (9)\n         0: 0x07 (7)\n"));
+    }
+
+    #[test]
+    fn call_stack_of_nested_calls() {
+        let mut i = Interpreter::new_test();
+        let program = [
+            "call outer",
+            "stop",
+            "fn outer:",
+            "pshb 1",
+            "call inner",
+            "ret",
+            "fn inner:",
+            "divb ra 0",
+        ]
+        .join("\n");
+        let err = i.run(&program).expect_err("program should fail");
+
+        let actual = i.crash_report(&err).to_string();
+
+        assert!(actual
+            .contains("call stack:\n  #0 line 8 in inner\n  #1 line 5 in outer\n  #2 line 1\n"));
+    }
+
+    #[test]
+    fn returned_calls_are_not_in_call_stack() {
+        let mut i = Interpreter::new_test();
+        let program = ["call done", "popb ra", "fn done:", "ret"].join("\n");
+        let err = i.run(&program).expect_err("program should fail");
+
+        let actual = i.crash_report(&err).to_string();
+
+        assert!(actual.contains("call stack:\n  #0 line 2\n\n"));
+    }
+
+    #[test]
+    fn last_executed_lines() {
+        let mut i = Interpreter::new_test();
+        let program = ["addb ra 1", "jnz -1", "popb rb"].join("\n");
+        let err = i.run(&program).expect_err("program should fail");
+
+        let actual = i.crash_report(&err).to_string();
+        let (_, history) = actual
+            .split_once("last executed lines:\n")
+            .expect("report should contain last executed lines");
+
+        assert_eq!(history.lines().count(), CRASH_REPORT_HISTORY);
+        assert!(history.ends_with("      2: jnz -1\n      3: popb rb\n"));
+    }
+}
@@ -0,0 +1,332 @@
+//! The debug mode, which executes the loaded program as directed by actions read from the input.
+//!
+//! Actions are read one per line, either typed interactively or from a script, and the responses are written to
+//! the debug output, apart from the output of the program. This allows a debugging session to be replayed, and its
+//! responses to be compared with those of an earlier session.
+
+use crate::{
+    constant::{COMMENT, DEBUG_HELP, DEBUG_INITIAL, STACK_DUMP_BYTES},
+    error::{ExecuteError, InterpreterError},
+    instruction::Instruction,
+    register::Register,
+    stepping::Status,
+    Interpreter,
+};
+use std::{fmt::Write as _, path::PathBuf};
+
+impl Interpreter {
+    pub(super) fn debug(&mut self) -> Result<(), InterpreterError> {
+        self.debug_write(&format!("{DEBUG_INITIAL}\n"))?;
+
+        while self.running {
+            let mut action = String::new();
+            let read = self.config.input.read_line(&mut action).map_err(|err| {
+                InterpreterError::Execute(self.pc(), ExecuteError::IO(err.to_string()))
+            })?;
+
+            // an exhausted input means no further actions can be taken
+            if read == 0 {
+                break;
+            }
+
+            if !self.debug_action(action.trim(), &mut Vec::new())? {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Takes the action, and returns false if the interpretation was stopped by it.
+    ///
+    /// The files currently being sourced are tracked, such that a script cannot source itself.
+    fn debug_action(
+        &mut self,
+        action: &str,
+        sourcing: &mut Vec<PathBuf>,
+    ) -> Result<bool, InterpreterError> {
+        if action.is_empty() || action.starts_with(COMMENT) {
+            return Ok(true);
+        }
+
+        let mut action_iter = action.split_ascii_whitespace();
+        let mut response = String::new();
+        match action_iter.next().unwrap_or_default() {
+            "help" | "h" => response.push_str(DEBUG_HELP),
+            "next" | "n" => {
+                if let Status::Error(err) = self.step() {
+                    return Err(err);
+                }
+            }
+            "continue" | "c" => match self.run_until(|_| false) {
+                Status::Error(err) => return Err(err),
+                Status::Breakpoint(line) => {
+                    let _ = writeln!(response, "paused at breakpoint on line {line}");
+                }
+                Status::Running | Status::Stopped => {}
+            },
+            "break" | "b" => match action_iter.next().map(str::parse::<usize>) {
+                Some(Ok(line)) => self.add_breakpoint(line),
+                _ => response.push_str("expected the line of the breakpoint\n"),
+            },
+            "delete" | "d" => match action_iter.next().map(str::parse::<usize>) {
+                Some(Ok(line)) if self.remove_breakpoint(line) => {}
+                Some(Ok(line)) => {
+                    let _ = writeln!(response, "there is no breakpoint on line {line}");
+                }
+                _ => response.push_str("expected the line of the breakpoint\n"),
+            },
+            "registers" | "r" => response.push_str(&self.registers_dump()),
+            "flags" | "f" => response.push_str(&self.flags_dump()),
+            "stack" => response.push_str(&self.stack_dump()),
+            "backtrace" | "bt" => {
+                let _ = write!(response, "{}", self.backtrace(self.pc()));
+            }
+            "source" => {
+                let file_name = PathBuf::from(action_iter.next().unwrap_or_default());
+                return self.source(file_name, sourcing);
+            }
+            "stop" | "s" => {
+                self.execute(Instruction::Stop)
+                    .map_err(|err| InterpreterError::Execute(self.pc(), err))?;
+                return Ok(false);
+            }
+            unknown => {
+                let _ = writeln!(response, "unknown action: '{unknown}'");
+            }
+        }
+
+        let _ = writeln!(response, "pc: {}, sp: {}", self.pc(), self.stack.sp());
+        self.debug_write(&response)?;
+
+        Ok(true)
+    }
+
+    /// Takes the actions in the file, until the interpretation is stopped or the program finishes.
+    fn source(
+        &mut self,
+        file_name: PathBuf,
+        sourcing: &mut Vec<PathBuf>,
+    ) -> Result<bool, InterpreterError> {
+        if sourcing.contains(&file_name) {
+            let response = format!("'{}' is already being sourced\n", file_name.display());
+            self.debug_write(&response)?;
+            return Ok(true);
+        }
+
+        let actions = match std::fs::read_to_string(&file_name) {
+            Ok(actions) => actions,
+            Err(err) => {
+                let response = format!("could not read '{}': {err}\n", file_name.display());
+                self.debug_write(&response)?;
+                return Ok(true);
+            }
+        };
+
+        sourcing.push(file_name);
+        for action in actions.lines() {
+            if !self.running {
+                break;
+            }
+
+            if !self.debug_action(action.trim(), sourcing)? {
+                sourcing.pop();
+                return Ok(false);
+            }
+        }
+        sourcing.pop();
+
+        Ok(true)
+    }
+
+    fn debug_write(&mut self, response: &str) -> Result<(), InterpreterError> {
+        self.config
+            .debug_output
+            .write_all(response.as_bytes())
+            .map_err(|err| InterpreterError::Execute(self.pc(), ExecuteError::IO(err.to_string())))
+    }
+
+    /// Formats the value of each register, one per line.
+    pub(crate) fn registers_dump(&self) -> String {
+        let mut dump = String::new();
+        for register in Register::ALL {
+            let value = self.register(register);
+            let _ = writeln!(dump, "{register}: {value:#018x} ({value})");
+        }
+
+        dump
+    }
+
+    /// Formats the flags on a single line.
+    pub(crate) fn flags_dump(&self) -> String {
+        format!(
+            "sign={} overflow={} zero={}\n",
+            u8::from(self.flags.sign),
+            u8::from(self.flags.overflow),
+            u8::from(self.flags.zero)
+        )
+    }
+
+    /// Formats the stack pointer and the bytes on top of the stack, with the top first.
+    pub(crate) fn stack_dump(&self) -> String {
+        let stack = self.stack.contents();
+        let mut dump = format!("stack pointer: {}\n", self.stack.sp());
+        for (address, byte) in stack.iter().enumerate().rev().take(STACK_DUMP_BYTES) {
+            let _ = writeln!(dump, "{address:>8}: {byte:#04x} ({byte})");
+        }
+
+        dump
+    }
+}
+
+#[cfg(test)]
+mod actions {
+    use crate::{
+        constant::Byte,
+        error::{ExecuteError, InterpreterError},
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+    use std::io::Cursor;
+
+    /// Debugs the program with the actions, and returns the responses to the actions.
+    fn debug(
+        program: &[&str],
+        actions: &[&str],
+    ) -> Result<(Interpreter, String), InterpreterError> {
+        let mut i = Interpreter::new_test();
+        i.config.debug = true;
+        i.config.input = Box::new(Cursor::new(actions.join("\n")));
+
+        i.run(&program.join("\n"))?;
+        let responses = i
+            .config
+            .debug_output
+            .get_buffer()
+            .expect("interpreter test instance should return buffer");
+
+        Ok((i, responses))
+    }
+
+    #[test]
+    fn breakpoints_and_dumps() -> Result<(), InterpreterError> {
+        let program = ["setb ra 1", "pshb ra", "cmpb ra 1", "prrb ra", "stop"];
+        let actions = [
+            "// pause before the comparison",
+            "break 3",
+            "continue",
+            "stack",
+            "next",
+            "flags",
+            "delete 3",
+            "continue",
+        ];
+        let expected = [
+            "You are running the program in debug mode.",
+            "Use the 'help'/'h' action for more information.",
+            "pc: 1, sp: 0",
+            "paused at breakpoint on line 3",
+            "pc: 3, sp: 1",
+            "stack pointer: 1",
+            "       0: 0x01 (1)",
+            "pc: 3, sp: 1",
+            "pc: 4, sp: 1",
+            "sign=0 overflow=0 zero=1",
+            "pc: 4, sp: 1",
+            "pc: 4, sp: 1",
+            "pc: 5, sp: 1",
+            "",
+        ]
+        .join("\n");
+
+        let (i, actual) = debug(&program, &actions)?;
+
+        assert_eq!(actual, expected);
+        assert_eq!(i.config.output.get_buffer(), Some("ra: 1\n".to_string()));
+        assert!(!i.running);
+
+        Ok(())
+    }
+
+    #[test]
+    fn registers() -> Result<(), InterpreterError> {
+        let program = ["setb rh 255", "stop"];
+        let actions = ["next", "registers"];
+
+        let (_, actual) = debug(&program, &actions)?;
+
+        assert!(actual.contains("rh: 0x00000000000000ff (255)\n"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_breakpoints() -> Result<(), InterpreterError> {
+        let program = ["stop"];
+        let actions = ["break", "delete 7"];
+        let expected = [
+            "You are running the program in debug mode.",
+            "Use the 'help'/'h' action for more information.",
+            "expected the line of the breakpoint",
+            "pc: 1, sp: 0",
+            "there is no breakpoint on line 7",
+            "pc: 1, sp: 0",
+            "",
+        ]
+        .join("\n");
+
+        let (_, actual) = debug(&program, &actions)?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn source_script() -> Result<(), InterpreterError> {
+        let script = std::env::temp_dir().join(format!("kasm-debug-{}.txt", std::process::id()));
+        let source = format!("source {}", script.display());
+        std::fs::write(
+            &script,
+            ["next", "next", &source, "stop", "next"].join("\n"),
+        )
+        .expect("script should be writable");
+        let program = ["setb ra 1", "addb ra 1", "addb ra 1", "stop"];
+
+        let result = debug(&program, &[&source]);
+        std::fs::remove_file(&script).expect("script should be removable");
+        let (i, actual) = result?;
+
+        assert_eq!(i.registers.get::<Byte>(Register::A), 2);
+        assert!(actual.contains("' is already being sourced\n"));
+        assert!(!i.running);
+
+        Ok(())
+    }
+
+    #[test]
+    fn input_error_ends_debugging() {
+        let mut i = Interpreter::new_test();
+        i.config.debug = true;
+        // input that is not valid utf-8 cannot be read as an action
+        i.config.input = Box::new(Cursor::new(vec![0xff, b'\n']));
+
+        let actual = i.run("stop");
+
+        assert!(matches!(
+            actual,
+            Err(InterpreterError::Execute(1, ExecuteError::IO(_)))
+        ));
+    }
+
+    #[test]
+    fn execute_error_ends_debugging() {
+        let program = ["popb ra"];
+        let actions = ["next", "next"];
+
+        let actual = debug(&program, &actions);
+
+        assert!(matches!(actual, Err(InterpreterError::Execute(1, _))));
+    }
+}
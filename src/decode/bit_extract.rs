@@ -0,0 +1,243 @@
+use crate::{
+    constant::{Byte, Parameters},
+    error::DecodeError,
+    instruction::{BitExtract, Instruction},
+    operand::Operand,
+    register::Register,
+};
+
+pub struct BitExtractParameterDecoder;
+
+impl BitExtractParameterDecoder {
+    pub fn byte(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let (register, start, length) = try_bit_field_parameters(parameters)?;
+        let instruction = BitExtract::Byte(register, start, length);
+
+        Ok(Instruction::BitExtract(instruction))
+    }
+
+    pub fn quarter(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let (register, start, length) = try_bit_field_parameters(parameters)?;
+        let instruction = BitExtract::Quarter(register, start, length);
+
+        Ok(Instruction::BitExtract(instruction))
+    }
+
+    pub fn half(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let (register, start, length) = try_bit_field_parameters(parameters)?;
+        let instruction = BitExtract::Half(register, start, length);
+
+        Ok(Instruction::BitExtract(instruction))
+    }
+
+    pub fn word(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let (register, start, length) = try_bit_field_parameters(parameters)?;
+        let instruction = BitExtract::Word(register, start, length);
+
+        Ok(Instruction::BitExtract(instruction))
+    }
+}
+
+/// Attempts to get the register, the start and the length of the bit field from the instruction arguments.
+fn try_bit_field_parameters(
+    mut parameters: Parameters,
+) -> Result<(Register, Operand<Byte>, Operand<Byte>), DecodeError> {
+    let (Some(s_register), Some(s_start), Some(s_length)) =
+        (parameters.next(), parameters.next(), parameters.next())
+    else {
+        return Err(DecodeError::IncompleteInstruction);
+    };
+
+    let register = Register::try_from(s_register)?;
+    let start = Operand::try_from(s_start)?;
+    let length = Operand::try_from(s_length)?;
+
+    Ok((register, start, length))
+}
+
+#[cfg(test)]
+mod regression {
+    mod byte {
+        use crate::{
+            decode::decode,
+            error::DecodeError,
+            instruction::{BitExtract, Instruction},
+            operand::Operand,
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_third_param() {
+            let instruction = "bextb ra 2";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_register_error() {
+            let instruction = "bextb rx 2 3";
+            let expected = Err(DecodeError::InvalidRegister("rx".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn valid_parameters() -> Result<(), DecodeError> {
+            let instruction = "bextb ra 2 rb";
+            let expected = Instruction::BitExtract(BitExtract::Byte(
+                Register::A,
+                Operand::Immediate(2),
+                Operand::Register(Register::B),
+            ));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod quarter {
+        use crate::{
+            decode::decode,
+            error::DecodeError,
+            instruction::{BitExtract, Instruction},
+            operand::Operand,
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_third_param() {
+            let instruction = "bextq ra 2";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_register_error() {
+            let instruction = "bextq rx 2 3";
+            let expected = Err(DecodeError::InvalidRegister("rx".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn valid_parameters() -> Result<(), DecodeError> {
+            let instruction = "bextq ra 2 rb";
+            let expected = Instruction::BitExtract(BitExtract::Quarter(
+                Register::A,
+                Operand::Immediate(2),
+                Operand::Register(Register::B),
+            ));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod half {
+        use crate::{
+            decode::decode,
+            error::DecodeError,
+            instruction::{BitExtract, Instruction},
+            operand::Operand,
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_third_param() {
+            let instruction = "bexth ra 2";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_register_error() {
+            let instruction = "bexth rx 2 3";
+            let expected = Err(DecodeError::InvalidRegister("rx".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn valid_parameters() -> Result<(), DecodeError> {
+            let instruction = "bexth ra 2 rb";
+            let expected = Instruction::BitExtract(BitExtract::Half(
+                Register::A,
+                Operand::Immediate(2),
+                Operand::Register(Register::B),
+            ));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod word {
+        use crate::{
+            decode::decode,
+            error::DecodeError,
+            instruction::{BitExtract, Instruction},
+            operand::Operand,
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_third_param() {
+            let instruction = "bextw ra 2";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_register_error() {
+            let instruction = "bextw rx 2 3";
+            let expected = Err(DecodeError::InvalidRegister("rx".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn valid_parameters() -> Result<(), DecodeError> {
+            let instruction = "bextw ra 2 rb";
+            let expected = Instruction::BitExtract(BitExtract::Word(
+                Register::A,
+                Operand::Immediate(2),
+                Operand::Register(Register::B),
+            ));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,262 @@
+use crate::{
+    constant::{Byte, Parameters},
+    error::DecodeError,
+    instruction::{BitInsert, Instruction},
+    operand::Operand,
+    register::Register,
+};
+use std::str::FromStr;
+
+/// The register, the operand, the start and the length of the bit field of a bit insert instruction.
+type BitInsertParameters<T> = (Register, Operand<T>, Operand<Byte>, Operand<Byte>);
+
+pub struct BitInsertParameterDecoder;
+
+impl BitInsertParameterDecoder {
+    pub fn byte(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let (register, operand, start, length) = try_bit_insert_parameters(parameters)?;
+        let instruction = BitInsert::Byte(register, operand, start, length);
+
+        Ok(Instruction::BitInsert(instruction))
+    }
+
+    pub fn quarter(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let (register, operand, start, length) = try_bit_insert_parameters(parameters)?;
+        let instruction = BitInsert::Quarter(register, operand, start, length);
+
+        Ok(Instruction::BitInsert(instruction))
+    }
+
+    pub fn half(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let (register, operand, start, length) = try_bit_insert_parameters(parameters)?;
+        let instruction = BitInsert::Half(register, operand, start, length);
+
+        Ok(Instruction::BitInsert(instruction))
+    }
+
+    pub fn word(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let (register, operand, start, length) = try_bit_insert_parameters(parameters)?;
+        let instruction = BitInsert::Word(register, operand, start, length);
+
+        Ok(Instruction::BitInsert(instruction))
+    }
+}
+
+/// Attempts to get the register, the operand, the start and the length of the bit field from the instruction arguments.
+fn try_bit_insert_parameters<T>(
+    mut parameters: Parameters,
+) -> Result<BitInsertParameters<T>, DecodeError>
+where
+    T: FromStr,
+{
+    let (Some(s_register), Some(s_operand), Some(s_start), Some(s_length)) = (
+        parameters.next(),
+        parameters.next(),
+        parameters.next(),
+        parameters.next(),
+    ) else {
+        return Err(DecodeError::IncompleteInstruction);
+    };
+
+    let register = Register::try_from(s_register)?;
+    let operand = Operand::try_from(s_operand)?;
+    let start = Operand::try_from(s_start)?;
+    let length = Operand::try_from(s_length)?;
+
+    Ok((register, operand, start, length))
+}
+
+#[cfg(test)]
+mod regression {
+    mod byte {
+        use crate::{
+            constant::Byte,
+            decode::decode,
+            error::DecodeError,
+            instruction::{BitInsert, Instruction},
+            operand::Operand,
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_fourth_param() {
+            let instruction = "binsb ra rb 2";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_immediate_value_error_in_length() {
+            let instruction = "binsb ra rb 2 -3";
+            let expected = Err(DecodeError::InvalidImmediateValue("-3".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn valid_parameters() -> Result<(), DecodeError> {
+            let instruction = format!("binsb ra {} 2 3", Byte::MAX);
+            let expected = Instruction::BitInsert(BitInsert::Byte(
+                Register::A,
+                Operand::Immediate(Byte::MAX),
+                Operand::Immediate(2),
+                Operand::Immediate(3),
+            ));
+
+            let actual = decode(&instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod quarter {
+        use crate::{
+            constant::Quarter,
+            decode::decode,
+            error::DecodeError,
+            instruction::{BitInsert, Instruction},
+            operand::Operand,
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_fourth_param() {
+            let instruction = "binsq ra rb 2";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_immediate_value_error_in_length() {
+            let instruction = "binsq ra rb 2 -3";
+            let expected = Err(DecodeError::InvalidImmediateValue("-3".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn valid_parameters() -> Result<(), DecodeError> {
+            let instruction = format!("binsq ra {} 2 3", Quarter::MAX);
+            let expected = Instruction::BitInsert(BitInsert::Quarter(
+                Register::A,
+                Operand::Immediate(Quarter::MAX),
+                Operand::Immediate(2),
+                Operand::Immediate(3),
+            ));
+
+            let actual = decode(&instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod half {
+        use crate::{
+            constant::Half,
+            decode::decode,
+            error::DecodeError,
+            instruction::{BitInsert, Instruction},
+            operand::Operand,
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_fourth_param() {
+            let instruction = "binsh ra rb 2";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_immediate_value_error_in_length() {
+            let instruction = "binsh ra rb 2 -3";
+            let expected = Err(DecodeError::InvalidImmediateValue("-3".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn valid_parameters() -> Result<(), DecodeError> {
+            let instruction = format!("binsh ra {} 2 3", Half::MAX);
+            let expected = Instruction::BitInsert(BitInsert::Half(
+                Register::A,
+                Operand::Immediate(Half::MAX),
+                Operand::Immediate(2),
+                Operand::Immediate(3),
+            ));
+
+            let actual = decode(&instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod word {
+        use crate::{
+            constant::Word,
+            decode::decode,
+            error::DecodeError,
+            instruction::{BitInsert, Instruction},
+            operand::Operand,
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_fourth_param() {
+            let instruction = "binsw ra rb 2";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_immediate_value_error_in_length() {
+            let instruction = "binsw ra rb 2 -3";
+            let expected = Err(DecodeError::InvalidImmediateValue("-3".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn valid_parameters() -> Result<(), DecodeError> {
+            let instruction = format!("binsw ra {} 2 3", Word::MAX);
+            let expected = Instruction::BitInsert(BitInsert::Word(
+                Register::A,
+                Operand::Immediate(Word::MAX),
+                Operand::Immediate(2),
+                Operand::Immediate(3),
+            ));
+
+            let actual = decode(&instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,227 @@
+use super::try_get_both_parameters_str;
+use crate::{
+    constant::Parameters,
+    error::DecodeError,
+    instruction::{BitTest, Instruction},
+    operand::Operand,
+};
+
+pub struct BitTestParameterDecoder;
+
+impl BitTestParameterDecoder {
+    pub fn byte(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let (s_operand, s_index) = try_get_both_parameters_str(parameters)?;
+        let instruction = BitTest::Byte(Operand::try_from(s_operand)?, Operand::try_from(s_index)?);
+
+        Ok(Instruction::BitTest(instruction))
+    }
+
+    pub fn quarter(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let (s_operand, s_index) = try_get_both_parameters_str(parameters)?;
+        let instruction =
+            BitTest::Quarter(Operand::try_from(s_operand)?, Operand::try_from(s_index)?);
+
+        Ok(Instruction::BitTest(instruction))
+    }
+
+    pub fn half(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let (s_operand, s_index) = try_get_both_parameters_str(parameters)?;
+        let instruction = BitTest::Half(Operand::try_from(s_operand)?, Operand::try_from(s_index)?);
+
+        Ok(Instruction::BitTest(instruction))
+    }
+
+    pub fn word(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let (s_operand, s_index) = try_get_both_parameters_str(parameters)?;
+        let instruction = BitTest::Word(Operand::try_from(s_operand)?, Operand::try_from(s_index)?);
+
+        Ok(Instruction::BitTest(instruction))
+    }
+}
+
+#[cfg(test)]
+mod regression {
+    mod byte {
+        use crate::{
+            constant::Byte,
+            decode::decode,
+            error::DecodeError,
+            instruction::{BitTest, Instruction},
+            operand::Operand,
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_second_param() {
+            let instruction = "btb ra";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_immediate_value_error_in_index() {
+            let instruction = "btb ra 256";
+            let expected = Err(DecodeError::InvalidImmediateValue("256".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn immediate_and_register() -> Result<(), DecodeError> {
+            let instruction = format!("btb {} rb", Byte::MAX);
+            let expected = Instruction::BitTest(BitTest::Byte(
+                Operand::Immediate(Byte::MAX),
+                Operand::Register(Register::B),
+            ));
+
+            let actual = decode(&instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod quarter {
+        use crate::{
+            constant::Quarter,
+            decode::decode,
+            error::DecodeError,
+            instruction::{BitTest, Instruction},
+            operand::Operand,
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_second_param() {
+            let instruction = "btq ra";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_immediate_value_error_in_index() {
+            let instruction = "btq ra 256";
+            let expected = Err(DecodeError::InvalidImmediateValue("256".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn immediate_and_register() -> Result<(), DecodeError> {
+            let instruction = format!("btq {} rb", Quarter::MAX);
+            let expected = Instruction::BitTest(BitTest::Quarter(
+                Operand::Immediate(Quarter::MAX),
+                Operand::Register(Register::B),
+            ));
+
+            let actual = decode(&instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod half {
+        use crate::{
+            constant::Half,
+            decode::decode,
+            error::DecodeError,
+            instruction::{BitTest, Instruction},
+            operand::Operand,
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_second_param() {
+            let instruction = "bth ra";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_immediate_value_error_in_index() {
+            let instruction = "bth ra 256";
+            let expected = Err(DecodeError::InvalidImmediateValue("256".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn immediate_and_register() -> Result<(), DecodeError> {
+            let instruction = format!("bth {} rb", Half::MAX);
+            let expected = Instruction::BitTest(BitTest::Half(
+                Operand::Immediate(Half::MAX),
+                Operand::Register(Register::B),
+            ));
+
+            let actual = decode(&instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod word {
+        use crate::{
+            constant::Word,
+            decode::decode,
+            error::DecodeError,
+            instruction::{BitTest, Instruction},
+            operand::Operand,
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_second_param() {
+            let instruction = "btw ra";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_immediate_value_error_in_index() {
+            let instruction = "btw ra 256";
+            let expected = Err(DecodeError::InvalidImmediateValue("256".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn immediate_and_register() -> Result<(), DecodeError> {
+            let instruction = format!("btw {} rb", Word::MAX);
+            let expected = Instruction::BitTest(BitTest::Word(
+                Operand::Immediate(Word::MAX),
+                Operand::Register(Register::B),
+            ));
+
+            let actual = decode(&instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+}
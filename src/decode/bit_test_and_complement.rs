@@ -0,0 +1,281 @@
+use super::ParameterDecoderHelper;
+use crate::{
+    constant::Parameters,
+    error::DecodeError,
+    instruction::{BitTestAndComplement, Instruction},
+};
+
+pub struct BitTestAndComplementParameterDecoder;
+
+impl BitTestAndComplementParameterDecoder {
+    pub fn byte(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let (register, index) = ParameterDecoderHelper::try_register_and_operand(parameters)?;
+        let instruction = BitTestAndComplement::Byte(register, index);
+
+        Ok(Instruction::BitTestAndComplement(instruction))
+    }
+
+    pub fn quarter(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let (register, index) = ParameterDecoderHelper::try_register_and_operand(parameters)?;
+        let instruction = BitTestAndComplement::Quarter(register, index);
+
+        Ok(Instruction::BitTestAndComplement(instruction))
+    }
+
+    pub fn half(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let (register, index) = ParameterDecoderHelper::try_register_and_operand(parameters)?;
+        let instruction = BitTestAndComplement::Half(register, index);
+
+        Ok(Instruction::BitTestAndComplement(instruction))
+    }
+
+    pub fn word(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let (register, index) = ParameterDecoderHelper::try_register_and_operand(parameters)?;
+        let instruction = BitTestAndComplement::Word(register, index);
+
+        Ok(Instruction::BitTestAndComplement(instruction))
+    }
+}
+
+#[cfg(test)]
+mod regression {
+    mod byte {
+        use crate::{
+            decode::decode,
+            error::DecodeError,
+            instruction::{BitTestAndComplement, Instruction},
+            operand::Operand,
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_second_param() {
+            let instruction = "btcb ra";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_immediate_value_error() {
+            let instruction = "btcb ra 256";
+            let expected = Err(DecodeError::InvalidImmediateValue("256".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn register_and_immediate() -> Result<(), DecodeError> {
+            let instruction = "btcb ra 3";
+            let expected = Instruction::BitTestAndComplement(BitTestAndComplement::Byte(
+                Register::A,
+                Operand::Immediate(3),
+            ));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+
+        #[test]
+        fn register_and_register() -> Result<(), DecodeError> {
+            let instruction = "btcb ra rb";
+            let expected = Instruction::BitTestAndComplement(BitTestAndComplement::Byte(
+                Register::A,
+                Operand::Register(Register::B),
+            ));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod quarter {
+        use crate::{
+            decode::decode,
+            error::DecodeError,
+            instruction::{BitTestAndComplement, Instruction},
+            operand::Operand,
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_second_param() {
+            let instruction = "btcq ra";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_immediate_value_error() {
+            let instruction = "btcq ra 256";
+            let expected = Err(DecodeError::InvalidImmediateValue("256".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn register_and_immediate() -> Result<(), DecodeError> {
+            let instruction = "btcq ra 3";
+            let expected = Instruction::BitTestAndComplement(BitTestAndComplement::Quarter(
+                Register::A,
+                Operand::Immediate(3),
+            ));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+
+        #[test]
+        fn register_and_register() -> Result<(), DecodeError> {
+            let instruction = "btcq ra rb";
+            let expected = Instruction::BitTestAndComplement(BitTestAndComplement::Quarter(
+                Register::A,
+                Operand::Register(Register::B),
+            ));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod half {
+        use crate::{
+            decode::decode,
+            error::DecodeError,
+            instruction::{BitTestAndComplement, Instruction},
+            operand::Operand,
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_second_param() {
+            let instruction = "btch ra";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_immediate_value_error() {
+            let instruction = "btch ra 256";
+            let expected = Err(DecodeError::InvalidImmediateValue("256".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn register_and_immediate() -> Result<(), DecodeError> {
+            let instruction = "btch ra 3";
+            let expected = Instruction::BitTestAndComplement(BitTestAndComplement::Half(
+                Register::A,
+                Operand::Immediate(3),
+            ));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+
+        #[test]
+        fn register_and_register() -> Result<(), DecodeError> {
+            let instruction = "btch ra rb";
+            let expected = Instruction::BitTestAndComplement(BitTestAndComplement::Half(
+                Register::A,
+                Operand::Register(Register::B),
+            ));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod word {
+        use crate::{
+            decode::decode,
+            error::DecodeError,
+            instruction::{BitTestAndComplement, Instruction},
+            operand::Operand,
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_second_param() {
+            let instruction = "btcw ra";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_immediate_value_error() {
+            let instruction = "btcw ra 256";
+            let expected = Err(DecodeError::InvalidImmediateValue("256".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn register_and_immediate() -> Result<(), DecodeError> {
+            let instruction = "btcw ra 3";
+            let expected = Instruction::BitTestAndComplement(BitTestAndComplement::Word(
+                Register::A,
+                Operand::Immediate(3),
+            ));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+
+        #[test]
+        fn register_and_register() -> Result<(), DecodeError> {
+            let instruction = "btcw ra rb";
+            let expected = Instruction::BitTestAndComplement(BitTestAndComplement::Word(
+                Register::A,
+                Operand::Register(Register::B),
+            ));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,281 @@
+use super::ParameterDecoderHelper;
+use crate::{
+    constant::Parameters,
+    error::DecodeError,
+    instruction::{BitTestAndReset, Instruction},
+};
+
+pub struct BitTestAndResetParameterDecoder;
+
+impl BitTestAndResetParameterDecoder {
+    pub fn byte(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let (register, index) = ParameterDecoderHelper::try_register_and_operand(parameters)?;
+        let instruction = BitTestAndReset::Byte(register, index);
+
+        Ok(Instruction::BitTestAndReset(instruction))
+    }
+
+    pub fn quarter(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let (register, index) = ParameterDecoderHelper::try_register_and_operand(parameters)?;
+        let instruction = BitTestAndReset::Quarter(register, index);
+
+        Ok(Instruction::BitTestAndReset(instruction))
+    }
+
+    pub fn half(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let (register, index) = ParameterDecoderHelper::try_register_and_operand(parameters)?;
+        let instruction = BitTestAndReset::Half(register, index);
+
+        Ok(Instruction::BitTestAndReset(instruction))
+    }
+
+    pub fn word(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let (register, index) = ParameterDecoderHelper::try_register_and_operand(parameters)?;
+        let instruction = BitTestAndReset::Word(register, index);
+
+        Ok(Instruction::BitTestAndReset(instruction))
+    }
+}
+
+#[cfg(test)]
+mod regression {
+    mod byte {
+        use crate::{
+            decode::decode,
+            error::DecodeError,
+            instruction::{BitTestAndReset, Instruction},
+            operand::Operand,
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_second_param() {
+            let instruction = "btrb ra";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_immediate_value_error() {
+            let instruction = "btrb ra 256";
+            let expected = Err(DecodeError::InvalidImmediateValue("256".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn register_and_immediate() -> Result<(), DecodeError> {
+            let instruction = "btrb ra 3";
+            let expected = Instruction::BitTestAndReset(BitTestAndReset::Byte(
+                Register::A,
+                Operand::Immediate(3),
+            ));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+
+        #[test]
+        fn register_and_register() -> Result<(), DecodeError> {
+            let instruction = "btrb ra rb";
+            let expected = Instruction::BitTestAndReset(BitTestAndReset::Byte(
+                Register::A,
+                Operand::Register(Register::B),
+            ));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod quarter {
+        use crate::{
+            decode::decode,
+            error::DecodeError,
+            instruction::{BitTestAndReset, Instruction},
+            operand::Operand,
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_second_param() {
+            let instruction = "btrq ra";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_immediate_value_error() {
+            let instruction = "btrq ra 256";
+            let expected = Err(DecodeError::InvalidImmediateValue("256".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn register_and_immediate() -> Result<(), DecodeError> {
+            let instruction = "btrq ra 3";
+            let expected = Instruction::BitTestAndReset(BitTestAndReset::Quarter(
+                Register::A,
+                Operand::Immediate(3),
+            ));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+
+        #[test]
+        fn register_and_register() -> Result<(), DecodeError> {
+            let instruction = "btrq ra rb";
+            let expected = Instruction::BitTestAndReset(BitTestAndReset::Quarter(
+                Register::A,
+                Operand::Register(Register::B),
+            ));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod half {
+        use crate::{
+            decode::decode,
+            error::DecodeError,
+            instruction::{BitTestAndReset, Instruction},
+            operand::Operand,
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_second_param() {
+            let instruction = "btrh ra";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_immediate_value_error() {
+            let instruction = "btrh ra 256";
+            let expected = Err(DecodeError::InvalidImmediateValue("256".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn register_and_immediate() -> Result<(), DecodeError> {
+            let instruction = "btrh ra 3";
+            let expected = Instruction::BitTestAndReset(BitTestAndReset::Half(
+                Register::A,
+                Operand::Immediate(3),
+            ));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+
+        #[test]
+        fn register_and_register() -> Result<(), DecodeError> {
+            let instruction = "btrh ra rb";
+            let expected = Instruction::BitTestAndReset(BitTestAndReset::Half(
+                Register::A,
+                Operand::Register(Register::B),
+            ));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod word {
+        use crate::{
+            decode::decode,
+            error::DecodeError,
+            instruction::{BitTestAndReset, Instruction},
+            operand::Operand,
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_second_param() {
+            let instruction = "btrw ra";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_immediate_value_error() {
+            let instruction = "btrw ra 256";
+            let expected = Err(DecodeError::InvalidImmediateValue("256".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn register_and_immediate() -> Result<(), DecodeError> {
+            let instruction = "btrw ra 3";
+            let expected = Instruction::BitTestAndReset(BitTestAndReset::Word(
+                Register::A,
+                Operand::Immediate(3),
+            ));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+
+        #[test]
+        fn register_and_register() -> Result<(), DecodeError> {
+            let instruction = "btrw ra rb";
+            let expected = Instruction::BitTestAndReset(BitTestAndReset::Word(
+                Register::A,
+                Operand::Register(Register::B),
+            ));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+}
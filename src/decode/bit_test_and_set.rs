@@ -0,0 +1,275 @@
+use super::ParameterDecoderHelper;
+use crate::{
+    constant::Parameters,
+    error::DecodeError,
+    instruction::{BitTestAndSet, Instruction},
+};
+
+pub struct BitTestAndSetParameterDecoder;
+
+impl BitTestAndSetParameterDecoder {
+    pub fn byte(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let (register, index) = ParameterDecoderHelper::try_register_and_operand(parameters)?;
+        let instruction = BitTestAndSet::Byte(register, index);
+
+        Ok(Instruction::BitTestAndSet(instruction))
+    }
+
+    pub fn quarter(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let (register, index) = ParameterDecoderHelper::try_register_and_operand(parameters)?;
+        let instruction = BitTestAndSet::Quarter(register, index);
+
+        Ok(Instruction::BitTestAndSet(instruction))
+    }
+
+    pub fn half(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let (register, index) = ParameterDecoderHelper::try_register_and_operand(parameters)?;
+        let instruction = BitTestAndSet::Half(register, index);
+
+        Ok(Instruction::BitTestAndSet(instruction))
+    }
+
+    pub fn word(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let (register, index) = ParameterDecoderHelper::try_register_and_operand(parameters)?;
+        let instruction = BitTestAndSet::Word(register, index);
+
+        Ok(Instruction::BitTestAndSet(instruction))
+    }
+}
+
+#[cfg(test)]
+mod regression {
+    mod byte {
+        use crate::{
+            decode::decode,
+            error::DecodeError,
+            instruction::{BitTestAndSet, Instruction},
+            operand::Operand,
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_second_param() {
+            let instruction = "btsb ra";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_immediate_value_error() {
+            let instruction = "btsb ra 256";
+            let expected = Err(DecodeError::InvalidImmediateValue("256".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn register_and_immediate() -> Result<(), DecodeError> {
+            let instruction = "btsb ra 3";
+            let expected =
+                Instruction::BitTestAndSet(BitTestAndSet::Byte(Register::A, Operand::Immediate(3)));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+
+        #[test]
+        fn register_and_register() -> Result<(), DecodeError> {
+            let instruction = "btsb ra rb";
+            let expected = Instruction::BitTestAndSet(BitTestAndSet::Byte(
+                Register::A,
+                Operand::Register(Register::B),
+            ));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod quarter {
+        use crate::{
+            decode::decode,
+            error::DecodeError,
+            instruction::{BitTestAndSet, Instruction},
+            operand::Operand,
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_second_param() {
+            let instruction = "btsq ra";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_immediate_value_error() {
+            let instruction = "btsq ra 256";
+            let expected = Err(DecodeError::InvalidImmediateValue("256".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn register_and_immediate() -> Result<(), DecodeError> {
+            let instruction = "btsq ra 3";
+            let expected = Instruction::BitTestAndSet(BitTestAndSet::Quarter(
+                Register::A,
+                Operand::Immediate(3),
+            ));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+
+        #[test]
+        fn register_and_register() -> Result<(), DecodeError> {
+            let instruction = "btsq ra rb";
+            let expected = Instruction::BitTestAndSet(BitTestAndSet::Quarter(
+                Register::A,
+                Operand::Register(Register::B),
+            ));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod half {
+        use crate::{
+            decode::decode,
+            error::DecodeError,
+            instruction::{BitTestAndSet, Instruction},
+            operand::Operand,
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_second_param() {
+            let instruction = "btsh ra";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_immediate_value_error() {
+            let instruction = "btsh ra 256";
+            let expected = Err(DecodeError::InvalidImmediateValue("256".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn register_and_immediate() -> Result<(), DecodeError> {
+            let instruction = "btsh ra 3";
+            let expected =
+                Instruction::BitTestAndSet(BitTestAndSet::Half(Register::A, Operand::Immediate(3)));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+
+        #[test]
+        fn register_and_register() -> Result<(), DecodeError> {
+            let instruction = "btsh ra rb";
+            let expected = Instruction::BitTestAndSet(BitTestAndSet::Half(
+                Register::A,
+                Operand::Register(Register::B),
+            ));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod word {
+        use crate::{
+            decode::decode,
+            error::DecodeError,
+            instruction::{BitTestAndSet, Instruction},
+            operand::Operand,
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_second_param() {
+            let instruction = "btsw ra";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_immediate_value_error() {
+            let instruction = "btsw ra 256";
+            let expected = Err(DecodeError::InvalidImmediateValue("256".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn register_and_immediate() -> Result<(), DecodeError> {
+            let instruction = "btsw ra 3";
+            let expected =
+                Instruction::BitTestAndSet(BitTestAndSet::Word(Register::A, Operand::Immediate(3)));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+
+        #[test]
+        fn register_and_register() -> Result<(), DecodeError> {
+            let instruction = "btsw ra rb";
+            let expected = Instruction::BitTestAndSet(BitTestAndSet::Word(
+                Register::A,
+                Operand::Register(Register::B),
+            ));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+}
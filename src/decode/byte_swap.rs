@@ -0,0 +1,205 @@
+use super::ParameterDecoderHelper;
+use crate::{
+    constant::Parameters,
+    error::DecodeError,
+    instruction::{ByteSwap, Instruction},
+};
+
+pub struct ByteSwapParameterDecoder;
+
+impl ByteSwapParameterDecoder {
+    pub fn byte(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let register = ParameterDecoderHelper::try_register(parameters)?;
+        let instruction = ByteSwap::Byte(register);
+
+        Ok(Instruction::ByteSwap(instruction))
+    }
+
+    pub fn quarter(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let register = ParameterDecoderHelper::try_register(parameters)?;
+        let instruction = ByteSwap::Quarter(register);
+
+        Ok(Instruction::ByteSwap(instruction))
+    }
+
+    pub fn half(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let register = ParameterDecoderHelper::try_register(parameters)?;
+        let instruction = ByteSwap::Half(register);
+
+        Ok(Instruction::ByteSwap(instruction))
+    }
+
+    pub fn word(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let register = ParameterDecoderHelper::try_register(parameters)?;
+        let instruction = ByteSwap::Word(register);
+
+        Ok(Instruction::ByteSwap(instruction))
+    }
+}
+
+#[cfg(test)]
+mod regression {
+    mod byte {
+        use crate::{
+            decode::decode,
+            error::DecodeError,
+            instruction::{ByteSwap, Instruction},
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_param() {
+            let instruction = "bswapb";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_register_error() {
+            let instruction = "bswapb rx";
+            let expected = Err(DecodeError::InvalidRegister("rx".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn valid_register() -> Result<(), DecodeError> {
+            let instruction = "bswapb ra";
+            let expected = Instruction::ByteSwap(ByteSwap::Byte(Register::A));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod quarter {
+        use crate::{
+            decode::decode,
+            error::DecodeError,
+            instruction::{ByteSwap, Instruction},
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_param() {
+            let instruction = "bswapq";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_register_error() {
+            let instruction = "bswapq rx";
+            let expected = Err(DecodeError::InvalidRegister("rx".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn valid_register() -> Result<(), DecodeError> {
+            let instruction = "bswapq ra";
+            let expected = Instruction::ByteSwap(ByteSwap::Quarter(Register::A));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod half {
+        use crate::{
+            decode::decode,
+            error::DecodeError,
+            instruction::{ByteSwap, Instruction},
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_param() {
+            let instruction = "bswaph";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_register_error() {
+            let instruction = "bswaph rx";
+            let expected = Err(DecodeError::InvalidRegister("rx".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn valid_register() -> Result<(), DecodeError> {
+            let instruction = "bswaph ra";
+            let expected = Instruction::ByteSwap(ByteSwap::Half(Register::A));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod word {
+        use crate::{
+            decode::decode,
+            error::DecodeError,
+            instruction::{ByteSwap, Instruction},
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_param() {
+            let instruction = "bswapw";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_register_error() {
+            let instruction = "bswapw rx";
+            let expected = Err(DecodeError::InvalidRegister("rx".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn valid_register() -> Result<(), DecodeError> {
+            let instruction = "bswapw ra";
+            let expected = Instruction::ByteSwap(ByteSwap::Word(Register::A));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+}
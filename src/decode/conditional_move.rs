@@ -0,0 +1,505 @@
+use super::ParameterDecoderHelper;
+use crate::{
+    constant::Parameters,
+    error::DecodeError,
+    instruction::{Instruction, Jump, Set},
+};
+
+pub struct ConditionalMoveParameterDecoder;
+
+impl ConditionalMoveParameterDecoder {
+    pub fn if_zero_byte(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        Self::byte(parameters, Jump::IfZero)
+    }
+
+    pub fn if_zero_quarter(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        Self::quarter(parameters, Jump::IfZero)
+    }
+
+    pub fn if_zero_half(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        Self::half(parameters, Jump::IfZero)
+    }
+
+    pub fn if_zero_word(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        Self::word(parameters, Jump::IfZero)
+    }
+
+    pub fn if_not_zero_byte(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        Self::byte(parameters, Jump::IfNotZero)
+    }
+
+    pub fn if_not_zero_quarter(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        Self::quarter(parameters, Jump::IfNotZero)
+    }
+
+    pub fn if_not_zero_half(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        Self::half(parameters, Jump::IfNotZero)
+    }
+
+    pub fn if_not_zero_word(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        Self::word(parameters, Jump::IfNotZero)
+    }
+
+    pub fn if_sign_byte(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        Self::byte(parameters, Jump::IfSign)
+    }
+
+    pub fn if_sign_quarter(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        Self::quarter(parameters, Jump::IfSign)
+    }
+
+    pub fn if_sign_half(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        Self::half(parameters, Jump::IfSign)
+    }
+
+    pub fn if_sign_word(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        Self::word(parameters, Jump::IfSign)
+    }
+
+    pub fn if_not_sign_byte(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        Self::byte(parameters, Jump::IfNotSign)
+    }
+
+    pub fn if_not_sign_quarter(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        Self::quarter(parameters, Jump::IfNotSign)
+    }
+
+    pub fn if_not_sign_half(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        Self::half(parameters, Jump::IfNotSign)
+    }
+
+    pub fn if_not_sign_word(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        Self::word(parameters, Jump::IfNotSign)
+    }
+
+    pub fn if_overflow_byte(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        Self::byte(parameters, Jump::IfOverflow)
+    }
+
+    pub fn if_overflow_quarter(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        Self::quarter(parameters, Jump::IfOverflow)
+    }
+
+    pub fn if_overflow_half(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        Self::half(parameters, Jump::IfOverflow)
+    }
+
+    pub fn if_overflow_word(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        Self::word(parameters, Jump::IfOverflow)
+    }
+
+    pub fn if_not_overflow_byte(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        Self::byte(parameters, Jump::IfNotOverflow)
+    }
+
+    pub fn if_not_overflow_quarter(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        Self::quarter(parameters, Jump::IfNotOverflow)
+    }
+
+    pub fn if_not_overflow_half(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        Self::half(parameters, Jump::IfNotOverflow)
+    }
+
+    pub fn if_not_overflow_word(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        Self::word(parameters, Jump::IfNotOverflow)
+    }
+
+    pub fn if_greater_byte(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        Self::byte(parameters, Jump::IfGreater)
+    }
+
+    pub fn if_greater_quarter(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        Self::quarter(parameters, Jump::IfGreater)
+    }
+
+    pub fn if_greater_half(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        Self::half(parameters, Jump::IfGreater)
+    }
+
+    pub fn if_greater_word(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        Self::word(parameters, Jump::IfGreater)
+    }
+
+    pub fn if_lesser_byte(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        Self::byte(parameters, Jump::IfLesser)
+    }
+
+    pub fn if_lesser_quarter(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        Self::quarter(parameters, Jump::IfLesser)
+    }
+
+    pub fn if_lesser_half(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        Self::half(parameters, Jump::IfLesser)
+    }
+
+    pub fn if_lesser_word(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        Self::word(parameters, Jump::IfLesser)
+    }
+
+    pub fn if_greater_or_equal_byte(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        Self::byte(parameters, Jump::IfGreaterOrEqual)
+    }
+
+    pub fn if_greater_or_equal_quarter(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        Self::quarter(parameters, Jump::IfGreaterOrEqual)
+    }
+
+    pub fn if_greater_or_equal_half(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        Self::half(parameters, Jump::IfGreaterOrEqual)
+    }
+
+    pub fn if_greater_or_equal_word(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        Self::word(parameters, Jump::IfGreaterOrEqual)
+    }
+
+    pub fn if_lesser_or_equal_byte(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        Self::byte(parameters, Jump::IfLesserOrEqual)
+    }
+
+    pub fn if_lesser_or_equal_quarter(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        Self::quarter(parameters, Jump::IfLesserOrEqual)
+    }
+
+    pub fn if_lesser_or_equal_half(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        Self::half(parameters, Jump::IfLesserOrEqual)
+    }
+
+    pub fn if_lesser_or_equal_word(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        Self::word(parameters, Jump::IfLesserOrEqual)
+    }
+
+    fn byte(parameters: Parameters, condition: Jump) -> Result<Instruction, DecodeError> {
+        let (register, operand) = ParameterDecoderHelper::try_register_and_operand(parameters)?;
+        let instruction = Set::Byte(register, operand);
+
+        Ok(Instruction::ConditionalMove(condition, instruction))
+    }
+
+    fn quarter(parameters: Parameters, condition: Jump) -> Result<Instruction, DecodeError> {
+        let (register, operand) = ParameterDecoderHelper::try_register_and_operand(parameters)?;
+        let instruction = Set::Quarter(register, operand);
+
+        Ok(Instruction::ConditionalMove(condition, instruction))
+    }
+
+    fn half(parameters: Parameters, condition: Jump) -> Result<Instruction, DecodeError> {
+        let (register, operand) = ParameterDecoderHelper::try_register_and_operand(parameters)?;
+        let instruction = Set::Half(register, operand);
+
+        Ok(Instruction::ConditionalMove(condition, instruction))
+    }
+
+    fn word(parameters: Parameters, condition: Jump) -> Result<Instruction, DecodeError> {
+        let (register, operand) = ParameterDecoderHelper::try_register_and_operand(parameters)?;
+        let instruction = Set::Word(register, operand);
+
+        Ok(Instruction::ConditionalMove(condition, instruction))
+    }
+}
+
+#[cfg(test)]
+mod regression {
+    mod byte {
+        use crate::{
+            constant::Byte,
+            decode::decode,
+            error::DecodeError,
+            instruction::{Instruction, Jump, Set},
+            operand::Operand,
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_second_param() {
+            let instruction = "cmovizb ra";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_register_error() {
+            let instruction = "cmovizb rx 1";
+            let expected = Err(DecodeError::InvalidRegister("rx".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn register_and_immediate() -> Result<(), DecodeError> {
+            let instruction = format!("cmovnzb ra {}", Byte::MAX);
+            let expected = Instruction::ConditionalMove(
+                Jump::IfNotZero,
+                Set::Byte(Register::A, Operand::Immediate(Byte::MAX)),
+            );
+
+            let actual = decode(&instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+
+        #[test]
+        fn every_condition() -> Result<(), DecodeError> {
+            let conditions = [
+                ("iz", Jump::IfZero),
+                ("nz", Jump::IfNotZero),
+                ("is", Jump::IfSign),
+                ("ns", Jump::IfNotSign),
+                ("io", Jump::IfOverflow),
+                ("no", Jump::IfNotOverflow),
+                ("ig", Jump::IfGreater),
+                ("il", Jump::IfLesser),
+                ("ge", Jump::IfGreaterOrEqual),
+                ("le", Jump::IfLesserOrEqual),
+            ];
+
+            for (suffix, condition) in conditions {
+                let instruction = format!("cmov{suffix}b ra rb");
+                let expected = Instruction::ConditionalMove(
+                    condition,
+                    Set::Byte(Register::A, Operand::Register(Register::B)),
+                );
+
+                let actual = decode(&instruction)?;
+
+                assert_eq!(actual, expected);
+            }
+
+            Ok(())
+        }
+    }
+
+    mod quarter {
+        use crate::{
+            constant::Quarter,
+            decode::decode,
+            error::DecodeError,
+            instruction::{Instruction, Jump, Set},
+            operand::Operand,
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_second_param() {
+            let instruction = "cmovizq ra";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_register_error() {
+            let instruction = "cmovizq rx 1";
+            let expected = Err(DecodeError::InvalidRegister("rx".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn register_and_immediate() -> Result<(), DecodeError> {
+            let instruction = format!("cmovnzq ra {}", Quarter::MAX);
+            let expected = Instruction::ConditionalMove(
+                Jump::IfNotZero,
+                Set::Quarter(Register::A, Operand::Immediate(Quarter::MAX)),
+            );
+
+            let actual = decode(&instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+
+        #[test]
+        fn every_condition() -> Result<(), DecodeError> {
+            let conditions = [
+                ("iz", Jump::IfZero),
+                ("nz", Jump::IfNotZero),
+                ("is", Jump::IfSign),
+                ("ns", Jump::IfNotSign),
+                ("io", Jump::IfOverflow),
+                ("no", Jump::IfNotOverflow),
+                ("ig", Jump::IfGreater),
+                ("il", Jump::IfLesser),
+                ("ge", Jump::IfGreaterOrEqual),
+                ("le", Jump::IfLesserOrEqual),
+            ];
+
+            for (suffix, condition) in conditions {
+                let instruction = format!("cmov{suffix}q ra rb");
+                let expected = Instruction::ConditionalMove(
+                    condition,
+                    Set::Quarter(Register::A, Operand::Register(Register::B)),
+                );
+
+                let actual = decode(&instruction)?;
+
+                assert_eq!(actual, expected);
+            }
+
+            Ok(())
+        }
+    }
+
+    mod half {
+        use crate::{
+            constant::Half,
+            decode::decode,
+            error::DecodeError,
+            instruction::{Instruction, Jump, Set},
+            operand::Operand,
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_second_param() {
+            let instruction = "cmovizh ra";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_register_error() {
+            let instruction = "cmovizh rx 1";
+            let expected = Err(DecodeError::InvalidRegister("rx".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn register_and_immediate() -> Result<(), DecodeError> {
+            let instruction = format!("cmovnzh ra {}", Half::MAX);
+            let expected = Instruction::ConditionalMove(
+                Jump::IfNotZero,
+                Set::Half(Register::A, Operand::Immediate(Half::MAX)),
+            );
+
+            let actual = decode(&instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+
+        #[test]
+        fn every_condition() -> Result<(), DecodeError> {
+            let conditions = [
+                ("iz", Jump::IfZero),
+                ("nz", Jump::IfNotZero),
+                ("is", Jump::IfSign),
+                ("ns", Jump::IfNotSign),
+                ("io", Jump::IfOverflow),
+                ("no", Jump::IfNotOverflow),
+                ("ig", Jump::IfGreater),
+                ("il", Jump::IfLesser),
+                ("ge", Jump::IfGreaterOrEqual),
+                ("le", Jump::IfLesserOrEqual),
+            ];
+
+            for (suffix, condition) in conditions {
+                let instruction = format!("cmov{suffix}h ra rb");
+                let expected = Instruction::ConditionalMove(
+                    condition,
+                    Set::Half(Register::A, Operand::Register(Register::B)),
+                );
+
+                let actual = decode(&instruction)?;
+
+                assert_eq!(actual, expected);
+            }
+
+            Ok(())
+        }
+    }
+
+    mod word {
+        use crate::{
+            constant::Word,
+            decode::decode,
+            error::DecodeError,
+            instruction::{Instruction, Jump, Set},
+            operand::Operand,
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_second_param() {
+            let instruction = "cmovizw ra";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_register_error() {
+            let instruction = "cmovizw rx 1";
+            let expected = Err(DecodeError::InvalidRegister("rx".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn register_and_immediate() -> Result<(), DecodeError> {
+            let instruction = format!("cmovnzw ra {}", Word::MAX);
+            let expected = Instruction::ConditionalMove(
+                Jump::IfNotZero,
+                Set::Word(Register::A, Operand::Immediate(Word::MAX)),
+            );
+
+            let actual = decode(&instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+
+        #[test]
+        fn every_condition() -> Result<(), DecodeError> {
+            let conditions = [
+                ("iz", Jump::IfZero),
+                ("nz", Jump::IfNotZero),
+                ("is", Jump::IfSign),
+                ("ns", Jump::IfNotSign),
+                ("io", Jump::IfOverflow),
+                ("no", Jump::IfNotOverflow),
+                ("ig", Jump::IfGreater),
+                ("il", Jump::IfLesser),
+                ("ge", Jump::IfGreaterOrEqual),
+                ("le", Jump::IfLesserOrEqual),
+            ];
+
+            for (suffix, condition) in conditions {
+                let instruction = format!("cmov{suffix}w ra rb");
+                let expected = Instruction::ConditionalMove(
+                    condition,
+                    Set::Word(Register::A, Operand::Register(Register::B)),
+                );
+
+                let actual = decode(&instruction)?;
+
+                assert_eq!(actual, expected);
+            }
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use crate::{
+    constant::Parameters,
+    error::DecodeError,
+    instruction::{Discard, Instruction},
+};
+
+pub struct DiscardParameterDecoder;
+
+impl DiscardParameterDecoder {
+    pub fn byte(_parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let instruction = Discard::Byte;
+
+        Ok(Instruction::Discard(instruction))
+    }
+
+    pub fn quarter(_parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let instruction = Discard::Quarter;
+
+        Ok(Instruction::Discard(instruction))
+    }
+
+    pub fn half(_parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let instruction = Discard::Half;
+
+        Ok(Instruction::Discard(instruction))
+    }
+
+    pub fn word(_parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let instruction = Discard::Word;
+
+        Ok(Instruction::Discard(instruction))
+    }
+}
+
+#[cfg(test)]
+mod regression {
+    mod byte {
+        use crate::{
+            decode::decode,
+            error::DecodeError,
+            instruction::{Discard, Instruction},
+        };
+
+        #[test]
+        fn valid_instruction() -> Result<(), DecodeError> {
+            let instruction = "dropb";
+            let expected = Instruction::Discard(Discard::Byte);
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod quarter {
+        use crate::{
+            decode::decode,
+            error::DecodeError,
+            instruction::{Discard, Instruction},
+        };
+
+        #[test]
+        fn valid_instruction() -> Result<(), DecodeError> {
+            let instruction = "dropq";
+            let expected = Instruction::Discard(Discard::Quarter);
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod half {
+        use crate::{
+            decode::decode,
+            error::DecodeError,
+            instruction::{Discard, Instruction},
+        };
+
+        #[test]
+        fn valid_instruction() -> Result<(), DecodeError> {
+            let instruction = "droph";
+            let expected = Instruction::Discard(Discard::Half);
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod word {
+        use crate::{
+            decode::decode,
+            error::DecodeError,
+            instruction::{Discard, Instruction},
+        };
+
+        #[test]
+        fn valid_instruction() -> Result<(), DecodeError> {
+            let instruction = "dropw";
+            let expected = Instruction::Discard(Discard::Word);
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,337 @@
+use super::ParameterDecoderHelper;
+use crate::{
+    constant::Parameters,
+    error::DecodeError,
+    instruction::{DivisionRemainder, Instruction},
+};
+
+pub struct DivisionRemainderParameterDecoder;
+
+impl DivisionRemainderParameterDecoder {
+    pub fn byte(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let (quotient, remainder, operand) =
+            ParameterDecoderHelper::try_double_register_and_operand(parameters)?;
+        let instruction = DivisionRemainder::Byte(quotient, remainder, operand);
+
+        Ok(Instruction::DivisionRemainder(instruction))
+    }
+
+    pub fn quarter(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let (quotient, remainder, operand) =
+            ParameterDecoderHelper::try_double_register_and_operand(parameters)?;
+        let instruction = DivisionRemainder::Quarter(quotient, remainder, operand);
+
+        Ok(Instruction::DivisionRemainder(instruction))
+    }
+
+    pub fn half(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let (quotient, remainder, operand) =
+            ParameterDecoderHelper::try_double_register_and_operand(parameters)?;
+        let instruction = DivisionRemainder::Half(quotient, remainder, operand);
+
+        Ok(Instruction::DivisionRemainder(instruction))
+    }
+
+    pub fn word(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let (quotient, remainder, operand) =
+            ParameterDecoderHelper::try_double_register_and_operand(parameters)?;
+        let instruction = DivisionRemainder::Word(quotient, remainder, operand);
+
+        Ok(Instruction::DivisionRemainder(instruction))
+    }
+}
+
+#[cfg(test)]
+mod regression {
+    mod byte {
+        use crate::{
+            constant::Byte,
+            decode::decode,
+            error::DecodeError,
+            instruction::{DivisionRemainder, Instruction},
+            operand::Operand,
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_third_param() {
+            let instruction = "divremb ra rb";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_register_error_in_second_param() {
+            let instruction = "divremb ra rx 1";
+            let expected = Err(DecodeError::InvalidRegister("rx".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_immediate_value_error() {
+            let instruction = "divremb ra rb -1";
+            let expected = Err(DecodeError::InvalidImmediateValue("-1".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn third_param_immediate() -> Result<(), DecodeError> {
+            let instruction = format!("divremb ra rb {}", Byte::MAX);
+            let expected = Instruction::DivisionRemainder(DivisionRemainder::Byte(
+                Register::A,
+                Register::B,
+                Operand::Immediate(Byte::MAX),
+            ));
+
+            let actual = decode(&instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+
+        #[test]
+        fn third_param_register() -> Result<(), DecodeError> {
+            let instruction = "divremb ra rb rc";
+            let expected = Instruction::DivisionRemainder(DivisionRemainder::Byte(
+                Register::A,
+                Register::B,
+                Operand::Register(Register::C),
+            ));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod quarter {
+        use crate::{
+            constant::Quarter,
+            decode::decode,
+            error::DecodeError,
+            instruction::{DivisionRemainder, Instruction},
+            operand::Operand,
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_third_param() {
+            let instruction = "divremq ra rb";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_register_error_in_second_param() {
+            let instruction = "divremq ra rx 1";
+            let expected = Err(DecodeError::InvalidRegister("rx".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_immediate_value_error() {
+            let instruction = "divremq ra rb -1";
+            let expected = Err(DecodeError::InvalidImmediateValue("-1".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn third_param_immediate() -> Result<(), DecodeError> {
+            let instruction = format!("divremq ra rb {}", Quarter::MAX);
+            let expected = Instruction::DivisionRemainder(DivisionRemainder::Quarter(
+                Register::A,
+                Register::B,
+                Operand::Immediate(Quarter::MAX),
+            ));
+
+            let actual = decode(&instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+
+        #[test]
+        fn third_param_register() -> Result<(), DecodeError> {
+            let instruction = "divremq ra rb rc";
+            let expected = Instruction::DivisionRemainder(DivisionRemainder::Quarter(
+                Register::A,
+                Register::B,
+                Operand::Register(Register::C),
+            ));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod half {
+        use crate::{
+            constant::Half,
+            decode::decode,
+            error::DecodeError,
+            instruction::{DivisionRemainder, Instruction},
+            operand::Operand,
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_third_param() {
+            let instruction = "divremh ra rb";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_register_error_in_second_param() {
+            let instruction = "divremh ra rx 1";
+            let expected = Err(DecodeError::InvalidRegister("rx".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_immediate_value_error() {
+            let instruction = "divremh ra rb -1";
+            let expected = Err(DecodeError::InvalidImmediateValue("-1".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn third_param_immediate() -> Result<(), DecodeError> {
+            let instruction = format!("divremh ra rb {}", Half::MAX);
+            let expected = Instruction::DivisionRemainder(DivisionRemainder::Half(
+                Register::A,
+                Register::B,
+                Operand::Immediate(Half::MAX),
+            ));
+
+            let actual = decode(&instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+
+        #[test]
+        fn third_param_register() -> Result<(), DecodeError> {
+            let instruction = "divremh ra rb rc";
+            let expected = Instruction::DivisionRemainder(DivisionRemainder::Half(
+                Register::A,
+                Register::B,
+                Operand::Register(Register::C),
+            ));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod word {
+        use crate::{
+            constant::Word,
+            decode::decode,
+            error::DecodeError,
+            instruction::{DivisionRemainder, Instruction},
+            operand::Operand,
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_third_param() {
+            let instruction = "divremw ra rb";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_register_error_in_second_param() {
+            let instruction = "divremw ra rx 1";
+            let expected = Err(DecodeError::InvalidRegister("rx".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_immediate_value_error() {
+            let instruction = "divremw ra rb -1";
+            let expected = Err(DecodeError::InvalidImmediateValue("-1".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn third_param_immediate() -> Result<(), DecodeError> {
+            let instruction = format!("divremw ra rb {}", Word::MAX);
+            let expected = Instruction::DivisionRemainder(DivisionRemainder::Word(
+                Register::A,
+                Register::B,
+                Operand::Immediate(Word::MAX),
+            ));
+
+            let actual = decode(&instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+
+        #[test]
+        fn third_param_register() -> Result<(), DecodeError> {
+            let instruction = "divremw ra rb rc";
+            let expected = Instruction::DivisionRemainder(DivisionRemainder::Word(
+                Register::A,
+                Register::B,
+                Operand::Register(Register::C),
+            ));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use crate::{
+    constant::Parameters,
+    error::DecodeError,
+    instruction::{Duplicate, Instruction},
+};
+
+pub struct DuplicateParameterDecoder;
+
+impl DuplicateParameterDecoder {
+    pub fn byte(_parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let instruction = Duplicate::Byte;
+
+        Ok(Instruction::Duplicate(instruction))
+    }
+
+    pub fn quarter(_parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let instruction = Duplicate::Quarter;
+
+        Ok(Instruction::Duplicate(instruction))
+    }
+
+    pub fn half(_parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let instruction = Duplicate::Half;
+
+        Ok(Instruction::Duplicate(instruction))
+    }
+
+    pub fn word(_parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let instruction = Duplicate::Word;
+
+        Ok(Instruction::Duplicate(instruction))
+    }
+}
+
+#[cfg(test)]
+mod regression {
+    mod byte {
+        use crate::{
+            decode::decode,
+            error::DecodeError,
+            instruction::{Duplicate, Instruction},
+        };
+
+        #[test]
+        fn valid_instruction() -> Result<(), DecodeError> {
+            let instruction = "dupb";
+            let expected = Instruction::Duplicate(Duplicate::Byte);
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod quarter {
+        use crate::{
+            decode::decode,
+            error::DecodeError,
+            instruction::{Duplicate, Instruction},
+        };
+
+        #[test]
+        fn valid_instruction() -> Result<(), DecodeError> {
+            let instruction = "dupq";
+            let expected = Instruction::Duplicate(Duplicate::Quarter);
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod half {
+        use crate::{
+            decode::decode,
+            error::DecodeError,
+            instruction::{Duplicate, Instruction},
+        };
+
+        #[test]
+        fn valid_instruction() -> Result<(), DecodeError> {
+            let instruction = "duph";
+            let expected = Instruction::Duplicate(Duplicate::Half);
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod word {
+        use crate::{
+            decode::decode,
+            error::DecodeError,
+            instruction::{Duplicate, Instruction},
+        };
+
+        #[test]
+        fn valid_instruction() -> Result<(), DecodeError> {
+            let instruction = "dupw";
+            let expected = Instruction::Duplicate(Duplicate::Word);
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+}
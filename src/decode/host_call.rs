@@ -0,0 +1,51 @@
+use super::try_get_first_parameter_str;
+use crate::{constant::Parameters, error::DecodeError, instruction::Instruction};
+
+pub struct HostCallParameterDecoder;
+
+impl HostCallParameterDecoder {
+    pub fn call(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let name = try_get_first_parameter_str(parameters)?;
+
+        Ok(Instruction::HostCall(name.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod regression {
+    use crate::{decode::decode, error::DecodeError, instruction::Instruction};
+
+    #[test]
+    fn incomplete_instruction_error_missing_param() {
+        let instruction = "sys";
+        let expected = Err(DecodeError::IncompleteInstruction);
+
+        let actual = decode(instruction);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn sys() -> Result<(), DecodeError> {
+        let instruction = "sys clock";
+        let expected = Instruction::HostCall("clock".to_string());
+
+        let actual = decode(instruction)?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn ecall() -> Result<(), DecodeError> {
+        let instruction = "ecall file_read";
+        let expected = Instruction::HostCall("file_read".to_string());
+
+        let actual = decode(instruction)?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
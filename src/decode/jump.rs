@@ -3,6 +3,7 @@ use crate::{
     error::DecodeError,
     instruction::{Instruction, Jump, Relative},
     operand::Operand,
+    register::Register,
 };
 
 pub struct JumpParameterDecoder;
@@ -150,6 +151,22 @@ impl JumpParameterDecoder {
 
         Ok(Instruction::Jump(instruction, operand, relative))
     }
+
+    pub fn table(mut parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let (Some(s_register), Some(s_base), Some(s_count)) =
+            (parameters.next(), parameters.next(), parameters.next())
+        else {
+            return Err(DecodeError::IncompleteInstruction);
+        };
+
+        let register = Register::try_from(s_register)?;
+        let mut base = s_base.to_string();
+        let relative = is_relative(&mut base);
+        let base = Operand::try_from(base.as_str())?;
+        let count = Operand::try_from(s_count)?;
+
+        Ok(Instruction::JumpTable(register, base, relative, count))
+    }
 }
 
 fn is_relative(s: &mut String) -> Option<Relative> {
@@ -1775,4 +1792,95 @@ mod regression {
             Ok(())
         }
     }
+
+    mod table {
+        use crate::{
+            decode::decode,
+            error::DecodeError,
+            instruction::{Instruction, Relative},
+            operand::Operand,
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_third_param() {
+            let instruction = "jtab ra 10";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_register_error() {
+            let instruction = "jtab 10 10 2";
+            let expected = Err(DecodeError::InvalidRegister("10".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_operand_error_in_count() {
+            let instruction = "jtab ra 10 +2";
+            let expected = Err(DecodeError::InvalidOperand("+2".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn absolute_base() -> Result<(), DecodeError> {
+            let instruction = "jtab ra 10 rb";
+            let expected = Instruction::JumpTable(
+                Register::A,
+                Operand::Immediate(10),
+                None,
+                Operand::Register(Register::B),
+            );
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+
+        #[test]
+        fn relative_positive_base() -> Result<(), DecodeError> {
+            let instruction = "jtab ra +1 3";
+            let expected = Instruction::JumpTable(
+                Register::A,
+                Operand::Immediate(1),
+                Some(Relative::Positive),
+                Operand::Immediate(3),
+            );
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+
+        #[test]
+        fn relative_negative_base() -> Result<(), DecodeError> {
+            let instruction = "jtab ra -rc 3";
+            let expected = Instruction::JumpTable(
+                Register::A,
+                Operand::Register(Register::C),
+                Some(Relative::Negative),
+                Operand::Immediate(3),
+            );
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
 }
@@ -0,0 +1,205 @@
+use super::ParameterDecoderHelper;
+use crate::{
+    constant::Parameters,
+    error::DecodeError,
+    instruction::{Instruction, LeadingZeros},
+};
+
+pub struct LeadingZerosParameterDecoder;
+
+impl LeadingZerosParameterDecoder {
+    pub fn byte(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let register = ParameterDecoderHelper::try_register(parameters)?;
+        let instruction = LeadingZeros::Byte(register);
+
+        Ok(Instruction::LeadingZeros(instruction))
+    }
+
+    pub fn quarter(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let register = ParameterDecoderHelper::try_register(parameters)?;
+        let instruction = LeadingZeros::Quarter(register);
+
+        Ok(Instruction::LeadingZeros(instruction))
+    }
+
+    pub fn half(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let register = ParameterDecoderHelper::try_register(parameters)?;
+        let instruction = LeadingZeros::Half(register);
+
+        Ok(Instruction::LeadingZeros(instruction))
+    }
+
+    pub fn word(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let register = ParameterDecoderHelper::try_register(parameters)?;
+        let instruction = LeadingZeros::Word(register);
+
+        Ok(Instruction::LeadingZeros(instruction))
+    }
+}
+
+#[cfg(test)]
+mod regression {
+    mod byte {
+        use crate::{
+            decode::decode,
+            error::DecodeError,
+            instruction::{Instruction, LeadingZeros},
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_param() {
+            let instruction = "clzb";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_register_error() {
+            let instruction = "clzb rx";
+            let expected = Err(DecodeError::InvalidRegister("rx".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn valid_register() -> Result<(), DecodeError> {
+            let instruction = "clzb ra";
+            let expected = Instruction::LeadingZeros(LeadingZeros::Byte(Register::A));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod quarter {
+        use crate::{
+            decode::decode,
+            error::DecodeError,
+            instruction::{Instruction, LeadingZeros},
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_param() {
+            let instruction = "clzq";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_register_error() {
+            let instruction = "clzq rx";
+            let expected = Err(DecodeError::InvalidRegister("rx".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn valid_register() -> Result<(), DecodeError> {
+            let instruction = "clzq ra";
+            let expected = Instruction::LeadingZeros(LeadingZeros::Quarter(Register::A));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod half {
+        use crate::{
+            decode::decode,
+            error::DecodeError,
+            instruction::{Instruction, LeadingZeros},
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_param() {
+            let instruction = "clzh";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_register_error() {
+            let instruction = "clzh rx";
+            let expected = Err(DecodeError::InvalidRegister("rx".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn valid_register() -> Result<(), DecodeError> {
+            let instruction = "clzh ra";
+            let expected = Instruction::LeadingZeros(LeadingZeros::Half(Register::A));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod word {
+        use crate::{
+            decode::decode,
+            error::DecodeError,
+            instruction::{Instruction, LeadingZeros},
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_param() {
+            let instruction = "clzw";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_register_error() {
+            let instruction = "clzw rx";
+            let expected = Err(DecodeError::InvalidRegister("rx".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn valid_register() -> Result<(), DecodeError> {
+            let instruction = "clzw ra";
+            let expected = Instruction::LeadingZeros(LeadingZeros::Word(Register::A));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+}
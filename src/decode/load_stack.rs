@@ -0,0 +1,273 @@
+use super::ParameterDecoderHelper;
+use crate::{
+    constant::Parameters,
+    error::DecodeError,
+    instruction::{Instruction, LoadStack},
+};
+
+pub struct LoadStackParameterDecoder;
+
+impl LoadStackParameterDecoder {
+    pub fn byte(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let (register, offset) = ParameterDecoderHelper::try_register_and_operand(parameters)?;
+        let instruction = LoadStack::Byte(register, offset);
+
+        Ok(Instruction::LoadStack(instruction))
+    }
+
+    pub fn quarter(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let (register, offset) = ParameterDecoderHelper::try_register_and_operand(parameters)?;
+        let instruction = LoadStack::Quarter(register, offset);
+
+        Ok(Instruction::LoadStack(instruction))
+    }
+
+    pub fn half(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let (register, offset) = ParameterDecoderHelper::try_register_and_operand(parameters)?;
+        let instruction = LoadStack::Half(register, offset);
+
+        Ok(Instruction::LoadStack(instruction))
+    }
+
+    pub fn word(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let (register, offset) = ParameterDecoderHelper::try_register_and_operand(parameters)?;
+        let instruction = LoadStack::Word(register, offset);
+
+        Ok(Instruction::LoadStack(instruction))
+    }
+}
+
+#[cfg(test)]
+mod regression {
+    mod byte {
+        use crate::{
+            decode::decode,
+            error::DecodeError,
+            instruction::{Instruction, LoadStack},
+            operand::Operand,
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_second_param() {
+            let instruction = "ldsb ra";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_register_error() {
+            let instruction = "ldsb rx 0";
+            let expected = Err(DecodeError::InvalidRegister("rx".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn valid_immediate_offset() -> Result<(), DecodeError> {
+            let instruction = "ldsb ra 8";
+            let expected =
+                Instruction::LoadStack(LoadStack::Byte(Register::A, Operand::Immediate(8)));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+
+        #[test]
+        fn valid_register_offset() -> Result<(), DecodeError> {
+            let instruction = "ldsb ra rb";
+            let expected = Instruction::LoadStack(LoadStack::Byte(
+                Register::A,
+                Operand::Register(Register::B),
+            ));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod quarter {
+        use crate::{
+            decode::decode,
+            error::DecodeError,
+            instruction::{Instruction, LoadStack},
+            operand::Operand,
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_second_param() {
+            let instruction = "ldsq ra";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_register_error() {
+            let instruction = "ldsq rx 0";
+            let expected = Err(DecodeError::InvalidRegister("rx".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn valid_immediate_offset() -> Result<(), DecodeError> {
+            let instruction = "ldsq ra 8";
+            let expected =
+                Instruction::LoadStack(LoadStack::Quarter(Register::A, Operand::Immediate(8)));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+
+        #[test]
+        fn valid_register_offset() -> Result<(), DecodeError> {
+            let instruction = "ldsq ra rb";
+            let expected = Instruction::LoadStack(LoadStack::Quarter(
+                Register::A,
+                Operand::Register(Register::B),
+            ));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod half {
+        use crate::{
+            decode::decode,
+            error::DecodeError,
+            instruction::{Instruction, LoadStack},
+            operand::Operand,
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_second_param() {
+            let instruction = "ldsh ra";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_register_error() {
+            let instruction = "ldsh rx 0";
+            let expected = Err(DecodeError::InvalidRegister("rx".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn valid_immediate_offset() -> Result<(), DecodeError> {
+            let instruction = "ldsh ra 8";
+            let expected =
+                Instruction::LoadStack(LoadStack::Half(Register::A, Operand::Immediate(8)));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+
+        #[test]
+        fn valid_register_offset() -> Result<(), DecodeError> {
+            let instruction = "ldsh ra rb";
+            let expected = Instruction::LoadStack(LoadStack::Half(
+                Register::A,
+                Operand::Register(Register::B),
+            ));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod word {
+        use crate::{
+            decode::decode,
+            error::DecodeError,
+            instruction::{Instruction, LoadStack},
+            operand::Operand,
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_second_param() {
+            let instruction = "ldsw ra";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_register_error() {
+            let instruction = "ldsw rx 0";
+            let expected = Err(DecodeError::InvalidRegister("rx".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn valid_immediate_offset() -> Result<(), DecodeError> {
+            let instruction = "ldsw ra 8";
+            let expected =
+                Instruction::LoadStack(LoadStack::Word(Register::A, Operand::Immediate(8)));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+
+        #[test]
+        fn valid_register_offset() -> Result<(), DecodeError> {
+            let instruction = "ldsw ra rb";
+            let expected = Instruction::LoadStack(LoadStack::Word(
+                Register::A,
+                Operand::Register(Register::B),
+            ));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+}
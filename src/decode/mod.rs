@@ -1,5 +1,5 @@
 use crate::{
-    constant::{DecodeTable, Parameters},
+    constant::{DecodeTable, Parameters, Word, COMMENT},
     error::DecodeError,
     instruction::Instruction,
     operand::Operand,
@@ -7,40 +7,88 @@ use crate::{
 };
 use addition::AdditionParameterDecoder;
 use and::AndParameterDecoder;
+use bit_extract::BitExtractParameterDecoder;
+use bit_insert::BitInsertParameterDecoder;
+use bit_test::BitTestParameterDecoder;
+use bit_test_and_complement::BitTestAndComplementParameterDecoder;
+use bit_test_and_reset::BitTestAndResetParameterDecoder;
+use bit_test_and_set::BitTestAndSetParameterDecoder;
+use byte_swap::ByteSwapParameterDecoder;
 use compare::CompareParameterDecoder;
+use conditional_move::ConditionalMoveParameterDecoder;
+use discard::DiscardParameterDecoder;
 use division::DivisionParameterDecoder;
+use division_remainder::DivisionRemainderParameterDecoder;
+use duplicate::DuplicateParameterDecoder;
+use host_call::HostCallParameterDecoder;
 use jump::JumpParameterDecoder;
+use leading_zeros::LeadingZerosParameterDecoder;
+use load_stack::LoadStackParameterDecoder;
 use multiplication::MultiplicationParameterDecoder;
 use not::NotParameterDecoder;
 use or::OrParameterDecoder;
+use peek::PeekParameterDecoder;
 use phf::phf_map;
 use pop::PopParameterDecoder;
+use pop_count::PopCountParameterDecoder;
 use print_register::PrintRegisterParameterDecoder;
 use print_stack::PrintStackParameterDecoder;
+use print_value::PrintValueParameterDecoder;
+use program_counter::ProgramCounterParameterDecoder;
 use push::PushParameterDecoder;
 use remainder::RemainderParameterDecoder;
 use set::SetParameterDecoder;
+use set_condition::SetConditionParameterDecoder;
+use stack_pointer::StackPointerParameterDecoder;
 use std::str::FromStr;
+use store_stack::StoreStackParameterDecoder;
 use subtraction::SubtractionParameterDecoder;
+use swap::SwapParameterDecoder;
 use test::TestParameterDecoder;
+use trailing_zeros::TrailingZerosParameterDecoder;
+use widening_multiplication::WideningMultiplicationParameterDecoder;
 use xor::XorParameterDecoder;
 
 mod addition;
 mod and;
+mod bit_extract;
+mod bit_insert;
+mod bit_test;
+mod bit_test_and_complement;
+mod bit_test_and_reset;
+mod bit_test_and_set;
+mod byte_swap;
 mod compare;
+mod conditional_move;
+mod discard;
 mod division;
+mod division_remainder;
+mod duplicate;
+mod host_call;
 mod jump;
+mod leading_zeros;
+mod load_stack;
 mod multiplication;
 mod not;
 mod or;
+mod peek;
 mod pop;
+mod pop_count;
 mod print_register;
 mod print_stack;
+mod print_value;
+mod program_counter;
 mod push;
 mod remainder;
 mod set;
+mod set_condition;
+mod stack_pointer;
+mod store_stack;
 mod subtraction;
+mod swap;
 mod test;
+mod trailing_zeros;
+mod widening_multiplication;
 mod xor;
 
 /// Decodes an instruction in string representation into an [`Instruction`].
@@ -60,6 +108,18 @@ pub(super) fn decode(s: &str) -> Result<Instruction, DecodeError> {
     Ok(instruction)
 }
 
+/// Decodes a line of a preprocessed program, where comments have no instruction.
+///
+/// # Errors
+/// An error is returned if the line is not a comment and could not be decoded.
+pub(super) fn decode_line(s: &str) -> Result<Option<Instruction>, DecodeError> {
+    if s.starts_with(COMMENT) {
+        return Ok(None);
+    }
+
+    decode(s).map(Some)
+}
+
 pub const DECODE_TABLE: DecodeTable = phf_map! {
     "addb" => AdditionParameterDecoder::byte,
     "addq" => AdditionParameterDecoder::quarter,
@@ -74,10 +134,60 @@ pub const DECODE_TABLE: DecodeTable = phf_map! {
     "cmpq" => CompareParameterDecoder::quarter,
     "cmph" => CompareParameterDecoder::half,
     "cmpw" => CompareParameterDecoder::word,
+    "cmovizb" => ConditionalMoveParameterDecoder::if_zero_byte,
+    "cmovizq" => ConditionalMoveParameterDecoder::if_zero_quarter,
+    "cmovizh" => ConditionalMoveParameterDecoder::if_zero_half,
+    "cmovizw" => ConditionalMoveParameterDecoder::if_zero_word,
+    "cmovnzb" => ConditionalMoveParameterDecoder::if_not_zero_byte,
+    "cmovnzq" => ConditionalMoveParameterDecoder::if_not_zero_quarter,
+    "cmovnzh" => ConditionalMoveParameterDecoder::if_not_zero_half,
+    "cmovnzw" => ConditionalMoveParameterDecoder::if_not_zero_word,
+    "cmovisb" => ConditionalMoveParameterDecoder::if_sign_byte,
+    "cmovisq" => ConditionalMoveParameterDecoder::if_sign_quarter,
+    "cmovish" => ConditionalMoveParameterDecoder::if_sign_half,
+    "cmovisw" => ConditionalMoveParameterDecoder::if_sign_word,
+    "cmovnsb" => ConditionalMoveParameterDecoder::if_not_sign_byte,
+    "cmovnsq" => ConditionalMoveParameterDecoder::if_not_sign_quarter,
+    "cmovnsh" => ConditionalMoveParameterDecoder::if_not_sign_half,
+    "cmovnsw" => ConditionalMoveParameterDecoder::if_not_sign_word,
+    "cmoviob" => ConditionalMoveParameterDecoder::if_overflow_byte,
+    "cmovioq" => ConditionalMoveParameterDecoder::if_overflow_quarter,
+    "cmovioh" => ConditionalMoveParameterDecoder::if_overflow_half,
+    "cmoviow" => ConditionalMoveParameterDecoder::if_overflow_word,
+    "cmovnob" => ConditionalMoveParameterDecoder::if_not_overflow_byte,
+    "cmovnoq" => ConditionalMoveParameterDecoder::if_not_overflow_quarter,
+    "cmovnoh" => ConditionalMoveParameterDecoder::if_not_overflow_half,
+    "cmovnow" => ConditionalMoveParameterDecoder::if_not_overflow_word,
+    "cmovigb" => ConditionalMoveParameterDecoder::if_greater_byte,
+    "cmovigq" => ConditionalMoveParameterDecoder::if_greater_quarter,
+    "cmovigh" => ConditionalMoveParameterDecoder::if_greater_half,
+    "cmovigw" => ConditionalMoveParameterDecoder::if_greater_word,
+    "cmovilb" => ConditionalMoveParameterDecoder::if_lesser_byte,
+    "cmovilq" => ConditionalMoveParameterDecoder::if_lesser_quarter,
+    "cmovilh" => ConditionalMoveParameterDecoder::if_lesser_half,
+    "cmovilw" => ConditionalMoveParameterDecoder::if_lesser_word,
+    "cmovgeb" => ConditionalMoveParameterDecoder::if_greater_or_equal_byte,
+    "cmovgeq" => ConditionalMoveParameterDecoder::if_greater_or_equal_quarter,
+    "cmovgeh" => ConditionalMoveParameterDecoder::if_greater_or_equal_half,
+    "cmovgew" => ConditionalMoveParameterDecoder::if_greater_or_equal_word,
+    "cmovleb" => ConditionalMoveParameterDecoder::if_lesser_or_equal_byte,
+    "cmovleq" => ConditionalMoveParameterDecoder::if_lesser_or_equal_quarter,
+    "cmovleh" => ConditionalMoveParameterDecoder::if_lesser_or_equal_half,
+    "cmovlew" => ConditionalMoveParameterDecoder::if_lesser_or_equal_word,
     "divb" => DivisionParameterDecoder::byte,
     "divq" => DivisionParameterDecoder::quarter,
     "divh" => DivisionParameterDecoder::half,
     "divw" => DivisionParameterDecoder::word,
+    "dropb" => DiscardParameterDecoder::byte,
+    "dropq" => DiscardParameterDecoder::quarter,
+    "droph" => DiscardParameterDecoder::half,
+    "dropw" => DiscardParameterDecoder::word,
+    "dupb" => DuplicateParameterDecoder::byte,
+    "dupq" => DuplicateParameterDecoder::quarter,
+    "duph" => DuplicateParameterDecoder::half,
+    "dupw" => DuplicateParameterDecoder::word,
+    "getpc" => ProgramCounterParameterDecoder::get,
+    "getsp" => StackPointerParameterDecoder::get,
     "jmp" => JumpParameterDecoder::unconditional,
     "jiz" => JumpParameterDecoder::if_zero,
     "jnz" => JumpParameterDecoder::if_not_zero,
@@ -89,6 +199,11 @@ pub const DECODE_TABLE: DecodeTable = phf_map! {
     "jil" => JumpParameterDecoder::if_lesser,
     "jge" => JumpParameterDecoder::if_greater_or_equal,
     "jle" => JumpParameterDecoder::if_lesser_or_equal,
+    "jtab" => JumpParameterDecoder::table,
+    "ldsb" => LoadStackParameterDecoder::byte,
+    "ldsq" => LoadStackParameterDecoder::quarter,
+    "ldsh" => LoadStackParameterDecoder::half,
+    "ldsw" => LoadStackParameterDecoder::word,
     "mulb" => MultiplicationParameterDecoder::byte,
     "mulq" => MultiplicationParameterDecoder::quarter,
     "mulh" => MultiplicationParameterDecoder::half,
@@ -101,6 +216,10 @@ pub const DECODE_TABLE: DecodeTable = phf_map! {
     "orq" => OrParameterDecoder::quarter,
     "orh" => OrParameterDecoder::half,
     "orw" => OrParameterDecoder::word,
+    "peekb" => PeekParameterDecoder::byte,
+    "peekq" => PeekParameterDecoder::quarter,
+    "peekh" => PeekParameterDecoder::half,
+    "peekw" => PeekParameterDecoder::word,
     "popb" => PopParameterDecoder::byte,
     "popq" => PopParameterDecoder::quarter,
     "poph" => PopParameterDecoder::half,
@@ -114,6 +233,12 @@ pub const DECODE_TABLE: DecodeTable = phf_map! {
     "prsh" => PrintStackParameterDecoder::half,
     "prsw" => PrintStackParameterDecoder::word,
     "prss" => PrintStackParameterDecoder::str,
+    "prvb" => PrintValueParameterDecoder::byte,
+    "prvq" => PrintValueParameterDecoder::quarter,
+    "prvh" => PrintValueParameterDecoder::half,
+    "prvw" => PrintValueParameterDecoder::word,
+    "prnl" => PrintValueParameterDecoder::newline,
+    "prsp" => PrintValueParameterDecoder::space,
     "pshb" => PushParameterDecoder::byte,
     "pshq" => PushParameterDecoder::quarter,
     "pshh" => PushParameterDecoder::half,
@@ -127,11 +252,30 @@ pub const DECODE_TABLE: DecodeTable = phf_map! {
     "setq" => SetParameterDecoder::quarter,
     "seth" => SetParameterDecoder::half,
     "setw" => SetParameterDecoder::word,
+    "setiz" => SetConditionParameterDecoder::if_zero,
+    "setnz" => SetConditionParameterDecoder::if_not_zero,
+    "setis" => SetConditionParameterDecoder::if_sign,
+    "setns" => SetConditionParameterDecoder::if_not_sign,
+    "setio" => SetConditionParameterDecoder::if_overflow,
+    "setno" => SetConditionParameterDecoder::if_not_overflow,
+    "setig" => SetConditionParameterDecoder::if_greater,
+    "setil" => SetConditionParameterDecoder::if_lesser,
+    "setge" => SetConditionParameterDecoder::if_greater_or_equal,
+    "setle" => SetConditionParameterDecoder::if_lesser_or_equal,
+    "setsp" => StackPointerParameterDecoder::set,
     "stop" => Instruction::stop,
+    "stsb" => StoreStackParameterDecoder::byte,
+    "stsq" => StoreStackParameterDecoder::quarter,
+    "stsh" => StoreStackParameterDecoder::half,
+    "stsw" => StoreStackParameterDecoder::word,
     "subb" => SubtractionParameterDecoder::byte,
     "subq" => SubtractionParameterDecoder::quarter,
     "subh" => SubtractionParameterDecoder::half,
     "subw" => SubtractionParameterDecoder::word,
+    "swapb" => SwapParameterDecoder::byte,
+    "swapq" => SwapParameterDecoder::quarter,
+    "swaph" => SwapParameterDecoder::half,
+    "swapw" => SwapParameterDecoder::word,
     "tstb" => TestParameterDecoder::byte,
     "tstq" => TestParameterDecoder::quarter,
     "tsth" => TestParameterDecoder::half,
@@ -140,13 +284,127 @@ pub const DECODE_TABLE: DecodeTable = phf_map! {
     "xorq" => XorParameterDecoder::quarter,
     "xorh" => XorParameterDecoder::half,
     "xorw" => XorParameterDecoder::word,
+    "bextb" => BitExtractParameterDecoder::byte,
+    "bextq" => BitExtractParameterDecoder::quarter,
+    "bexth" => BitExtractParameterDecoder::half,
+    "bextw" => BitExtractParameterDecoder::word,
+    "binsb" => BitInsertParameterDecoder::byte,
+    "binsq" => BitInsertParameterDecoder::quarter,
+    "binsh" => BitInsertParameterDecoder::half,
+    "binsw" => BitInsertParameterDecoder::word,
+    "btb" => BitTestParameterDecoder::byte,
+    "btq" => BitTestParameterDecoder::quarter,
+    "bth" => BitTestParameterDecoder::half,
+    "btw" => BitTestParameterDecoder::word,
+    "btcb" => BitTestAndComplementParameterDecoder::byte,
+    "btcq" => BitTestAndComplementParameterDecoder::quarter,
+    "btch" => BitTestAndComplementParameterDecoder::half,
+    "btcw" => BitTestAndComplementParameterDecoder::word,
+    "btrb" => BitTestAndResetParameterDecoder::byte,
+    "btrq" => BitTestAndResetParameterDecoder::quarter,
+    "btrh" => BitTestAndResetParameterDecoder::half,
+    "btrw" => BitTestAndResetParameterDecoder::word,
+    "btsb" => BitTestAndSetParameterDecoder::byte,
+    "btsq" => BitTestAndSetParameterDecoder::quarter,
+    "btsh" => BitTestAndSetParameterDecoder::half,
+    "btsw" => BitTestAndSetParameterDecoder::word,
+    "bswapb" => ByteSwapParameterDecoder::byte,
+    "bswapq" => ByteSwapParameterDecoder::quarter,
+    "bswaph" => ByteSwapParameterDecoder::half,
+    "bswapw" => ByteSwapParameterDecoder::word,
+    "clzb" => LeadingZerosParameterDecoder::byte,
+    "clzq" => LeadingZerosParameterDecoder::quarter,
+    "clzh" => LeadingZerosParameterDecoder::half,
+    "clzw" => LeadingZerosParameterDecoder::word,
+    "popcntb" => PopCountParameterDecoder::byte,
+    "popcntq" => PopCountParameterDecoder::quarter,
+    "popcnth" => PopCountParameterDecoder::half,
+    "popcntw" => PopCountParameterDecoder::word,
+    "ctzb" => TrailingZerosParameterDecoder::byte,
+    "ctzq" => TrailingZerosParameterDecoder::quarter,
+    "ctzh" => TrailingZerosParameterDecoder::half,
+    "ctzw" => TrailingZerosParameterDecoder::word,
+    "mulxb" => WideningMultiplicationParameterDecoder::byte,
+    "mulxq" => WideningMultiplicationParameterDecoder::quarter,
+    "mulxh" => WideningMultiplicationParameterDecoder::half,
+    "mulxw" => WideningMultiplicationParameterDecoder::word,
+    "divremb" => DivisionRemainderParameterDecoder::byte,
+    "divremq" => DivisionRemainderParameterDecoder::quarter,
+    "divremh" => DivisionRemainderParameterDecoder::half,
+    "divremw" => DivisionRemainderParameterDecoder::word,
+    "sys" => HostCallParameterDecoder::call,
+    "ecall" => HostCallParameterDecoder::call,
+};
+
+/// The type of an operand of an instruction, which decides the largest immediate value it can be given.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum OperandType {
+    /// Only a register, which is never given an immediate value.
+    Register,
+    /// The type given by the size suffix of the instruction.
+    Sized,
+    /// A byte regardless of the size suffix, such as a bit index.
+    Byte,
+    /// A word regardless of the size suffix, such as a stack offset.
+    Word,
+}
+
+/// The types of the operands of the instructions with a size suffix, by their mnemonic without the suffix.
+///
+/// This mirrors the operands the decoders of [`DECODE_TABLE`] parse, in the order they are given.
+pub(crate) const OPERAND_TYPES: phf::Map<&'static str, &'static [OperandType]> = phf_map! {
+    "add" => &[OperandType::Register, OperandType::Sized],
+    "and" => &[OperandType::Register, OperandType::Sized],
+    "bext" => &[OperandType::Register, OperandType::Byte, OperandType::Byte],
+    "bins" => &[OperandType::Register, OperandType::Sized, OperandType::Byte, OperandType::Byte],
+    "bswap" => &[OperandType::Register],
+    "bt" => &[OperandType::Sized, OperandType::Byte],
+    "btc" => &[OperandType::Register, OperandType::Byte],
+    "btr" => &[OperandType::Register, OperandType::Byte],
+    "bts" => &[OperandType::Register, OperandType::Byte],
+    "clz" => &[OperandType::Register],
+    "cmp" => &[OperandType::Sized, OperandType::Sized],
+    "cmoviz" => &[OperandType::Register, OperandType::Sized],
+    "cmovnz" => &[OperandType::Register, OperandType::Sized],
+    "cmovis" => &[OperandType::Register, OperandType::Sized],
+    "cmovns" => &[OperandType::Register, OperandType::Sized],
+    "cmovio" => &[OperandType::Register, OperandType::Sized],
+    "cmovno" => &[OperandType::Register, OperandType::Sized],
+    "cmovig" => &[OperandType::Register, OperandType::Sized],
+    "cmovil" => &[OperandType::Register, OperandType::Sized],
+    "cmovge" => &[OperandType::Register, OperandType::Sized],
+    "cmovle" => &[OperandType::Register, OperandType::Sized],
+    "ctz" => &[OperandType::Register],
+    "div" => &[OperandType::Register, OperandType::Sized],
+    "divrem" => &[OperandType::Register, OperandType::Register, OperandType::Sized],
+    "drop" => &[],
+    "dup" => &[],
+    "lds" => &[OperandType::Register, OperandType::Word],
+    "mul" => &[OperandType::Register, OperandType::Sized],
+    "mulx" => &[OperandType::Register, OperandType::Register, OperandType::Sized],
+    "not" => &[OperandType::Register],
+    "or" => &[OperandType::Register, OperandType::Sized],
+    "peek" => &[OperandType::Register],
+    "pop" => &[OperandType::Register],
+    "popcnt" => &[OperandType::Register],
+    "prr" => &[OperandType::Register],
+    "prs" => &[OperandType::Word],
+    "prv" => &[OperandType::Sized],
+    "psh" => &[OperandType::Sized],
+    "rem" => &[OperandType::Register, OperandType::Sized],
+    "set" => &[OperandType::Register, OperandType::Sized],
+    "sts" => &[OperandType::Word, OperandType::Sized],
+    "sub" => &[OperandType::Register, OperandType::Sized],
+    "swap" => &[],
+    "tst" => &[OperandType::Sized, OperandType::Sized],
+    "xor" => &[OperandType::Register, OperandType::Sized],
 };
 
 /// Attempts to get one parameter from the instruction arguments.
 ///
 /// # Errors
 /// Will return [`DecodeError::IncompleteInstruction`] if no parameter could be found.
-fn try_get_first_parameter_str(mut parameters: Parameters) -> Result<&str, DecodeError> {
+fn try_get_first_parameter_str(mut parameters: Parameters<'_>) -> Result<&str, DecodeError> {
     match parameters.next() {
         Some(s_operand) => Ok(s_operand),
         None => Err(DecodeError::IncompleteInstruction),
@@ -157,7 +415,9 @@ fn try_get_first_parameter_str(mut parameters: Parameters) -> Result<&str, Decod
 ///
 /// # Errors
 /// Will return [`DecodeError::IncompleteInstruction`] if either parameter could be found.
-fn try_get_both_parameters_str(mut parameters: Parameters) -> Result<(&str, &str), DecodeError> {
+fn try_get_both_parameters_str(
+    mut parameters: Parameters<'_>,
+) -> Result<(&str, &str), DecodeError> {
     let (Some(s_register), Some(s_operand)) = (parameters.next(), parameters.next()) else {
         return Err(DecodeError::IncompleteInstruction);
     };
@@ -210,6 +470,38 @@ impl ParameterDecoderHelper {
 
         Ok((operand1, operand2))
     }
+
+    fn try_offset_and_operand<T>(
+        parameters: Parameters,
+    ) -> Result<(Operand<Word>, Operand<T>), DecodeError>
+    where
+        T: FromStr,
+    {
+        let (s_offset, s_operand) = try_get_both_parameters_str(parameters)?;
+        let offset = Operand::try_from(s_offset)?;
+        let operand = Operand::try_from(s_operand)?;
+
+        Ok((offset, operand))
+    }
+
+    fn try_double_register_and_operand<T>(
+        mut parameters: Parameters,
+    ) -> Result<(Register, Register, Operand<T>), DecodeError>
+    where
+        T: FromStr,
+    {
+        let (Some(s_register1), Some(s_register2), Some(s_operand)) =
+            (parameters.next(), parameters.next(), parameters.next())
+        else {
+            return Err(DecodeError::IncompleteInstruction);
+        };
+
+        let register1 = Register::try_from(s_register1)?;
+        let register2 = Register::try_from(s_register2)?;
+        let operand = Operand::try_from(s_operand)?;
+
+        Ok((register1, register2, operand))
+    }
 }
 
 /// TODO: move implementation from Instruction to something else.
@@ -1246,6 +1538,21 @@ mod decoder_helper {
     }
 }
 
+#[cfg(test)]
+mod operand_types {
+    use super::{DECODE_TABLE, OPERAND_TYPES};
+
+    #[test]
+    fn every_size_is_an_instruction() {
+        for stem in OPERAND_TYPES.keys() {
+            for suffix in ['b', 'q', 'h', 'w'] {
+                let mnemonic = format!("{stem}{suffix}");
+                assert!(DECODE_TABLE.contains_key(&mnemonic), "{mnemonic}");
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod regression {
     use crate::{decode::decode, error::DecodeError, instruction::Instruction};
@@ -0,0 +1,205 @@
+use super::ParameterDecoderHelper;
+use crate::{
+    constant::Parameters,
+    error::DecodeError,
+    instruction::{Instruction, Peek},
+};
+
+pub struct PeekParameterDecoder;
+
+impl PeekParameterDecoder {
+    pub fn byte(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let register = ParameterDecoderHelper::try_register(parameters)?;
+        let instruction = Peek::Byte(register);
+
+        Ok(Instruction::Peek(instruction))
+    }
+
+    pub fn quarter(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let register = ParameterDecoderHelper::try_register(parameters)?;
+        let instruction = Peek::Quarter(register);
+
+        Ok(Instruction::Peek(instruction))
+    }
+
+    pub fn half(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let register = ParameterDecoderHelper::try_register(parameters)?;
+        let instruction = Peek::Half(register);
+
+        Ok(Instruction::Peek(instruction))
+    }
+
+    pub fn word(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let register = ParameterDecoderHelper::try_register(parameters)?;
+        let instruction = Peek::Word(register);
+
+        Ok(Instruction::Peek(instruction))
+    }
+}
+
+#[cfg(test)]
+mod regression {
+    mod byte {
+        use crate::{
+            decode::decode,
+            error::DecodeError,
+            instruction::{Instruction, Peek},
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_param() {
+            let instruction = "peekb";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_register_error() {
+            let instruction = "peekb rx";
+            let expected = Err(DecodeError::InvalidRegister("rx".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn valid_register() -> Result<(), DecodeError> {
+            let instruction = "peekb ra";
+            let expected = Instruction::Peek(Peek::Byte(Register::A));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod quarter {
+        use crate::{
+            decode::decode,
+            error::DecodeError,
+            instruction::{Instruction, Peek},
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_param() {
+            let instruction = "peekq";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_register_error() {
+            let instruction = "peekq rx";
+            let expected = Err(DecodeError::InvalidRegister("rx".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn valid_register() -> Result<(), DecodeError> {
+            let instruction = "peekq ra";
+            let expected = Instruction::Peek(Peek::Quarter(Register::A));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod half {
+        use crate::{
+            decode::decode,
+            error::DecodeError,
+            instruction::{Instruction, Peek},
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_param() {
+            let instruction = "peekh";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_register_error() {
+            let instruction = "peekh rx";
+            let expected = Err(DecodeError::InvalidRegister("rx".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn valid_register() -> Result<(), DecodeError> {
+            let instruction = "peekh ra";
+            let expected = Instruction::Peek(Peek::Half(Register::A));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod word {
+        use crate::{
+            decode::decode,
+            error::DecodeError,
+            instruction::{Instruction, Peek},
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_param() {
+            let instruction = "peekw";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_register_error() {
+            let instruction = "peekw rx";
+            let expected = Err(DecodeError::InvalidRegister("rx".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn valid_register() -> Result<(), DecodeError> {
+            let instruction = "peekw ra";
+            let expected = Instruction::Peek(Peek::Word(Register::A));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+}
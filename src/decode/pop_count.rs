@@ -0,0 +1,205 @@
+use super::ParameterDecoderHelper;
+use crate::{
+    constant::Parameters,
+    error::DecodeError,
+    instruction::{Instruction, PopCount},
+};
+
+pub struct PopCountParameterDecoder;
+
+impl PopCountParameterDecoder {
+    pub fn byte(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let register = ParameterDecoderHelper::try_register(parameters)?;
+        let instruction = PopCount::Byte(register);
+
+        Ok(Instruction::PopCount(instruction))
+    }
+
+    pub fn quarter(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let register = ParameterDecoderHelper::try_register(parameters)?;
+        let instruction = PopCount::Quarter(register);
+
+        Ok(Instruction::PopCount(instruction))
+    }
+
+    pub fn half(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let register = ParameterDecoderHelper::try_register(parameters)?;
+        let instruction = PopCount::Half(register);
+
+        Ok(Instruction::PopCount(instruction))
+    }
+
+    pub fn word(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let register = ParameterDecoderHelper::try_register(parameters)?;
+        let instruction = PopCount::Word(register);
+
+        Ok(Instruction::PopCount(instruction))
+    }
+}
+
+#[cfg(test)]
+mod regression {
+    mod byte {
+        use crate::{
+            decode::decode,
+            error::DecodeError,
+            instruction::{Instruction, PopCount},
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_param() {
+            let instruction = "popcntb";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_register_error() {
+            let instruction = "popcntb rx";
+            let expected = Err(DecodeError::InvalidRegister("rx".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn valid_register() -> Result<(), DecodeError> {
+            let instruction = "popcntb ra";
+            let expected = Instruction::PopCount(PopCount::Byte(Register::A));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod quarter {
+        use crate::{
+            decode::decode,
+            error::DecodeError,
+            instruction::{Instruction, PopCount},
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_param() {
+            let instruction = "popcntq";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_register_error() {
+            let instruction = "popcntq rx";
+            let expected = Err(DecodeError::InvalidRegister("rx".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn valid_register() -> Result<(), DecodeError> {
+            let instruction = "popcntq ra";
+            let expected = Instruction::PopCount(PopCount::Quarter(Register::A));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod half {
+        use crate::{
+            decode::decode,
+            error::DecodeError,
+            instruction::{Instruction, PopCount},
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_param() {
+            let instruction = "popcnth";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_register_error() {
+            let instruction = "popcnth rx";
+            let expected = Err(DecodeError::InvalidRegister("rx".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn valid_register() -> Result<(), DecodeError> {
+            let instruction = "popcnth ra";
+            let expected = Instruction::PopCount(PopCount::Half(Register::A));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod word {
+        use crate::{
+            decode::decode,
+            error::DecodeError,
+            instruction::{Instruction, PopCount},
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_param() {
+            let instruction = "popcntw";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_register_error() {
+            let instruction = "popcntw rx";
+            let expected = Err(DecodeError::InvalidRegister("rx".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn valid_register() -> Result<(), DecodeError> {
+            let instruction = "popcntw ra";
+            let expected = Instruction::PopCount(PopCount::Word(Register::A));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+}
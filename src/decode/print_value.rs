@@ -0,0 +1,376 @@
+use crate::{
+    constant::Parameters,
+    error::DecodeError,
+    instruction::{Instruction, PrintFormat, PrintValue},
+    operand::Operand,
+};
+use std::str::FromStr;
+
+pub struct PrintValueParameterDecoder;
+
+impl PrintValueParameterDecoder {
+    pub fn byte(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let (operand, format) = try_operand_and_format(parameters)?;
+        let instruction = PrintValue::Byte(operand, format);
+
+        Ok(Instruction::PrintValue(instruction))
+    }
+
+    pub fn quarter(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let (operand, format) = try_operand_and_format(parameters)?;
+        let instruction = PrintValue::Quarter(operand, format);
+
+        Ok(Instruction::PrintValue(instruction))
+    }
+
+    pub fn half(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let (operand, format) = try_operand_and_format(parameters)?;
+        let instruction = PrintValue::Half(operand, format);
+
+        Ok(Instruction::PrintValue(instruction))
+    }
+
+    pub fn word(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let (operand, format) = try_operand_and_format(parameters)?;
+        let instruction = PrintValue::Word(operand, format);
+
+        Ok(Instruction::PrintValue(instruction))
+    }
+
+    pub fn newline(_parameters: Parameters) -> Result<Instruction, DecodeError> {
+        Ok(Instruction::PrintNewline)
+    }
+
+    pub fn space(_parameters: Parameters) -> Result<Instruction, DecodeError> {
+        Ok(Instruction::PrintSpace)
+    }
+}
+
+/// Attempts to get the operand and the optional format from the instruction arguments.
+///
+/// The format defaults to decimal if it is not specified.
+fn try_operand_and_format<T>(
+    mut parameters: Parameters,
+) -> Result<(Operand<T>, PrintFormat), DecodeError>
+where
+    T: FromStr,
+{
+    let Some(s_operand) = parameters.next() else {
+        return Err(DecodeError::IncompleteInstruction);
+    };
+    let operand = Operand::try_from(s_operand)?;
+
+    let format = match parameters.next() {
+        None | Some("d") => PrintFormat::Decimal,
+        Some("s") => PrintFormat::Signed,
+        Some("x") => PrintFormat::Hexadecimal,
+        Some("b") => PrintFormat::Binary,
+        Some("o") => PrintFormat::Octal,
+        Some("c") => PrintFormat::Character,
+        Some(unknown) => return Err(DecodeError::InvalidPrintFormat(unknown.to_string())),
+    };
+
+    Ok((operand, format))
+}
+
+#[cfg(test)]
+mod regression {
+    mod byte {
+        use crate::{
+            constant::Byte,
+            decode::decode,
+            error::DecodeError,
+            instruction::{Instruction, PrintFormat, PrintValue},
+            operand::Operand,
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_first_param() {
+            let instruction = "prvb";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_print_format_error() {
+            let instruction = "prvb ra z";
+            let expected = Err(DecodeError::InvalidPrintFormat("z".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn default_format_is_decimal() -> Result<(), DecodeError> {
+            let instruction = "prvb ra";
+            let expected = Instruction::PrintValue(PrintValue::Byte(
+                Operand::Register(Register::A),
+                PrintFormat::Decimal,
+            ));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+
+        #[test]
+        fn immediate_with_format() -> Result<(), DecodeError> {
+            let instruction = format!("prvb {} x", Byte::MAX);
+            let expected = Instruction::PrintValue(PrintValue::Byte(
+                Operand::Immediate(Byte::MAX),
+                PrintFormat::Hexadecimal,
+            ));
+
+            let actual = decode(&instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod quarter {
+        use crate::{
+            constant::Quarter,
+            decode::decode,
+            error::DecodeError,
+            instruction::{Instruction, PrintFormat, PrintValue},
+            operand::Operand,
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_first_param() {
+            let instruction = "prvq";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_print_format_error() {
+            let instruction = "prvq ra z";
+            let expected = Err(DecodeError::InvalidPrintFormat("z".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn default_format_is_decimal() -> Result<(), DecodeError> {
+            let instruction = "prvq ra";
+            let expected = Instruction::PrintValue(PrintValue::Quarter(
+                Operand::Register(Register::A),
+                PrintFormat::Decimal,
+            ));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+
+        #[test]
+        fn immediate_with_format() -> Result<(), DecodeError> {
+            let instruction = format!("prvq {} x", Quarter::MAX);
+            let expected = Instruction::PrintValue(PrintValue::Quarter(
+                Operand::Immediate(Quarter::MAX),
+                PrintFormat::Hexadecimal,
+            ));
+
+            let actual = decode(&instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod half {
+        use crate::{
+            constant::Half,
+            decode::decode,
+            error::DecodeError,
+            instruction::{Instruction, PrintFormat, PrintValue},
+            operand::Operand,
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_first_param() {
+            let instruction = "prvh";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_print_format_error() {
+            let instruction = "prvh ra z";
+            let expected = Err(DecodeError::InvalidPrintFormat("z".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn default_format_is_decimal() -> Result<(), DecodeError> {
+            let instruction = "prvh ra";
+            let expected = Instruction::PrintValue(PrintValue::Half(
+                Operand::Register(Register::A),
+                PrintFormat::Decimal,
+            ));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+
+        #[test]
+        fn immediate_with_format() -> Result<(), DecodeError> {
+            let instruction = format!("prvh {} x", Half::MAX);
+            let expected = Instruction::PrintValue(PrintValue::Half(
+                Operand::Immediate(Half::MAX),
+                PrintFormat::Hexadecimal,
+            ));
+
+            let actual = decode(&instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod word {
+        use crate::{
+            constant::Word,
+            decode::decode,
+            error::DecodeError,
+            instruction::{Instruction, PrintFormat, PrintValue},
+            operand::Operand,
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_first_param() {
+            let instruction = "prvw";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_print_format_error() {
+            let instruction = "prvw ra z";
+            let expected = Err(DecodeError::InvalidPrintFormat("z".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn default_format_is_decimal() -> Result<(), DecodeError> {
+            let instruction = "prvw ra";
+            let expected = Instruction::PrintValue(PrintValue::Word(
+                Operand::Register(Register::A),
+                PrintFormat::Decimal,
+            ));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+
+        #[test]
+        fn immediate_with_format() -> Result<(), DecodeError> {
+            let instruction = format!("prvw {} x", Word::MAX);
+            let expected = Instruction::PrintValue(PrintValue::Word(
+                Operand::Immediate(Word::MAX),
+                PrintFormat::Hexadecimal,
+            ));
+
+            let actual = decode(&instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod format {
+        use crate::{
+            decode::decode,
+            error::DecodeError,
+            instruction::{Instruction, PrintFormat, PrintValue},
+            operand::Operand,
+        };
+
+        #[test]
+        fn every_format_selector() -> Result<(), DecodeError> {
+            let selectors = [
+                ("d", PrintFormat::Decimal),
+                ("s", PrintFormat::Signed),
+                ("x", PrintFormat::Hexadecimal),
+                ("b", PrintFormat::Binary),
+                ("o", PrintFormat::Octal),
+                ("c", PrintFormat::Character),
+            ];
+
+            for (selector, format) in selectors {
+                let instruction = format!("prvb 1 {selector}");
+                let expected =
+                    Instruction::PrintValue(PrintValue::Byte(Operand::Immediate(1), format));
+
+                let actual = decode(&instruction)?;
+
+                assert_eq!(actual, expected);
+            }
+
+            Ok(())
+        }
+    }
+
+    mod separator {
+        use crate::{decode::decode, error::DecodeError, instruction::Instruction};
+
+        #[test]
+        fn newline() -> Result<(), DecodeError> {
+            let actual = decode("prnl")?;
+
+            assert_eq!(actual, Instruction::PrintNewline);
+
+            Ok(())
+        }
+
+        #[test]
+        fn space() -> Result<(), DecodeError> {
+            let actual = decode("prsp")?;
+
+            assert_eq!(actual, Instruction::PrintSpace);
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use super::ParameterDecoderHelper;
+use crate::{constant::Parameters, error::DecodeError, instruction::Instruction};
+
+pub struct ProgramCounterParameterDecoder;
+
+impl ProgramCounterParameterDecoder {
+    pub fn get(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let register = ParameterDecoderHelper::try_register(parameters)?;
+
+        Ok(Instruction::GetProgramCounter(register))
+    }
+}
+
+#[cfg(test)]
+mod regression {
+    mod get {
+        use crate::{
+            decode::decode, error::DecodeError, instruction::Instruction, register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_param() {
+            let instruction = "getpc";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_register_error() {
+            let instruction = "getpc rx";
+            let expected = Err(DecodeError::InvalidRegister("rx".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn valid_register() -> Result<(), DecodeError> {
+            let instruction = "getpc ra";
+            let expected = Instruction::GetProgramCounter(Register::A);
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use super::ParameterDecoderHelper;
+use crate::{
+    constant::Parameters,
+    error::DecodeError,
+    instruction::{Instruction, Jump},
+};
+
+pub struct SetConditionParameterDecoder;
+
+impl SetConditionParameterDecoder {
+    pub fn if_zero(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        Self::decode(parameters, Jump::IfZero)
+    }
+
+    pub fn if_not_zero(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        Self::decode(parameters, Jump::IfNotZero)
+    }
+
+    pub fn if_sign(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        Self::decode(parameters, Jump::IfSign)
+    }
+
+    pub fn if_not_sign(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        Self::decode(parameters, Jump::IfNotSign)
+    }
+
+    pub fn if_overflow(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        Self::decode(parameters, Jump::IfOverflow)
+    }
+
+    pub fn if_not_overflow(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        Self::decode(parameters, Jump::IfNotOverflow)
+    }
+
+    pub fn if_greater(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        Self::decode(parameters, Jump::IfGreater)
+    }
+
+    pub fn if_lesser(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        Self::decode(parameters, Jump::IfLesser)
+    }
+
+    pub fn if_greater_or_equal(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        Self::decode(parameters, Jump::IfGreaterOrEqual)
+    }
+
+    pub fn if_lesser_or_equal(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        Self::decode(parameters, Jump::IfLesserOrEqual)
+    }
+
+    fn decode(parameters: Parameters, condition: Jump) -> Result<Instruction, DecodeError> {
+        let register = ParameterDecoderHelper::try_register(parameters)?;
+
+        Ok(Instruction::SetCondition(condition, register))
+    }
+}
+
+#[cfg(test)]
+mod regression {
+    use crate::{
+        decode::decode,
+        error::DecodeError,
+        instruction::{Instruction, Jump},
+        register::Register,
+    };
+
+    #[test]
+    fn incomplete_instruction_error_missing_param() {
+        let instruction = "setiz";
+        let expected = Err(DecodeError::IncompleteInstruction);
+
+        let actual = decode(instruction);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn invalid_register_error() {
+        let instruction = "setiz rx";
+        let expected = Err(DecodeError::InvalidRegister("rx".to_string()));
+
+        let actual = decode(instruction);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn every_condition() -> Result<(), DecodeError> {
+        let conditions = [
+            ("iz", Jump::IfZero),
+            ("nz", Jump::IfNotZero),
+            ("is", Jump::IfSign),
+            ("ns", Jump::IfNotSign),
+            ("io", Jump::IfOverflow),
+            ("no", Jump::IfNotOverflow),
+            ("ig", Jump::IfGreater),
+            ("il", Jump::IfLesser),
+            ("ge", Jump::IfGreaterOrEqual),
+            ("le", Jump::IfLesserOrEqual),
+        ];
+
+        for (suffix, condition) in conditions {
+            let instruction = format!("set{suffix} rc");
+            let expected = Instruction::SetCondition(condition, Register::C);
+
+            let actual = decode(&instruction)?;
+
+            assert_eq!(actual, expected);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,110 @@
+use super::ParameterDecoderHelper;
+use crate::{constant::Parameters, error::DecodeError, instruction::Instruction};
+
+pub struct StackPointerParameterDecoder;
+
+impl StackPointerParameterDecoder {
+    pub fn get(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let register = ParameterDecoderHelper::try_register(parameters)?;
+
+        Ok(Instruction::GetStackPointer(register))
+    }
+
+    pub fn set(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let operand = ParameterDecoderHelper::try_operand(parameters)?;
+
+        Ok(Instruction::SetStackPointer(operand))
+    }
+}
+
+#[cfg(test)]
+mod regression {
+    mod get {
+        use crate::{
+            decode::decode, error::DecodeError, instruction::Instruction, register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_param() {
+            let instruction = "getsp";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_register_error() {
+            let instruction = "getsp rx";
+            let expected = Err(DecodeError::InvalidRegister("rx".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn valid_register() -> Result<(), DecodeError> {
+            let instruction = "getsp ra";
+            let expected = Instruction::GetStackPointer(Register::A);
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod set {
+        use crate::{
+            constant::Word, decode::decode, error::DecodeError, instruction::Instruction,
+            operand::Operand, register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_param() {
+            let instruction = "setsp";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_immediate_value_error() {
+            let instruction = "setsp -1";
+            let expected = Err(DecodeError::InvalidImmediateValue("-1".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn valid_register() -> Result<(), DecodeError> {
+            let instruction = "setsp ra";
+            let expected = Instruction::SetStackPointer(Operand::Register(Register::A));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+
+        #[test]
+        fn valid_immediate() -> Result<(), DecodeError> {
+            let instruction = format!("setsp {}", Word::MAX);
+            let expected = Instruction::SetStackPointer(Operand::Immediate(Word::MAX));
+
+            let actual = decode(&instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,325 @@
+use super::ParameterDecoderHelper;
+use crate::{
+    constant::Parameters,
+    error::DecodeError,
+    instruction::{Instruction, StoreStack},
+};
+
+pub struct StoreStackParameterDecoder;
+
+impl StoreStackParameterDecoder {
+    pub fn byte(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let (offset, operand) = ParameterDecoderHelper::try_offset_and_operand(parameters)?;
+        let instruction = StoreStack::Byte(offset, operand);
+
+        Ok(Instruction::StoreStack(instruction))
+    }
+
+    pub fn quarter(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let (offset, operand) = ParameterDecoderHelper::try_offset_and_operand(parameters)?;
+        let instruction = StoreStack::Quarter(offset, operand);
+
+        Ok(Instruction::StoreStack(instruction))
+    }
+
+    pub fn half(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let (offset, operand) = ParameterDecoderHelper::try_offset_and_operand(parameters)?;
+        let instruction = StoreStack::Half(offset, operand);
+
+        Ok(Instruction::StoreStack(instruction))
+    }
+
+    pub fn word(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let (offset, operand) = ParameterDecoderHelper::try_offset_and_operand(parameters)?;
+        let instruction = StoreStack::Word(offset, operand);
+
+        Ok(Instruction::StoreStack(instruction))
+    }
+}
+
+#[cfg(test)]
+mod regression {
+    mod byte {
+        use crate::{
+            constant::Byte,
+            decode::decode,
+            error::DecodeError,
+            instruction::{Instruction, StoreStack},
+            operand::Operand,
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_second_param() {
+            let instruction = "stsb 0";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_operand_error_in_first_param() {
+            let instruction = "stsb 0x 0";
+            let expected = Err(DecodeError::InvalidOperand("0x".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_immediate_value_error_in_second_param() {
+            let instruction = "stsb 0 -1";
+            let expected = Err(DecodeError::InvalidImmediateValue("-1".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn valid_immediate_offset_and_register() -> Result<(), DecodeError> {
+            let instruction = "stsb 8 ra";
+            let expected = Instruction::StoreStack(StoreStack::Byte(
+                Operand::Immediate(8),
+                Operand::Register(Register::A),
+            ));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+
+        #[test]
+        fn valid_register_offset_and_immediate() -> Result<(), DecodeError> {
+            let instruction = format!("stsb rb {}", Byte::MAX);
+            let expected = Instruction::StoreStack(StoreStack::Byte(
+                Operand::Register(Register::B),
+                Operand::Immediate(Byte::MAX),
+            ));
+
+            let actual = decode(&instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod quarter {
+        use crate::{
+            constant::Quarter,
+            decode::decode,
+            error::DecodeError,
+            instruction::{Instruction, StoreStack},
+            operand::Operand,
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_second_param() {
+            let instruction = "stsq 0";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_operand_error_in_first_param() {
+            let instruction = "stsq 0x 0";
+            let expected = Err(DecodeError::InvalidOperand("0x".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_immediate_value_error_in_second_param() {
+            let instruction = "stsq 0 -1";
+            let expected = Err(DecodeError::InvalidImmediateValue("-1".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn valid_immediate_offset_and_register() -> Result<(), DecodeError> {
+            let instruction = "stsq 8 ra";
+            let expected = Instruction::StoreStack(StoreStack::Quarter(
+                Operand::Immediate(8),
+                Operand::Register(Register::A),
+            ));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+
+        #[test]
+        fn valid_register_offset_and_immediate() -> Result<(), DecodeError> {
+            let instruction = format!("stsq rb {}", Quarter::MAX);
+            let expected = Instruction::StoreStack(StoreStack::Quarter(
+                Operand::Register(Register::B),
+                Operand::Immediate(Quarter::MAX),
+            ));
+
+            let actual = decode(&instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod half {
+        use crate::{
+            constant::Half,
+            decode::decode,
+            error::DecodeError,
+            instruction::{Instruction, StoreStack},
+            operand::Operand,
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_second_param() {
+            let instruction = "stsh 0";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_operand_error_in_first_param() {
+            let instruction = "stsh 0x 0";
+            let expected = Err(DecodeError::InvalidOperand("0x".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_immediate_value_error_in_second_param() {
+            let instruction = "stsh 0 -1";
+            let expected = Err(DecodeError::InvalidImmediateValue("-1".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn valid_immediate_offset_and_register() -> Result<(), DecodeError> {
+            let instruction = "stsh 8 ra";
+            let expected = Instruction::StoreStack(StoreStack::Half(
+                Operand::Immediate(8),
+                Operand::Register(Register::A),
+            ));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+
+        #[test]
+        fn valid_register_offset_and_immediate() -> Result<(), DecodeError> {
+            let instruction = format!("stsh rb {}", Half::MAX);
+            let expected = Instruction::StoreStack(StoreStack::Half(
+                Operand::Register(Register::B),
+                Operand::Immediate(Half::MAX),
+            ));
+
+            let actual = decode(&instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod word {
+        use crate::{
+            constant::Word,
+            decode::decode,
+            error::DecodeError,
+            instruction::{Instruction, StoreStack},
+            operand::Operand,
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_second_param() {
+            let instruction = "stsw 0";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_operand_error_in_first_param() {
+            let instruction = "stsw 0x 0";
+            let expected = Err(DecodeError::InvalidOperand("0x".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_immediate_value_error_in_second_param() {
+            let instruction = "stsw 0 -1";
+            let expected = Err(DecodeError::InvalidImmediateValue("-1".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn valid_immediate_offset_and_register() -> Result<(), DecodeError> {
+            let instruction = "stsw 8 ra";
+            let expected = Instruction::StoreStack(StoreStack::Word(
+                Operand::Immediate(8),
+                Operand::Register(Register::A),
+            ));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+
+        #[test]
+        fn valid_register_offset_and_immediate() -> Result<(), DecodeError> {
+            let instruction = format!("stsw rb {}", Word::MAX);
+            let expected = Instruction::StoreStack(StoreStack::Word(
+                Operand::Register(Register::B),
+                Operand::Immediate(Word::MAX),
+            ));
+
+            let actual = decode(&instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use crate::{
+    constant::Parameters,
+    error::DecodeError,
+    instruction::{Instruction, Swap},
+};
+
+pub struct SwapParameterDecoder;
+
+impl SwapParameterDecoder {
+    pub fn byte(_parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let instruction = Swap::Byte;
+
+        Ok(Instruction::Swap(instruction))
+    }
+
+    pub fn quarter(_parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let instruction = Swap::Quarter;
+
+        Ok(Instruction::Swap(instruction))
+    }
+
+    pub fn half(_parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let instruction = Swap::Half;
+
+        Ok(Instruction::Swap(instruction))
+    }
+
+    pub fn word(_parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let instruction = Swap::Word;
+
+        Ok(Instruction::Swap(instruction))
+    }
+}
+
+#[cfg(test)]
+mod regression {
+    mod byte {
+        use crate::{
+            decode::decode,
+            error::DecodeError,
+            instruction::{Instruction, Swap},
+        };
+
+        #[test]
+        fn valid_instruction() -> Result<(), DecodeError> {
+            let instruction = "swapb";
+            let expected = Instruction::Swap(Swap::Byte);
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod quarter {
+        use crate::{
+            decode::decode,
+            error::DecodeError,
+            instruction::{Instruction, Swap},
+        };
+
+        #[test]
+        fn valid_instruction() -> Result<(), DecodeError> {
+            let instruction = "swapq";
+            let expected = Instruction::Swap(Swap::Quarter);
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod half {
+        use crate::{
+            decode::decode,
+            error::DecodeError,
+            instruction::{Instruction, Swap},
+        };
+
+        #[test]
+        fn valid_instruction() -> Result<(), DecodeError> {
+            let instruction = "swaph";
+            let expected = Instruction::Swap(Swap::Half);
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod word {
+        use crate::{
+            decode::decode,
+            error::DecodeError,
+            instruction::{Instruction, Swap},
+        };
+
+        #[test]
+        fn valid_instruction() -> Result<(), DecodeError> {
+            let instruction = "swapw";
+            let expected = Instruction::Swap(Swap::Word);
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+}
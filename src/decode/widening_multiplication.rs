@@ -0,0 +1,337 @@
+use super::ParameterDecoderHelper;
+use crate::{
+    constant::Parameters,
+    error::DecodeError,
+    instruction::{Instruction, WideningMultiplication},
+};
+
+pub struct WideningMultiplicationParameterDecoder;
+
+impl WideningMultiplicationParameterDecoder {
+    pub fn byte(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let (high, low, operand) =
+            ParameterDecoderHelper::try_double_register_and_operand(parameters)?;
+        let instruction = WideningMultiplication::Byte(high, low, operand);
+
+        Ok(Instruction::WideningMultiplication(instruction))
+    }
+
+    pub fn quarter(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let (high, low, operand) =
+            ParameterDecoderHelper::try_double_register_and_operand(parameters)?;
+        let instruction = WideningMultiplication::Quarter(high, low, operand);
+
+        Ok(Instruction::WideningMultiplication(instruction))
+    }
+
+    pub fn half(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let (high, low, operand) =
+            ParameterDecoderHelper::try_double_register_and_operand(parameters)?;
+        let instruction = WideningMultiplication::Half(high, low, operand);
+
+        Ok(Instruction::WideningMultiplication(instruction))
+    }
+
+    pub fn word(parameters: Parameters) -> Result<Instruction, DecodeError> {
+        let (high, low, operand) =
+            ParameterDecoderHelper::try_double_register_and_operand(parameters)?;
+        let instruction = WideningMultiplication::Word(high, low, operand);
+
+        Ok(Instruction::WideningMultiplication(instruction))
+    }
+}
+
+#[cfg(test)]
+mod regression {
+    mod byte {
+        use crate::{
+            constant::Byte,
+            decode::decode,
+            error::DecodeError,
+            instruction::{Instruction, WideningMultiplication},
+            operand::Operand,
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_third_param() {
+            let instruction = "mulxb ra rb";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_register_error_in_second_param() {
+            let instruction = "mulxb ra rx 1";
+            let expected = Err(DecodeError::InvalidRegister("rx".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_immediate_value_error() {
+            let instruction = "mulxb ra rb -1";
+            let expected = Err(DecodeError::InvalidImmediateValue("-1".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn third_param_immediate() -> Result<(), DecodeError> {
+            let instruction = format!("mulxb ra rb {}", Byte::MAX);
+            let expected = Instruction::WideningMultiplication(WideningMultiplication::Byte(
+                Register::A,
+                Register::B,
+                Operand::Immediate(Byte::MAX),
+            ));
+
+            let actual = decode(&instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+
+        #[test]
+        fn third_param_register() -> Result<(), DecodeError> {
+            let instruction = "mulxb ra rb rc";
+            let expected = Instruction::WideningMultiplication(WideningMultiplication::Byte(
+                Register::A,
+                Register::B,
+                Operand::Register(Register::C),
+            ));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod quarter {
+        use crate::{
+            constant::Quarter,
+            decode::decode,
+            error::DecodeError,
+            instruction::{Instruction, WideningMultiplication},
+            operand::Operand,
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_third_param() {
+            let instruction = "mulxq ra rb";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_register_error_in_second_param() {
+            let instruction = "mulxq ra rx 1";
+            let expected = Err(DecodeError::InvalidRegister("rx".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_immediate_value_error() {
+            let instruction = "mulxq ra rb -1";
+            let expected = Err(DecodeError::InvalidImmediateValue("-1".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn third_param_immediate() -> Result<(), DecodeError> {
+            let instruction = format!("mulxq ra rb {}", Quarter::MAX);
+            let expected = Instruction::WideningMultiplication(WideningMultiplication::Quarter(
+                Register::A,
+                Register::B,
+                Operand::Immediate(Quarter::MAX),
+            ));
+
+            let actual = decode(&instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+
+        #[test]
+        fn third_param_register() -> Result<(), DecodeError> {
+            let instruction = "mulxq ra rb rc";
+            let expected = Instruction::WideningMultiplication(WideningMultiplication::Quarter(
+                Register::A,
+                Register::B,
+                Operand::Register(Register::C),
+            ));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod half {
+        use crate::{
+            constant::Half,
+            decode::decode,
+            error::DecodeError,
+            instruction::{Instruction, WideningMultiplication},
+            operand::Operand,
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_third_param() {
+            let instruction = "mulxh ra rb";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_register_error_in_second_param() {
+            let instruction = "mulxh ra rx 1";
+            let expected = Err(DecodeError::InvalidRegister("rx".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_immediate_value_error() {
+            let instruction = "mulxh ra rb -1";
+            let expected = Err(DecodeError::InvalidImmediateValue("-1".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn third_param_immediate() -> Result<(), DecodeError> {
+            let instruction = format!("mulxh ra rb {}", Half::MAX);
+            let expected = Instruction::WideningMultiplication(WideningMultiplication::Half(
+                Register::A,
+                Register::B,
+                Operand::Immediate(Half::MAX),
+            ));
+
+            let actual = decode(&instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+
+        #[test]
+        fn third_param_register() -> Result<(), DecodeError> {
+            let instruction = "mulxh ra rb rc";
+            let expected = Instruction::WideningMultiplication(WideningMultiplication::Half(
+                Register::A,
+                Register::B,
+                Operand::Register(Register::C),
+            ));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+
+    mod word {
+        use crate::{
+            constant::Word,
+            decode::decode,
+            error::DecodeError,
+            instruction::{Instruction, WideningMultiplication},
+            operand::Operand,
+            register::Register,
+        };
+
+        #[test]
+        fn incomplete_instruction_error_missing_third_param() {
+            let instruction = "mulxw ra rb";
+            let expected = Err(DecodeError::IncompleteInstruction);
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_register_error_in_second_param() {
+            let instruction = "mulxw ra rx 1";
+            let expected = Err(DecodeError::InvalidRegister("rx".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn invalid_immediate_value_error() {
+            let instruction = "mulxw ra rb -1";
+            let expected = Err(DecodeError::InvalidImmediateValue("-1".to_string()));
+
+            let actual = decode(instruction);
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn third_param_immediate() -> Result<(), DecodeError> {
+            let instruction = format!("mulxw ra rb {}", Word::MAX);
+            let expected = Instruction::WideningMultiplication(WideningMultiplication::Word(
+                Register::A,
+                Register::B,
+                Operand::Immediate(Word::MAX),
+            ));
+
+            let actual = decode(&instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+
+        #[test]
+        fn third_param_register() -> Result<(), DecodeError> {
+            let instruction = "mulxw ra rb rc";
+            let expected = Instruction::WideningMultiplication(WideningMultiplication::Word(
+                Register::A,
+                Register::B,
+                Operand::Register(Register::C),
+            ));
+
+            let actual = decode(instruction)?;
+
+            assert_eq!(actual, expected);
+
+            Ok(())
+        }
+    }
+}
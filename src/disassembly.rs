@@ -0,0 +1,194 @@
+//! Listing of the loaded program as canonical source code, regardless of whether it was loaded from source code or bytecode.
+
+use crate::{
+    constant::Word,
+    decode,
+    instruction::{Instruction, Relative},
+    operand::Operand,
+    program::{Line, Program},
+    Interpreter,
+};
+use std::fmt::Display;
+
+/// The width the instructions are padded to, such that the annotations line up.
+const INSTRUCTION_WIDTH: usize = 24;
+
+/// The loaded program with line numbers, where instructions are written in their canonical form.
+///
+/// Calls are annotated with the name of the called function, and jumps with the line they jump to.
+/// Lines that cannot be decoded are written as is.
+pub struct Disassembly<'a> {
+    program: &'a Program,
+}
+
+impl Interpreter {
+    /// Gets a listing of the loaded program.
+    pub fn disassembly(&self) -> Disassembly<'_> {
+        Disassembly {
+            program: &self.program,
+        }
+    }
+}
+
+impl Disassembly<'_> {
+    /// Returns the annotation of the instruction, if it transfers control to a known line.
+    fn annotation(&self, line_number: usize, instruction: &Instruction) -> Option<String> {
+        match instruction {
+            Instruction::Call(Operand::Immediate(destination)) => {
+                match self.program.function(*destination) {
+                    Some(function) => Some(function.to_string()),
+                    None => Some(format!("line {destination}")),
+                }
+            }
+            Instruction::Jump(_, destination, relative) => {
+                let destination = destination_line(line_number, destination, relative)?;
+                Some(format!("line {destination}"))
+            }
+            Instruction::JumpTable(_, base, relative, Operand::Immediate(count)) if *count > 0 => {
+                let first = destination_line(line_number, base, relative)?;
+                let last = first.checked_add(*count)?.checked_sub(1)?;
+                Some(format!("lines {first} to {last}"))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Computes the line an immediate destination refers to, which may be relative to the line of the instruction.
+pub(crate) fn destination_line(
+    line_number: usize,
+    destination: &Operand<Word>,
+    relative: &Option<Relative>,
+) -> Option<Word> {
+    let Operand::Immediate(destination) = destination else {
+        return None;
+    };
+
+    let line_number = line_number as Word;
+    match relative {
+        Some(Relative::Positive) => line_number.checked_add(*destination),
+        Some(Relative::Negative) => line_number.checked_sub(*destination),
+        None => Some(*destination),
+    }
+}
+
+impl Display for Disassembly<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for line_number in 1..=self.program.len() {
+            let instruction = match self.program.line(line_number) {
+                Ok(Line::Source(line)) => decode::decode(line).ok(),
+                Ok(Line::Decoded(instruction)) => Some(instruction.clone()),
+                Ok(Line::Comment) | Err(_) => None,
+            };
+
+            let Some(instruction) = instruction else {
+                let line = self.program.get(line_number).unwrap_or_default();
+                writeln!(f, "{line_number:>5}  {line}")?;
+                continue;
+            };
+
+            match self.annotation(line_number, &instruction) {
+                Some(annotation) => {
+                    let instruction = instruction.to_string();
+                    writeln!(
+                        f,
+                        "{line_number:>5}  {instruction:<INSTRUCTION_WIDTH$} // {annotation}"
+                    )?;
+                }
+                None => writeln!(f, "{line_number:>5}  {instruction}")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod listing {
+    use crate::{bytecode::assemble, error::InterpreterError, Interpreter};
+
+    fn program() -> String {
+        [
+            "// sums the numbers from one to five",
+            "setb   ra 5",
+            "call add_ra",
+            "subb ra 1",
+            "jnz -2",
+            "jtab ra +2 2",
+            "jmp 10",
+            "jmp 10",
+            "fn add_ra:",
+            "addb rb ra",
+            "ret",
+        ]
+        .join("\n")
+    }
+
+    fn expected() -> String {
+        [
+            "    1  // sums the numbers from one to five",
+            "    2  setb ra 5",
+            "    3  call 10                  // add_ra",
+            "    4  subb ra 1",
+            "    5  jnz -2                   // line 3",
+            "    6  jtab ra +2 2             // lines 8 to 9",
+            "    7  jmp 10                   // line 10",
+            "    8  jmp 10                   // line 10",
+            "    9  fn add_ra:",
+            "   10  addb rb ra",
+            "   11  ret",
+            "",
+        ]
+        .join("\n")
+    }
+
+    #[test]
+    fn source_code() -> Result<(), InterpreterError> {
+        let mut i = Interpreter::new_test();
+        i.load(&program())?;
+
+        let actual = i.disassembly().to_string();
+
+        assert_eq!(actual, expected());
+
+        Ok(())
+    }
+
+    #[test]
+    fn bytecode() -> Result<(), InterpreterError> {
+        let mut i = Interpreter::new_test();
+        i.load_bytecode(&assemble(&program(), true)?)?;
+
+        let actual = i.disassembly().to_string();
+
+        assert_eq!(actual, expected());
+
+        Ok(())
+    }
+
+    #[test]
+    fn empty_jump_table_has_no_range() -> Result<(), InterpreterError> {
+        let mut i = Interpreter::new_test();
+        i.load(&["jtab ra +1 0", "stop"].join("\n"))?;
+        let expected = "    1  jtab ra +1 0\n    2  stop\n";
+
+        let actual = i.disassembly().to_string();
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn bytecode_without_line_table() -> Result<(), InterpreterError> {
+        let mut i = Interpreter::new_test();
+        i.load_bytecode(&assemble(&program(), false)?)?;
+
+        let actual = i.disassembly().to_string();
+
+        assert!(actual.starts_with("    1  //\n    2  setb ra 5\n"));
+        assert!(actual.contains("    3  call 10                  // line 10\n"));
+
+        Ok(())
+    }
+}
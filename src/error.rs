@@ -1,3 +1,4 @@
+use crate::constant::{Byte, Word};
 use std::path::PathBuf;
 use thiserror::Error;
 
@@ -18,6 +19,14 @@ pub enum InterpreterError {
     #[error("failed during preprocessing: {0}")]
     PreProcess(PreProcessError),
 
+    /// A wrapper for a snapshot error.
+    #[error("failed to restore snapshot: {0}")]
+    Snapshot(SnapshotError),
+
+    /// A wrapper for a bytecode error.
+    #[error("failed to load bytecode: {0}")]
+    Bytecode(BytecodeError),
+
     /// A wrapper for an argument error.
     #[error("faled to process command line arguments: {0}")]
     Argument(ArgumentError),
@@ -35,6 +44,43 @@ pub enum InterpreterError {
     /// in case it may be helpful to determine the underlying cause of the error.
     #[error("failed to create or open output file, underlying cause is: {0}")]
     FailedOutputFileCreation(String),
+
+    /// Indicates formatting the source code would change the meaning of the line, which is never expected.
+    ///
+    /// The source code is left as is when this occurs.
+    #[error("formatting would change the meaning of line {0}, the source code is left as is")]
+    FormatChangedMeaning(usize),
+
+    /// Indicates the program reached the maximum amount of instructions before stopping.
+    ///
+    /// The first parameter is the line of the next instruction, and the second is the amount of instructions executed.
+    #[error("stopped before line {0} after reaching the limit of {1} executed instructions")]
+    InstructionLimitReached(usize, u64),
+
+    /// Indicates the program ran for longer than the timeout before stopping.
+    ///
+    /// The first parameter is the line of the next instruction, and the second is the amount of instructions executed.
+    #[error("timed out before line {0} after {1} executed instructions")]
+    Timeout(usize, u64),
+}
+
+impl InterpreterError {
+    /// Returns the line the error occured on, if it occured while the program was being executed.
+    pub fn line(&self) -> Option<usize> {
+        match self {
+            InterpreterError::Decode(line, _)
+            | InterpreterError::Execute(line, _)
+            | InterpreterError::InvalidProgramCounter(line)
+            | InterpreterError::InstructionLimitReached(line, _)
+            | InterpreterError::Timeout(line, _) => Some(*line),
+            InterpreterError::PreProcess(_)
+            | InterpreterError::Snapshot(_)
+            | InterpreterError::Bytecode(_)
+            | InterpreterError::FormatChangedMeaning(_)
+            | InterpreterError::Argument(_)
+            | InterpreterError::FailedOutputFileCreation(_) => None,
+        }
+    }
 }
 
 /// Represents an error during the decoding of an instruction.
@@ -63,6 +109,10 @@ pub enum DecodeError {
     #[error("incomplete instruction")]
     IncompleteInstruction,
 
+    /// Indicates a parameter could not be parsed to a print format.
+    #[error("invalid print format '{0}'")]
+    InvalidPrintFormat(String),
+
     /// Indicates an empty line was specified for decoding.
     #[error("could not decode empty line")]
     EmptyLine,
@@ -97,6 +147,45 @@ pub enum ExecuteError {
     /// Indicates a program counter underflow during relative jump.
     #[error("the program counter underflowed")]
     ProgramCounterUnderflow,
+
+    /// Indicates an instruction transferred control to a line outside the program.
+    ///
+    /// The first parameter is the destination, and the second is the amount of lines in the program.
+    #[error("the destination '{0}' is outside the program, which spans lines 1 to {1}")]
+    JumpOutOfBounds(Word, usize),
+
+    /// Indicates a return to an address that was not pushed by the innermost call,
+    /// meaning the stack has been corrupted or there is no call to return from.
+    #[error("the return address '{0}' was not pushed by a call, the stack may be corrupted")]
+    InvalidReturnAddress(Word),
+
+    /// Indicates the index of a jump table instruction exceeded the amount of entries in the table.
+    #[error("the jump table index '{0}' is out of bounds for a table with {1} entries")]
+    JumpTableIndexOutOfBounds(Word, Word),
+
+    /// Indicates a bit index exceeded the amount of bits in the size of the instruction.
+    #[error("the bit index '{0}' is out of bounds for a value of {1} bits")]
+    InvalidBitIndex(Byte, u32),
+
+    /// Indicates a bit field extended beyond the amount of bits in the size of the instruction.
+    ///
+    /// The parameters are the start of the bit field, its length, and the amount of bits in the value.
+    #[error(
+        "the bit field starting at '{0}' with length '{1}' is out of bounds for a value of {2} bits"
+    )]
+    InvalidBitField(Byte, Byte, u32),
+
+    /// Indicates a value printed as a character is not a valid unicode scalar value.
+    #[error("the value '{0}' is not a valid character")]
+    InvalidCharacter(Word),
+
+    /// Indicates a program called a host function that has not been registered.
+    #[error("no host function named '{0}' is registered")]
+    UnknownHostFunction(String),
+
+    /// Indicates a host function failed, with a message provided by the host function.
+    #[error("the host function failed: {0}")]
+    HostFunction(String),
 }
 
 /// Represents an error during the parsing and substitution during preprocessing.
@@ -131,6 +220,54 @@ pub enum PreProcessError {
 
     #[error("a function was named after an instruction")]
     FunctionNamedAfterInstruction,
+
+    #[error("label '{0}' is not a dot followed by a snake case name")]
+    InvalidLabelFormat(String),
+
+    /// Indicates a label definition followed by other tokens, as labels are defined on a line of their own.
+    #[error("label '{0}' is not on a line of its own")]
+    LabelNotOnOwnLine(String),
+
+    /// Indicates the same label defined multiple times within the same function.
+    #[error("label '{0}' is defined multiple times in the same scope")]
+    DuplicateLabel(String),
+
+    /// Indicates a jump to a label that is not defined anywhere in the program.
+    #[error("jumped to undefined label '{0}'")]
+    UndefinedLabel(String),
+
+    /// Indicates a jump to a label that is local to another function.
+    ///
+    /// The first parameter is the label, and the second is the scope it is defined in.
+    #[error("label '{0}' is local to {1}, so it can only be jumped to from there")]
+    LabelOutOfScope(String, String),
+
+    /// Indicates a constant declaration that is not of the form `const NAME: type = value`.
+    #[error("the constant declaration '{0}' is not of the form 'const NAME: type = value'")]
+    InvalidConstantDeclaration(String),
+
+    #[error("the format for the constant '{0}' is invalid")]
+    InvalidConstantNameFormat(String),
+
+    /// Indicates a constant declared with a type other than byte, quarter, half or word.
+    #[error("constant '{0}' has the unknown type '{1}', expected byte, quarter, half or word")]
+    UnknownConstantType(String, String),
+
+    /// Indicates a constant with a value that is not valid for its type.
+    ///
+    /// The parameters are the constant, its value and its type.
+    #[error("the value '{1}' of constant '{0}' is not a valid {2}")]
+    InvalidConstantValue(String, String, String),
+
+    /// Indicates the same constant declared multiple times.
+    #[error("constant '{0}' is declared multiple times")]
+    DuplicateConstant(String),
+
+    /// Indicates a constant used by an instruction of a smaller size than the type of the constant.
+    ///
+    /// The parameters are the constant, its type and the instruction it is used in.
+    #[error("constant '{0}' is a {1}, which is too large for the instruction '{2}'")]
+    ConstantTooLarge(String, String, String),
 }
 
 #[derive(Debug, Error, PartialEq)]
@@ -174,7 +311,126 @@ pub enum ArgumentError {
     #[error("the program file contains invalid encoding, it must be UTF-8 compatible")]
     ProgramFileInvalidEncoding,
 
+    /// Indicates the snapshot file could not be read.
+    ///
+    /// The underlying error is from the standard library and is only provided to the user
+    /// in case it may be helpful to determine the underlying cause of the error.
+    #[error("failed to read snapshot file, underlying cause is: {0}")]
+    FailedSnapshotRead(String),
+
+    /// Indicates the snapshot file could not be written.
+    ///
+    /// The underlying error is from the standard library and is only provided to the user
+    /// in case it may be helpful to determine the underlying cause of the error.
+    #[error("failed to write snapshot file, underlying cause is: {0}")]
+    FailedSnapshotWrite(String),
+
+    /// Indicates the crash report file could not be written.
+    ///
+    /// The underlying error is from the standard library and is only provided to the user
+    /// in case it may be helpful to determine the underlying cause of the error.
+    #[error("failed to write crash report file, underlying cause is: {0}")]
+    FailedCrashReportWrite(String),
+
+    /// Indicates a profile file could not be written.
+    ///
+    /// The underlying error is from the standard library and is only provided to the user
+    /// in case it may be helpful to determine the underlying cause of the error.
+    #[error("failed to write profile file, underlying cause is: {0}")]
+    FailedProfileWrite(String),
+
+    /// Indicates a coverage file could not be written.
+    ///
+    /// The underlying error is from the standard library and is only provided to the user
+    /// in case it may be helpful to determine the underlying cause of the error.
+    #[error("failed to write coverage file, underlying cause is: {0}")]
+    FailedCoverageWrite(String),
+
+    /// Indicates the assembled bytecode file could not be written.
+    ///
+    /// The underlying error is from the standard library and is only provided to the user
+    /// in case it may be helpful to determine the underlying cause of the error.
+    #[error("failed to write bytecode file, underlying cause is: {0}")]
+    FailedBytecodeWrite(String),
+
+    /// Indicates a formatted program file could not be written.
+    ///
+    /// The underlying error is from the standard library and is only provided to the user
+    /// in case it may be helpful to determine the underlying cause of the error.
+    #[error("failed to write formatted program file, underlying cause is: {0}")]
+    FailedFormatWrite(String),
+
+    /// Indicates the duration of the timeout flag could not be parsed.
+    #[error("invalid duration '{0}', it must be a whole number followed by ms, s, m or h")]
+    InvalidDuration(String),
+
     /// Indicates the specified program file is not in fact a file.
     #[error("'{0}' is not a file")]
     NotAFile(PathBuf),
 }
+
+/// Represents an error during the restoration of a snapshot.
+#[derive(Debug, Error, PartialEq)]
+pub enum SnapshotError {
+    /// Indicates the snapshot does not start with the expected magic bytes, meaning it is not a snapshot.
+    #[error("the file is not a snapshot")]
+    InvalidMagic,
+
+    /// Indicates the snapshot was created with a format version that is not supported.
+    #[error("the snapshot format version '{0}' is not supported")]
+    UnsupportedVersion(u8),
+
+    /// Indicates the snapshot ended before all of the state was read.
+    #[error("the snapshot is truncated")]
+    Truncated,
+
+    /// Indicates the snapshot contains more bytes than the state it describes.
+    #[error("the snapshot contains unexpected trailing bytes")]
+    TrailingBytes,
+
+    /// Indicates the snapshot was taken of a different program than the one loaded.
+    #[error("the snapshot was taken of a different program")]
+    ProgramMismatch,
+
+    /// Indicates the stack in the snapshot does not fit in the stack of the interpreter.
+    ///
+    /// The first parameter is the amount of bytes in the snapshot, and the second is the size of the stack.
+    #[error("the snapshot contains {0} bytes of stack, but the stack size is {1} bytes")]
+    StackTooSmall(usize, usize),
+}
+
+/// Represents an error during the loading of bytecode.
+#[derive(Debug, Error, PartialEq)]
+pub enum BytecodeError {
+    /// Indicates the bytecode does not start with the expected magic bytes, meaning it is not bytecode.
+    #[error("the file is not bytecode")]
+    InvalidMagic,
+
+    /// Indicates the bytecode was assembled with a format version that is not supported.
+    #[error("the bytecode format version '{0}' is not supported")]
+    UnsupportedVersion(u8),
+
+    /// Indicates the checksum in the header does not match the contents, meaning the bytecode is corrupted.
+    #[error("the bytecode checksum does not match its contents")]
+    ChecksumMismatch,
+
+    /// Indicates the bytecode ended before the whole program was read.
+    #[error("the bytecode is truncated")]
+    Truncated,
+
+    /// Indicates the bytecode contains more bytes than the program it describes.
+    #[error("the bytecode contains unexpected trailing bytes")]
+    TrailingBytes,
+
+    /// Indicates an instruction with an opcode that does not exist.
+    #[error("unknown opcode '{0}'")]
+    UnknownOpcode(Byte),
+
+    /// Indicates a parameter of an instruction, such as a register or size, is encoded with a value that does not exist.
+    #[error("invalid encoded value '{0}'")]
+    InvalidValue(Byte),
+
+    /// Indicates text in the bytecode, such as a line of the source code, is not valid UTF-8.
+    #[error("the bytecode contains text that is not valid UTF-8")]
+    InvalidText,
+}
@@ -0,0 +1,399 @@
+use crate::{
+    constant::{Byte, Half, Quarter, Word},
+    error::ExecuteError,
+    instruction::BitExtract,
+    operand::Operand,
+    register::Register,
+    registers::RegisterOperations,
+    utils::BitWise,
+    Interpreter,
+};
+
+impl Interpreter {
+    pub(super) fn bit_extract(&mut self, instruction: BitExtract) -> Result<(), ExecuteError> {
+        match instruction {
+            BitExtract::Byte(r, s, l) => self.bit_extract_value::<Byte>(r, s, l),
+            BitExtract::Quarter(r, s, l) => self.bit_extract_value::<Quarter>(r, s, l),
+            BitExtract::Half(r, s, l) => self.bit_extract_value::<Half>(r, s, l),
+            BitExtract::Word(r, s, l) => self.bit_extract_value::<Word>(r, s, l),
+        }
+    }
+
+    fn bit_extract_value<T>(
+        &mut self,
+        register: Register,
+        start: Operand<Byte>,
+        length: Operand<Byte>,
+    ) -> Result<(), ExecuteError>
+    where
+        T: BitWise,
+    {
+        let (mask, start) = self.bit_field_mask::<T>(start, length)?;
+        let value = self.registers.get::<T>(register);
+        let result = value.bit_and(mask).bit_shift_right(start);
+        self.flags.set(result, false);
+        self.registers.set(register, result);
+
+        Ok(())
+    }
+
+    /// Gets a mask with the bits of the bit field set, along with the start of the bit field.
+    ///
+    /// # Errors
+    /// Returns an error if the bit field extends beyond the amount of bits in the size of the instruction.
+    pub(super) fn bit_field_mask<T>(
+        &self,
+        start: Operand<Byte>,
+        length: Operand<Byte>,
+    ) -> Result<(T, u32), ExecuteError>
+    where
+        T: BitWise,
+    {
+        let start = self.get_operand_value(start);
+        let length = self.get_operand_value(length);
+        let mask = T::bit_field_mask(u32::from(start), u32::from(length))
+            .ok_or(ExecuteError::InvalidBitField(start, length, T::BITS))?;
+
+        Ok((mask, u32::from(start)))
+    }
+}
+
+#[cfg(test)]
+mod byte {
+    use crate::{
+        constant::Byte,
+        error::ExecuteError,
+        instruction::{BitExtract, Instruction},
+        operand::Operand,
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn extracts_field() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Byte>(Register::A, 0b0110_1100);
+        let instruction = Instruction::BitExtract(BitExtract::Byte(
+            Register::A,
+            Operand::Immediate(2),
+            Operand::Immediate(4),
+        ));
+        let expected: Byte = 0b1011;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Byte>(Register::A), expected);
+        assert!(!i.flags.zero);
+
+        Ok(())
+    }
+
+    #[test]
+    fn whole_value_from_registers() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set(Register::A, Byte::MAX);
+        i.registers.set::<Byte>(Register::B, 0);
+        i.registers.set::<Byte>(Register::C, 8);
+        let instruction = Instruction::BitExtract(BitExtract::Byte(
+            Register::A,
+            Operand::Register(Register::B),
+            Operand::Register(Register::C),
+        ));
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Byte>(Register::A), Byte::MAX);
+        assert!(i.flags.sign);
+
+        Ok(())
+    }
+
+    #[test]
+    fn empty_field_is_zero() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set(Register::A, Byte::MAX);
+        let instruction = Instruction::BitExtract(BitExtract::Byte(
+            Register::A,
+            Operand::Immediate(8),
+            Operand::Immediate(0),
+        ));
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Byte>(Register::A), 0);
+        assert!(i.flags.zero);
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_bit_field() {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::BitExtract(BitExtract::Byte(
+            Register::A,
+            Operand::Immediate(6),
+            Operand::Immediate(3),
+        ));
+        let expected = Err(ExecuteError::InvalidBitField(6, 3, 8));
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+    }
+}
+
+#[cfg(test)]
+mod quarter {
+    use crate::{
+        constant::{Byte, Quarter},
+        error::ExecuteError,
+        instruction::{BitExtract, Instruction},
+        operand::Operand,
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn extracts_field() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Quarter>(Register::A, 0b0110_1100);
+        let instruction = Instruction::BitExtract(BitExtract::Quarter(
+            Register::A,
+            Operand::Immediate(2),
+            Operand::Immediate(4),
+        ));
+        let expected: Quarter = 0b1011;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Quarter>(Register::A), expected);
+        assert!(!i.flags.zero);
+
+        Ok(())
+    }
+
+    #[test]
+    fn whole_value_from_registers() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set(Register::A, Quarter::MAX);
+        i.registers.set::<Byte>(Register::B, 0);
+        i.registers.set::<Byte>(Register::C, 16);
+        let instruction = Instruction::BitExtract(BitExtract::Quarter(
+            Register::A,
+            Operand::Register(Register::B),
+            Operand::Register(Register::C),
+        ));
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Quarter>(Register::A), Quarter::MAX);
+        assert!(i.flags.sign);
+
+        Ok(())
+    }
+
+    #[test]
+    fn empty_field_is_zero() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set(Register::A, Quarter::MAX);
+        let instruction = Instruction::BitExtract(BitExtract::Quarter(
+            Register::A,
+            Operand::Immediate(16),
+            Operand::Immediate(0),
+        ));
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Quarter>(Register::A), 0);
+        assert!(i.flags.zero);
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_bit_field() {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::BitExtract(BitExtract::Quarter(
+            Register::A,
+            Operand::Immediate(14),
+            Operand::Immediate(3),
+        ));
+        let expected = Err(ExecuteError::InvalidBitField(14, 3, 16));
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+    }
+}
+
+#[cfg(test)]
+mod half {
+    use crate::{
+        constant::{Byte, Half},
+        error::ExecuteError,
+        instruction::{BitExtract, Instruction},
+        operand::Operand,
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn extracts_field() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Half>(Register::A, 0b0110_1100);
+        let instruction = Instruction::BitExtract(BitExtract::Half(
+            Register::A,
+            Operand::Immediate(2),
+            Operand::Immediate(4),
+        ));
+        let expected: Half = 0b1011;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Half>(Register::A), expected);
+        assert!(!i.flags.zero);
+
+        Ok(())
+    }
+
+    #[test]
+    fn whole_value_from_registers() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set(Register::A, Half::MAX);
+        i.registers.set::<Byte>(Register::B, 0);
+        i.registers.set::<Byte>(Register::C, 32);
+        let instruction = Instruction::BitExtract(BitExtract::Half(
+            Register::A,
+            Operand::Register(Register::B),
+            Operand::Register(Register::C),
+        ));
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Half>(Register::A), Half::MAX);
+        assert!(i.flags.sign);
+
+        Ok(())
+    }
+
+    #[test]
+    fn empty_field_is_zero() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set(Register::A, Half::MAX);
+        let instruction = Instruction::BitExtract(BitExtract::Half(
+            Register::A,
+            Operand::Immediate(32),
+            Operand::Immediate(0),
+        ));
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Half>(Register::A), 0);
+        assert!(i.flags.zero);
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_bit_field() {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::BitExtract(BitExtract::Half(
+            Register::A,
+            Operand::Immediate(30),
+            Operand::Immediate(3),
+        ));
+        let expected = Err(ExecuteError::InvalidBitField(30, 3, 32));
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+    }
+}
+
+#[cfg(test)]
+mod word {
+    use crate::{
+        constant::{Byte, Word},
+        error::ExecuteError,
+        instruction::{BitExtract, Instruction},
+        operand::Operand,
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn extracts_field() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Word>(Register::A, 0b0110_1100);
+        let instruction = Instruction::BitExtract(BitExtract::Word(
+            Register::A,
+            Operand::Immediate(2),
+            Operand::Immediate(4),
+        ));
+        let expected: Word = 0b1011;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Word>(Register::A), expected);
+        assert!(!i.flags.zero);
+
+        Ok(())
+    }
+
+    #[test]
+    fn whole_value_from_registers() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set(Register::A, Word::MAX);
+        i.registers.set::<Byte>(Register::B, 0);
+        i.registers.set::<Byte>(Register::C, 64);
+        let instruction = Instruction::BitExtract(BitExtract::Word(
+            Register::A,
+            Operand::Register(Register::B),
+            Operand::Register(Register::C),
+        ));
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Word>(Register::A), Word::MAX);
+        assert!(i.flags.sign);
+
+        Ok(())
+    }
+
+    #[test]
+    fn empty_field_is_zero() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set(Register::A, Word::MAX);
+        let instruction = Instruction::BitExtract(BitExtract::Word(
+            Register::A,
+            Operand::Immediate(64),
+            Operand::Immediate(0),
+        ));
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Word>(Register::A), 0);
+        assert!(i.flags.zero);
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_bit_field() {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::BitExtract(BitExtract::Word(
+            Register::A,
+            Operand::Immediate(62),
+            Operand::Immediate(3),
+        ));
+        let expected = Err(ExecuteError::InvalidBitField(62, 3, 64));
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+    }
+}
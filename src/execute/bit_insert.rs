@@ -0,0 +1,315 @@
+use crate::{
+    constant::Byte, error::ExecuteError, instruction::BitInsert, operand::Operand,
+    register::Register, registers::RegisterOperations, utils::BitWise, Interpreter,
+};
+
+impl Interpreter {
+    pub(super) fn bit_insert(&mut self, instruction: BitInsert) -> Result<(), ExecuteError> {
+        match instruction {
+            BitInsert::Byte(r, o, s, l) => self.bit_insert_value(r, o, s, l),
+            BitInsert::Quarter(r, o, s, l) => self.bit_insert_value(r, o, s, l),
+            BitInsert::Half(r, o, s, l) => self.bit_insert_value(r, o, s, l),
+            BitInsert::Word(r, o, s, l) => self.bit_insert_value(r, o, s, l),
+        }
+    }
+
+    fn bit_insert_value<T>(
+        &mut self,
+        register: Register,
+        operand: Operand<T>,
+        start: Operand<Byte>,
+        length: Operand<Byte>,
+    ) -> Result<(), ExecuteError>
+    where
+        T: BitWise,
+    {
+        let (mask, start) = self.bit_field_mask::<T>(start, length)?;
+        let value = self.registers.get::<T>(register);
+        let field = self.get_operand_value(operand).bit_shift_left(start);
+        let result = value.bit_and(mask.bit_not()).bit_or(field.bit_and(mask));
+        self.flags.set(result, false);
+        self.registers.set(register, result);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod byte {
+    use crate::{
+        constant::Byte,
+        error::ExecuteError,
+        instruction::{BitInsert, Instruction},
+        operand::Operand,
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn inserts_field() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Byte>(Register::A, 0b1100_0011);
+        let instruction = Instruction::BitInsert(BitInsert::Byte(
+            Register::A,
+            Operand::Immediate(0b1001),
+            Operand::Immediate(2),
+            Operand::Immediate(4),
+        ));
+        let expected: Byte = 0b1110_0111;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Byte>(Register::A), expected);
+        assert!(!i.flags.zero);
+
+        Ok(())
+    }
+
+    #[test]
+    fn excess_bits_of_operand_are_discarded() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Byte>(Register::B, Byte::MAX);
+        let instruction = Instruction::BitInsert(BitInsert::Byte(
+            Register::A,
+            Operand::Register(Register::B),
+            Operand::Immediate(1),
+            Operand::Immediate(2),
+        ));
+        let expected: Byte = 0b110;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Byte>(Register::A), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_bit_field() {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Byte>(Register::A, 1);
+        let instruction = Instruction::BitInsert(BitInsert::Byte(
+            Register::A,
+            Operand::Immediate(0),
+            Operand::Immediate(1),
+            Operand::Immediate(8),
+        ));
+        let expected = Err(ExecuteError::InvalidBitField(1, 8, 8));
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+        assert_eq!(i.registers.get::<Byte>(Register::A), 1);
+    }
+}
+
+#[cfg(test)]
+mod quarter {
+    use crate::{
+        constant::Quarter,
+        error::ExecuteError,
+        instruction::{BitInsert, Instruction},
+        operand::Operand,
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn inserts_field() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Quarter>(Register::A, 0b1100_0011);
+        let instruction = Instruction::BitInsert(BitInsert::Quarter(
+            Register::A,
+            Operand::Immediate(0b1001),
+            Operand::Immediate(2),
+            Operand::Immediate(4),
+        ));
+        let expected: Quarter = 0b1110_0111;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Quarter>(Register::A), expected);
+        assert!(!i.flags.zero);
+
+        Ok(())
+    }
+
+    #[test]
+    fn excess_bits_of_operand_are_discarded() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Quarter>(Register::B, Quarter::MAX);
+        let instruction = Instruction::BitInsert(BitInsert::Quarter(
+            Register::A,
+            Operand::Register(Register::B),
+            Operand::Immediate(1),
+            Operand::Immediate(2),
+        ));
+        let expected: Quarter = 0b110;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Quarter>(Register::A), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_bit_field() {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Quarter>(Register::A, 1);
+        let instruction = Instruction::BitInsert(BitInsert::Quarter(
+            Register::A,
+            Operand::Immediate(0),
+            Operand::Immediate(1),
+            Operand::Immediate(16),
+        ));
+        let expected = Err(ExecuteError::InvalidBitField(1, 16, 16));
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+        assert_eq!(i.registers.get::<Quarter>(Register::A), 1);
+    }
+}
+
+#[cfg(test)]
+mod half {
+    use crate::{
+        constant::Half,
+        error::ExecuteError,
+        instruction::{BitInsert, Instruction},
+        operand::Operand,
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn inserts_field() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Half>(Register::A, 0b1100_0011);
+        let instruction = Instruction::BitInsert(BitInsert::Half(
+            Register::A,
+            Operand::Immediate(0b1001),
+            Operand::Immediate(2),
+            Operand::Immediate(4),
+        ));
+        let expected: Half = 0b1110_0111;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Half>(Register::A), expected);
+        assert!(!i.flags.zero);
+
+        Ok(())
+    }
+
+    #[test]
+    fn excess_bits_of_operand_are_discarded() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Half>(Register::B, Half::MAX);
+        let instruction = Instruction::BitInsert(BitInsert::Half(
+            Register::A,
+            Operand::Register(Register::B),
+            Operand::Immediate(1),
+            Operand::Immediate(2),
+        ));
+        let expected: Half = 0b110;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Half>(Register::A), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_bit_field() {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Half>(Register::A, 1);
+        let instruction = Instruction::BitInsert(BitInsert::Half(
+            Register::A,
+            Operand::Immediate(0),
+            Operand::Immediate(1),
+            Operand::Immediate(32),
+        ));
+        let expected = Err(ExecuteError::InvalidBitField(1, 32, 32));
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+        assert_eq!(i.registers.get::<Half>(Register::A), 1);
+    }
+}
+
+#[cfg(test)]
+mod word {
+    use crate::{
+        constant::Word,
+        error::ExecuteError,
+        instruction::{BitInsert, Instruction},
+        operand::Operand,
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn inserts_field() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Word>(Register::A, 0b1100_0011);
+        let instruction = Instruction::BitInsert(BitInsert::Word(
+            Register::A,
+            Operand::Immediate(0b1001),
+            Operand::Immediate(2),
+            Operand::Immediate(4),
+        ));
+        let expected: Word = 0b1110_0111;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Word>(Register::A), expected);
+        assert!(!i.flags.zero);
+
+        Ok(())
+    }
+
+    #[test]
+    fn excess_bits_of_operand_are_discarded() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Word>(Register::B, Word::MAX);
+        let instruction = Instruction::BitInsert(BitInsert::Word(
+            Register::A,
+            Operand::Register(Register::B),
+            Operand::Immediate(1),
+            Operand::Immediate(2),
+        ));
+        let expected: Word = 0b110;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Word>(Register::A), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_bit_field() {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Word>(Register::A, 1);
+        let instruction = Instruction::BitInsert(BitInsert::Word(
+            Register::A,
+            Operand::Immediate(0),
+            Operand::Immediate(1),
+            Operand::Immediate(64),
+        ));
+        let expected = Err(ExecuteError::InvalidBitField(1, 64, 64));
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+        assert_eq!(i.registers.get::<Word>(Register::A), 1);
+    }
+}
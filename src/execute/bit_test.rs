@@ -0,0 +1,288 @@
+use crate::{
+    constant::Byte, error::ExecuteError, instruction::BitTest, operand::Operand, utils::BitWise,
+    Interpreter,
+};
+
+impl Interpreter {
+    pub(super) fn bit_test(&mut self, instruction: BitTest) -> Result<(), ExecuteError> {
+        match instruction {
+            BitTest::Byte(o, i) => self.bit_test_value(o, i),
+            BitTest::Quarter(o, i) => self.bit_test_value(o, i),
+            BitTest::Half(o, i) => self.bit_test_value(o, i),
+            BitTest::Word(o, i) => self.bit_test_value(o, i),
+        }
+    }
+
+    fn bit_test_value<T>(
+        &mut self,
+        operand: Operand<T>,
+        index: Operand<Byte>,
+    ) -> Result<(), ExecuteError>
+    where
+        T: BitWise,
+    {
+        let value = self.get_operand_value(operand);
+        let mask = self.bit_mask::<T>(index)?;
+        self.flags.set(value.bit_and(mask), false);
+
+        Ok(())
+    }
+
+    /// Gets a mask with only the bit at the index set.
+    ///
+    /// # Errors
+    /// Returns an error if the index exceeds the amount of bits in the size of the instruction.
+    pub(super) fn bit_mask<T>(&self, index: Operand<Byte>) -> Result<T, ExecuteError>
+    where
+        T: BitWise,
+    {
+        let index = self.get_operand_value(index);
+        T::bit_mask(u32::from(index)).ok_or(ExecuteError::InvalidBitIndex(index, T::BITS))
+    }
+}
+
+#[cfg(test)]
+mod byte {
+    use crate::{
+        constant::Byte,
+        error::ExecuteError,
+        instruction::{BitTest, Instruction},
+        operand::Operand,
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn bit_set() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::BitTest(BitTest::Byte(
+            Operand::Immediate(Byte::MAX),
+            Operand::Immediate(7),
+        ));
+
+        i.execute(instruction)?;
+
+        assert!(!i.flags.zero);
+        assert!(i.flags.sign);
+        assert!(!i.flags.overflow);
+
+        Ok(())
+    }
+
+    #[test]
+    fn bit_clear_from_register() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Byte>(Register::A, 0b1011);
+        i.registers.set::<Byte>(Register::B, 2);
+        let instruction = Instruction::BitTest(BitTest::Byte(
+            Operand::Register(Register::A),
+            Operand::Register(Register::B),
+        ));
+
+        i.execute(instruction)?;
+
+        assert!(i.flags.zero);
+        assert!(!i.flags.sign);
+        assert_eq!(i.registers.get::<Byte>(Register::A), 0b1011);
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_bit_index() {
+        let mut i = Interpreter::new_test();
+        let instruction =
+            Instruction::BitTest(BitTest::Byte(Operand::Immediate(1), Operand::Immediate(8)));
+        let expected = Err(ExecuteError::InvalidBitIndex(8, 8));
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+    }
+}
+
+#[cfg(test)]
+mod quarter {
+    use crate::{
+        constant::{Byte, Quarter},
+        error::ExecuteError,
+        instruction::{BitTest, Instruction},
+        operand::Operand,
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn bit_set() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::BitTest(BitTest::Quarter(
+            Operand::Immediate(Quarter::MAX),
+            Operand::Immediate(15),
+        ));
+
+        i.execute(instruction)?;
+
+        assert!(!i.flags.zero);
+        assert!(i.flags.sign);
+        assert!(!i.flags.overflow);
+
+        Ok(())
+    }
+
+    #[test]
+    fn bit_clear_from_register() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Quarter>(Register::A, 0b1011);
+        i.registers.set::<Byte>(Register::B, 2);
+        let instruction = Instruction::BitTest(BitTest::Quarter(
+            Operand::Register(Register::A),
+            Operand::Register(Register::B),
+        ));
+
+        i.execute(instruction)?;
+
+        assert!(i.flags.zero);
+        assert!(!i.flags.sign);
+        assert_eq!(i.registers.get::<Quarter>(Register::A), 0b1011);
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_bit_index() {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::BitTest(BitTest::Quarter(
+            Operand::Immediate(1),
+            Operand::Immediate(16),
+        ));
+        let expected = Err(ExecuteError::InvalidBitIndex(16, 16));
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+    }
+}
+
+#[cfg(test)]
+mod half {
+    use crate::{
+        constant::{Byte, Half},
+        error::ExecuteError,
+        instruction::{BitTest, Instruction},
+        operand::Operand,
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn bit_set() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::BitTest(BitTest::Half(
+            Operand::Immediate(Half::MAX),
+            Operand::Immediate(31),
+        ));
+
+        i.execute(instruction)?;
+
+        assert!(!i.flags.zero);
+        assert!(i.flags.sign);
+        assert!(!i.flags.overflow);
+
+        Ok(())
+    }
+
+    #[test]
+    fn bit_clear_from_register() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Half>(Register::A, 0b1011);
+        i.registers.set::<Byte>(Register::B, 2);
+        let instruction = Instruction::BitTest(BitTest::Half(
+            Operand::Register(Register::A),
+            Operand::Register(Register::B),
+        ));
+
+        i.execute(instruction)?;
+
+        assert!(i.flags.zero);
+        assert!(!i.flags.sign);
+        assert_eq!(i.registers.get::<Half>(Register::A), 0b1011);
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_bit_index() {
+        let mut i = Interpreter::new_test();
+        let instruction =
+            Instruction::BitTest(BitTest::Half(Operand::Immediate(1), Operand::Immediate(32)));
+        let expected = Err(ExecuteError::InvalidBitIndex(32, 32));
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+    }
+}
+
+#[cfg(test)]
+mod word {
+    use crate::{
+        constant::{Byte, Word},
+        error::ExecuteError,
+        instruction::{BitTest, Instruction},
+        operand::Operand,
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn bit_set() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::BitTest(BitTest::Word(
+            Operand::Immediate(Word::MAX),
+            Operand::Immediate(63),
+        ));
+
+        i.execute(instruction)?;
+
+        assert!(!i.flags.zero);
+        assert!(i.flags.sign);
+        assert!(!i.flags.overflow);
+
+        Ok(())
+    }
+
+    #[test]
+    fn bit_clear_from_register() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Word>(Register::A, 0b1011);
+        i.registers.set::<Byte>(Register::B, 2);
+        let instruction = Instruction::BitTest(BitTest::Word(
+            Operand::Register(Register::A),
+            Operand::Register(Register::B),
+        ));
+
+        i.execute(instruction)?;
+
+        assert!(i.flags.zero);
+        assert!(!i.flags.sign);
+        assert_eq!(i.registers.get::<Word>(Register::A), 0b1011);
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_bit_index() {
+        let mut i = Interpreter::new_test();
+        let instruction =
+            Instruction::BitTest(BitTest::Word(Operand::Immediate(1), Operand::Immediate(64)));
+        let expected = Err(ExecuteError::InvalidBitIndex(64, 64));
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,322 @@
+use crate::{
+    constant::{Byte, Half, Quarter, Word},
+    error::ExecuteError,
+    instruction::BitTestAndReset,
+    operand::Operand,
+    register::Register,
+    registers::RegisterOperations,
+    utils::BitWise,
+    Interpreter,
+};
+
+impl Interpreter {
+    pub(super) fn bit_test_and_reset(
+        &mut self,
+        instruction: BitTestAndReset,
+    ) -> Result<(), ExecuteError> {
+        match instruction {
+            BitTestAndReset::Byte(r, i) => self.bit_test_and_reset_value::<Byte>(r, i),
+            BitTestAndReset::Quarter(r, i) => self.bit_test_and_reset_value::<Quarter>(r, i),
+            BitTestAndReset::Half(r, i) => self.bit_test_and_reset_value::<Half>(r, i),
+            BitTestAndReset::Word(r, i) => self.bit_test_and_reset_value::<Word>(r, i),
+        }
+    }
+
+    fn bit_test_and_reset_value<T>(
+        &mut self,
+        register: Register,
+        index: Operand<Byte>,
+    ) -> Result<(), ExecuteError>
+    where
+        T: BitWise,
+    {
+        let value = self.registers.get::<T>(register);
+        let mask = self.bit_mask::<T>(index)?;
+        self.flags.set(value.bit_and(mask), false);
+        self.registers.set(register, value.bit_and(mask.bit_not()));
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod byte {
+    use crate::{
+        constant::Byte,
+        error::ExecuteError,
+        instruction::{BitTestAndReset, Instruction},
+        operand::Operand,
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn resets_set_bit() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Byte>(Register::A, 0b1001);
+        let instruction =
+            Instruction::BitTestAndReset(BitTestAndReset::Byte(Register::A, Operand::Immediate(3)));
+        let expected: Byte = 0b0001;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Byte>(Register::A), expected);
+        assert!(!i.flags.zero);
+
+        Ok(())
+    }
+
+    #[test]
+    fn clear_bit_is_unchanged() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Byte>(Register::A, 0b0001);
+        let instruction =
+            Instruction::BitTestAndReset(BitTestAndReset::Byte(Register::A, Operand::Immediate(3)));
+        let expected: Byte = 0b0001;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Byte>(Register::A), expected);
+        assert!(i.flags.zero);
+
+        Ok(())
+    }
+
+    #[test]
+    fn index_from_register() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Byte>(Register::A, 0b1000_0000);
+        i.registers.set::<Byte>(Register::B, 7);
+        let instruction = Instruction::BitTestAndReset(BitTestAndReset::Byte(
+            Register::A,
+            Operand::Register(Register::B),
+        ));
+
+        i.execute(instruction)?;
+
+        assert!(!i.flags.zero);
+        assert!(i.flags.sign);
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_bit_index() {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Byte>(Register::A, 1);
+        let instruction =
+            Instruction::BitTestAndReset(BitTestAndReset::Byte(Register::A, Operand::Immediate(8)));
+        let expected = Err(ExecuteError::InvalidBitIndex(8, 8));
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+        assert_eq!(i.registers.get::<Byte>(Register::A), 1);
+    }
+}
+
+#[cfg(test)]
+mod quarter {
+    use crate::{
+        constant::Quarter,
+        error::ExecuteError,
+        instruction::{BitTestAndReset, Instruction},
+        operand::Operand,
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn resets_set_bit() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Quarter>(Register::A, 0b1001);
+        let instruction = Instruction::BitTestAndReset(BitTestAndReset::Quarter(
+            Register::A,
+            Operand::Immediate(3),
+        ));
+        let expected: Quarter = 0b0001;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Quarter>(Register::A), expected);
+        assert!(!i.flags.zero);
+
+        Ok(())
+    }
+
+    #[test]
+    fn clear_bit_is_unchanged() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Quarter>(Register::A, 0b0001);
+        let instruction = Instruction::BitTestAndReset(BitTestAndReset::Quarter(
+            Register::A,
+            Operand::Immediate(3),
+        ));
+        let expected: Quarter = 0b0001;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Quarter>(Register::A), expected);
+        assert!(i.flags.zero);
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_bit_index() {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Quarter>(Register::A, 1);
+        let instruction = Instruction::BitTestAndReset(BitTestAndReset::Quarter(
+            Register::A,
+            Operand::Immediate(16),
+        ));
+        let expected = Err(ExecuteError::InvalidBitIndex(16, 16));
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+        assert_eq!(i.registers.get::<Quarter>(Register::A), 1);
+    }
+}
+
+#[cfg(test)]
+mod half {
+    use crate::{
+        constant::Half,
+        error::ExecuteError,
+        instruction::{BitTestAndReset, Instruction},
+        operand::Operand,
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn resets_set_bit() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Half>(Register::A, 0b1001);
+        let instruction =
+            Instruction::BitTestAndReset(BitTestAndReset::Half(Register::A, Operand::Immediate(3)));
+        let expected: Half = 0b0001;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Half>(Register::A), expected);
+        assert!(!i.flags.zero);
+
+        Ok(())
+    }
+
+    #[test]
+    fn clear_bit_is_unchanged() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Half>(Register::A, 0b0001);
+        let instruction =
+            Instruction::BitTestAndReset(BitTestAndReset::Half(Register::A, Operand::Immediate(3)));
+        let expected: Half = 0b0001;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Half>(Register::A), expected);
+        assert!(i.flags.zero);
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_bit_index() {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Half>(Register::A, 1);
+        let instruction = Instruction::BitTestAndReset(BitTestAndReset::Half(
+            Register::A,
+            Operand::Immediate(32),
+        ));
+        let expected = Err(ExecuteError::InvalidBitIndex(32, 32));
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+        assert_eq!(i.registers.get::<Half>(Register::A), 1);
+    }
+}
+
+#[cfg(test)]
+mod word {
+    use crate::{
+        constant::{Byte, Word},
+        error::ExecuteError,
+        instruction::{BitTestAndReset, Instruction},
+        operand::Operand,
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn resets_set_bit() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Word>(Register::A, 0b1001);
+        let instruction =
+            Instruction::BitTestAndReset(BitTestAndReset::Word(Register::A, Operand::Immediate(3)));
+        let expected: Word = 0b0001;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Word>(Register::A), expected);
+        assert!(!i.flags.zero);
+
+        Ok(())
+    }
+
+    #[test]
+    fn clear_bit_is_unchanged() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Word>(Register::A, 0b0001);
+        let instruction =
+            Instruction::BitTestAndReset(BitTestAndReset::Word(Register::A, Operand::Immediate(3)));
+        let expected: Word = 0b0001;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Word>(Register::A), expected);
+        assert!(i.flags.zero);
+
+        Ok(())
+    }
+
+    #[test]
+    fn index_from_register() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Word>(Register::A, 1 << 63);
+        i.registers.set::<Byte>(Register::B, 63);
+        let instruction = Instruction::BitTestAndReset(BitTestAndReset::Word(
+            Register::A,
+            Operand::Register(Register::B),
+        ));
+
+        i.execute(instruction)?;
+
+        assert!(!i.flags.zero);
+        assert!(i.flags.sign);
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_bit_index() {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Word>(Register::A, 1);
+        let instruction = Instruction::BitTestAndReset(BitTestAndReset::Word(
+            Register::A,
+            Operand::Immediate(64),
+        ));
+        let expected = Err(ExecuteError::InvalidBitIndex(64, 64));
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+        assert_eq!(i.registers.get::<Word>(Register::A), 1);
+    }
+}
@@ -0,0 +1,141 @@
+use crate::{
+    constant::{Byte, Half, Quarter, Word},
+    instruction::ByteSwap,
+    register::Register,
+    registers::RegisterOperations,
+    utils::BitWise,
+    Interpreter,
+};
+
+impl Interpreter {
+    pub(super) fn byte_swap(&mut self, instruction: ByteSwap) {
+        match instruction {
+            ByteSwap::Byte(r) => self.byte_swap_value::<Byte>(r),
+            ByteSwap::Quarter(r) => self.byte_swap_value::<Quarter>(r),
+            ByteSwap::Half(r) => self.byte_swap_value::<Half>(r),
+            ByteSwap::Word(r) => self.byte_swap_value::<Word>(r),
+        }
+    }
+
+    fn byte_swap_value<T>(&mut self, register: Register)
+    where
+        T: BitWise,
+    {
+        let value = self.registers.get::<T>(register);
+        let result = value.bit_swap_bytes();
+        self.flags.set(result, false);
+        self.registers.set(register, result);
+    }
+}
+
+#[cfg(test)]
+mod byte {
+    use crate::{
+        constant::Byte,
+        error::ExecuteError,
+        instruction::{ByteSwap, Instruction},
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn single_byte_is_unchanged() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Byte>(Register::A, 0xAB);
+        let instruction = Instruction::ByteSwap(ByteSwap::Byte(Register::A));
+        let expected: Byte = 0xAB;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Byte>(Register::A), expected);
+        assert!(!i.flags.zero);
+        assert!(!i.flags.overflow);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod quarter {
+    use crate::{
+        constant::Quarter,
+        error::ExecuteError,
+        instruction::{ByteSwap, Instruction},
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn reverses_byte_order() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Quarter>(Register::A, 0x1234);
+        let instruction = Instruction::ByteSwap(ByteSwap::Quarter(Register::A));
+        let expected: Quarter = 0x3412;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Quarter>(Register::A), expected);
+        assert!(!i.flags.zero);
+        assert!(!i.flags.overflow);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod half {
+    use crate::{
+        constant::Half,
+        error::ExecuteError,
+        instruction::{ByteSwap, Instruction},
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn reverses_byte_order() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Half>(Register::A, 0x1234_5678);
+        let instruction = Instruction::ByteSwap(ByteSwap::Half(Register::A));
+        let expected: Half = 0x7856_3412;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Half>(Register::A), expected);
+        assert!(!i.flags.zero);
+        assert!(!i.flags.overflow);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod word {
+    use crate::{
+        constant::Word,
+        error::ExecuteError,
+        instruction::{ByteSwap, Instruction},
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn reverses_byte_order() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Word>(Register::A, 0x0102_0304_0506_0708);
+        let instruction = Instruction::ByteSwap(ByteSwap::Word(Register::A));
+        let expected: Word = 0x0807_0605_0403_0201;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Word>(Register::A), expected);
+        assert!(!i.flags.zero);
+        assert!(!i.flags.overflow);
+
+        Ok(())
+    }
+}
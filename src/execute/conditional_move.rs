@@ -0,0 +1,103 @@
+use crate::{
+    instruction::{Jump, Set},
+    Interpreter,
+};
+
+impl Interpreter {
+    pub(super) fn conditional_move(&mut self, condition: Jump, instruction: Set) {
+        if self.condition(&condition) {
+            self.set(instruction);
+        }
+    }
+}
+
+#[cfg(test)]
+mod byte {
+    use crate::{
+        constant::Byte,
+        error::ExecuteError,
+        instruction::{Instruction, Jump, Set},
+        operand::Operand,
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn condition_met() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::ConditionalMove(
+            Jump::IfZero,
+            Set::Byte(Register::A, Operand::Register(Register::B)),
+        );
+        i.flags.zero = true;
+        i.registers.set(Register::B, Byte::MAX);
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Byte>(Register::A), Byte::MAX);
+        assert_eq!(i.program_counter, 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn condition_not_met() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::ConditionalMove(
+            Jump::IfZero,
+            Set::Byte(Register::A, Operand::Immediate(Byte::MAX)),
+        );
+        i.registers.set(Register::A, 5);
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Byte>(Register::A), 5);
+        assert_eq!(i.program_counter, 2);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod word {
+    use crate::{
+        constant::Word,
+        error::ExecuteError,
+        instruction::{Instruction, Jump, Set},
+        operand::Operand,
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn condition_met() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::ConditionalMove(
+            Jump::IfGreater,
+            Set::Word(Register::A, Operand::Immediate(Word::MAX)),
+        );
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Word>(Register::A), Word::MAX);
+
+        Ok(())
+    }
+
+    #[test]
+    fn condition_not_met() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::ConditionalMove(
+            Jump::IfLesser,
+            Set::Word(Register::A, Operand::Immediate(Word::MAX)),
+        );
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Word>(Register::A), 0);
+
+        Ok(())
+    }
+}
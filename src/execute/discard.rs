@@ -0,0 +1,173 @@
+use crate::{
+    constant::{Byte, Half, Quarter, Word},
+    error::ExecuteError,
+    instruction::Discard,
+    utils::FromBytes,
+    Interpreter,
+};
+
+impl Interpreter {
+    pub(super) fn discard(&mut self, instruction: Discard) -> Result<(), ExecuteError> {
+        match instruction {
+            Discard::Byte => self.discard_value::<Byte>()?,
+            Discard::Quarter => self.discard_value::<Quarter>()?,
+            Discard::Half => self.discard_value::<Half>()?,
+            Discard::Word => self.discard_value::<Word>()?,
+        }
+
+        Ok(())
+    }
+
+    fn discard_value<T>(&mut self) -> Result<(), ExecuteError>
+    where
+        T: FromBytes,
+    {
+        self.stack.pop::<T>()?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod byte {
+    use crate::{
+        constant::Byte,
+        error::ExecuteError,
+        instruction::{Discard, Instruction},
+        Interpreter,
+    };
+
+    #[test]
+    fn stack_underflow() {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::Discard(Discard::Byte);
+        let expected = Err(ExecuteError::StackUnderflow);
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn discards_top_value() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::Discard(Discard::Byte);
+        i.stack.push(Byte::MAX)?;
+        i.stack.push(Byte::MAX)?;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.stack.pop::<Byte>()?, Byte::MAX);
+        assert_eq!(i.stack.sp(), 0);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod quarter {
+    use crate::{
+        constant::{Byte, Quarter},
+        error::ExecuteError,
+        instruction::{Discard, Instruction},
+        Interpreter,
+    };
+
+    #[test]
+    fn stack_underflow() {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::Discard(Discard::Quarter);
+        let expected = Err(ExecuteError::StackUnderflow);
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn discards_top_value() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::Discard(Discard::Quarter);
+        i.stack.push(Byte::MAX)?;
+        i.stack.push(Quarter::MAX)?;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.stack.pop::<Byte>()?, Byte::MAX);
+        assert_eq!(i.stack.sp(), 0);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod half {
+    use crate::{
+        constant::{Byte, Half},
+        error::ExecuteError,
+        instruction::{Discard, Instruction},
+        Interpreter,
+    };
+
+    #[test]
+    fn stack_underflow() {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::Discard(Discard::Half);
+        let expected = Err(ExecuteError::StackUnderflow);
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn discards_top_value() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::Discard(Discard::Half);
+        i.stack.push(Byte::MAX)?;
+        i.stack.push(Half::MAX)?;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.stack.pop::<Byte>()?, Byte::MAX);
+        assert_eq!(i.stack.sp(), 0);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod word {
+    use crate::{
+        constant::{Byte, Word},
+        error::ExecuteError,
+        instruction::{Discard, Instruction},
+        Interpreter,
+    };
+
+    #[test]
+    fn stack_underflow() {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::Discard(Discard::Word);
+        let expected = Err(ExecuteError::StackUnderflow);
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn discards_top_value() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::Discard(Discard::Word);
+        i.stack.push(Byte::MAX)?;
+        i.stack.push(Word::MAX)?;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.stack.pop::<Byte>()?, Byte::MAX);
+        assert_eq!(i.stack.sp(), 0);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,324 @@
+use crate::{
+    error::ExecuteError, instruction::DivisionRemainder, operand::Operand, register::Register,
+    registers::RegisterOperations, utils::Arithmetic, Interpreter,
+};
+
+impl Interpreter {
+    pub(super) fn divrem(&mut self, instruction: DivisionRemainder) -> Result<(), ExecuteError> {
+        match instruction {
+            DivisionRemainder::Byte(q, r, o) => self.divrem_value(q, r, o),
+            DivisionRemainder::Quarter(q, r, o) => self.divrem_value(q, r, o),
+            DivisionRemainder::Half(q, r, o) => self.divrem_value(q, r, o),
+            DivisionRemainder::Word(q, r, o) => self.divrem_value(q, r, o),
+        }
+    }
+
+    fn divrem_value<T>(
+        &mut self,
+        quotient: Register,
+        remainder: Register,
+        operand: Operand<T>,
+    ) -> Result<(), ExecuteError>
+    where
+        T: Arithmetic,
+    {
+        let a = self.registers.get::<T>(quotient);
+        let b = self.get_operand_value(operand);
+
+        if b.is_zero() {
+            return Err(ExecuteError::DivideByZero);
+        }
+
+        let (quotient_result, overflow) = a.overflow_div(b);
+        let (remainder_result, _) = a.overflow_rem(b);
+        self.flags.set(quotient_result, overflow);
+        self.registers.set(quotient, quotient_result);
+        self.registers.set(remainder, remainder_result);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod byte {
+    use crate::{
+        constant::Byte,
+        error::ExecuteError,
+        instruction::{DivisionRemainder, Instruction},
+        operand::Operand,
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn quotient_and_remainder() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Byte>(Register::A, 47);
+        let instruction = Instruction::DivisionRemainder(DivisionRemainder::Byte(
+            Register::A,
+            Register::B,
+            Operand::Immediate(10),
+        ));
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Byte>(Register::A), 4);
+        assert_eq!(i.registers.get::<Byte>(Register::B), 7);
+        assert!(!i.flags.zero);
+        assert!(!i.flags.overflow);
+
+        Ok(())
+    }
+
+    #[test]
+    fn divisor_from_register() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Byte>(Register::A, 3);
+        i.registers.set(Register::C, Byte::MAX);
+        let instruction = Instruction::DivisionRemainder(DivisionRemainder::Byte(
+            Register::A,
+            Register::B,
+            Operand::Register(Register::C),
+        ));
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Byte>(Register::A), 0);
+        assert_eq!(i.registers.get::<Byte>(Register::B), 3);
+        assert!(i.flags.zero);
+
+        Ok(())
+    }
+
+    #[test]
+    fn divide_by_zero_error() {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Byte>(Register::A, 47);
+        let instruction = Instruction::DivisionRemainder(DivisionRemainder::Byte(
+            Register::A,
+            Register::B,
+            Operand::Immediate(0),
+        ));
+        let expected = Err(ExecuteError::DivideByZero);
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+        assert_eq!(i.registers.get::<Byte>(Register::A), 47);
+        assert_eq!(i.registers.get::<Byte>(Register::B), 0);
+    }
+}
+
+#[cfg(test)]
+mod quarter {
+    use crate::{
+        constant::Quarter,
+        error::ExecuteError,
+        instruction::{DivisionRemainder, Instruction},
+        operand::Operand,
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn quotient_and_remainder() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Quarter>(Register::A, 47);
+        let instruction = Instruction::DivisionRemainder(DivisionRemainder::Quarter(
+            Register::A,
+            Register::B,
+            Operand::Immediate(10),
+        ));
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Quarter>(Register::A), 4);
+        assert_eq!(i.registers.get::<Quarter>(Register::B), 7);
+        assert!(!i.flags.zero);
+        assert!(!i.flags.overflow);
+
+        Ok(())
+    }
+
+    #[test]
+    fn divisor_from_register() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Quarter>(Register::A, 3);
+        i.registers.set(Register::C, Quarter::MAX);
+        let instruction = Instruction::DivisionRemainder(DivisionRemainder::Quarter(
+            Register::A,
+            Register::B,
+            Operand::Register(Register::C),
+        ));
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Quarter>(Register::A), 0);
+        assert_eq!(i.registers.get::<Quarter>(Register::B), 3);
+        assert!(i.flags.zero);
+
+        Ok(())
+    }
+
+    #[test]
+    fn divide_by_zero_error() {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Quarter>(Register::A, 47);
+        let instruction = Instruction::DivisionRemainder(DivisionRemainder::Quarter(
+            Register::A,
+            Register::B,
+            Operand::Immediate(0),
+        ));
+        let expected = Err(ExecuteError::DivideByZero);
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+        assert_eq!(i.registers.get::<Quarter>(Register::A), 47);
+        assert_eq!(i.registers.get::<Quarter>(Register::B), 0);
+    }
+}
+
+#[cfg(test)]
+mod half {
+    use crate::{
+        constant::Half,
+        error::ExecuteError,
+        instruction::{DivisionRemainder, Instruction},
+        operand::Operand,
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn quotient_and_remainder() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Half>(Register::A, 47);
+        let instruction = Instruction::DivisionRemainder(DivisionRemainder::Half(
+            Register::A,
+            Register::B,
+            Operand::Immediate(10),
+        ));
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Half>(Register::A), 4);
+        assert_eq!(i.registers.get::<Half>(Register::B), 7);
+        assert!(!i.flags.zero);
+        assert!(!i.flags.overflow);
+
+        Ok(())
+    }
+
+    #[test]
+    fn divisor_from_register() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Half>(Register::A, 3);
+        i.registers.set(Register::C, Half::MAX);
+        let instruction = Instruction::DivisionRemainder(DivisionRemainder::Half(
+            Register::A,
+            Register::B,
+            Operand::Register(Register::C),
+        ));
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Half>(Register::A), 0);
+        assert_eq!(i.registers.get::<Half>(Register::B), 3);
+        assert!(i.flags.zero);
+
+        Ok(())
+    }
+
+    #[test]
+    fn divide_by_zero_error() {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Half>(Register::A, 47);
+        let instruction = Instruction::DivisionRemainder(DivisionRemainder::Half(
+            Register::A,
+            Register::B,
+            Operand::Immediate(0),
+        ));
+        let expected = Err(ExecuteError::DivideByZero);
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+        assert_eq!(i.registers.get::<Half>(Register::A), 47);
+        assert_eq!(i.registers.get::<Half>(Register::B), 0);
+    }
+}
+
+#[cfg(test)]
+mod word {
+    use crate::{
+        constant::Word,
+        error::ExecuteError,
+        instruction::{DivisionRemainder, Instruction},
+        operand::Operand,
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn quotient_and_remainder() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Word>(Register::A, 47);
+        let instruction = Instruction::DivisionRemainder(DivisionRemainder::Word(
+            Register::A,
+            Register::B,
+            Operand::Immediate(10),
+        ));
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Word>(Register::A), 4);
+        assert_eq!(i.registers.get::<Word>(Register::B), 7);
+        assert!(!i.flags.zero);
+        assert!(!i.flags.overflow);
+
+        Ok(())
+    }
+
+    #[test]
+    fn divisor_from_register() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Word>(Register::A, 3);
+        i.registers.set(Register::C, Word::MAX);
+        let instruction = Instruction::DivisionRemainder(DivisionRemainder::Word(
+            Register::A,
+            Register::B,
+            Operand::Register(Register::C),
+        ));
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Word>(Register::A), 0);
+        assert_eq!(i.registers.get::<Word>(Register::B), 3);
+        assert!(i.flags.zero);
+
+        Ok(())
+    }
+
+    #[test]
+    fn divide_by_zero_error() {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Word>(Register::A, 47);
+        let instruction = Instruction::DivisionRemainder(DivisionRemainder::Word(
+            Register::A,
+            Register::B,
+            Operand::Immediate(0),
+        ));
+        let expected = Err(ExecuteError::DivideByZero);
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+        assert_eq!(i.registers.get::<Word>(Register::A), 47);
+        assert_eq!(i.registers.get::<Word>(Register::B), 0);
+    }
+}
@@ -0,0 +1,230 @@
+use crate::{
+    constant::{Byte, Half, Quarter, Word},
+    error::ExecuteError,
+    instruction::Duplicate,
+    utils::{FromBytes, ToBytes},
+    Interpreter,
+};
+
+impl Interpreter {
+    pub(super) fn duplicate(&mut self, instruction: Duplicate) -> Result<(), ExecuteError> {
+        match instruction {
+            Duplicate::Byte => self.duplicate_value::<Byte>()?,
+            Duplicate::Quarter => self.duplicate_value::<Quarter>()?,
+            Duplicate::Half => self.duplicate_value::<Half>()?,
+            Duplicate::Word => self.duplicate_value::<Word>()?,
+        }
+
+        Ok(())
+    }
+
+    fn duplicate_value<T>(&mut self) -> Result<(), ExecuteError>
+    where
+        T: FromBytes + ToBytes,
+    {
+        let value = self.stack.load::<T>(0)?;
+        self.stack.push(value)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod byte {
+    use crate::{
+        constant::{Byte, Word, TEST_STACK_SIZE},
+        error::ExecuteError,
+        instruction::{Duplicate, Instruction},
+        Interpreter,
+    };
+
+    #[test]
+    fn stack_underflow() {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::Duplicate(Duplicate::Byte);
+        let expected = Err(ExecuteError::StackUnderflow);
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn stack_overflow() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::Duplicate(Duplicate::Byte);
+        i.stack.set_sp(TEST_STACK_SIZE as Word)?;
+        let expected = Err(ExecuteError::StackOverflow);
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn duplicates_top_value() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::Duplicate(Duplicate::Byte);
+        i.stack.push(Byte::MAX)?;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.stack.pop::<Byte>()?, Byte::MAX);
+        assert_eq!(i.stack.pop::<Byte>()?, Byte::MAX);
+        assert_eq!(i.stack.sp(), 0);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod quarter {
+    use crate::{
+        constant::{Quarter, Word, TEST_STACK_SIZE},
+        error::ExecuteError,
+        instruction::{Duplicate, Instruction},
+        Interpreter,
+    };
+
+    #[test]
+    fn stack_underflow() {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::Duplicate(Duplicate::Quarter);
+        let expected = Err(ExecuteError::StackUnderflow);
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn stack_overflow() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::Duplicate(Duplicate::Quarter);
+        i.stack.set_sp(TEST_STACK_SIZE as Word)?;
+        let expected = Err(ExecuteError::StackOverflow);
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn duplicates_top_value() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::Duplicate(Duplicate::Quarter);
+        i.stack.push(Quarter::MAX)?;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.stack.pop::<Quarter>()?, Quarter::MAX);
+        assert_eq!(i.stack.pop::<Quarter>()?, Quarter::MAX);
+        assert_eq!(i.stack.sp(), 0);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod half {
+    use crate::{
+        constant::{Half, Word, TEST_STACK_SIZE},
+        error::ExecuteError,
+        instruction::{Duplicate, Instruction},
+        Interpreter,
+    };
+
+    #[test]
+    fn stack_underflow() {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::Duplicate(Duplicate::Half);
+        let expected = Err(ExecuteError::StackUnderflow);
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn stack_overflow() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::Duplicate(Duplicate::Half);
+        i.stack.set_sp(TEST_STACK_SIZE as Word)?;
+        let expected = Err(ExecuteError::StackOverflow);
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn duplicates_top_value() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::Duplicate(Duplicate::Half);
+        i.stack.push(Half::MAX)?;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.stack.pop::<Half>()?, Half::MAX);
+        assert_eq!(i.stack.pop::<Half>()?, Half::MAX);
+        assert_eq!(i.stack.sp(), 0);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod word {
+    use crate::{
+        constant::{Word, TEST_STACK_SIZE},
+        error::ExecuteError,
+        instruction::{Duplicate, Instruction},
+        Interpreter,
+    };
+
+    #[test]
+    fn stack_underflow() {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::Duplicate(Duplicate::Word);
+        let expected = Err(ExecuteError::StackUnderflow);
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn stack_overflow() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::Duplicate(Duplicate::Word);
+        i.stack.set_sp(TEST_STACK_SIZE as Word)?;
+        let expected = Err(ExecuteError::StackOverflow);
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn duplicates_top_value() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::Duplicate(Duplicate::Word);
+        i.stack.push(Word::MAX)?;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.stack.pop::<Word>()?, Word::MAX);
+        assert_eq!(i.stack.pop::<Word>()?, Word::MAX);
+        assert_eq!(i.stack.sp(), 0);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,158 @@
+use crate::{error::ExecuteError, host::HostContext, Interpreter};
+
+impl Interpreter {
+    pub(super) fn host_call(&mut self, name: &str) -> Result<(), ExecuteError> {
+        let function = self
+            .host_functions
+            .get_mut(name)
+            .ok_or(ExecuteError::UnknownHostFunction(name.to_string()))?;
+
+        let mut context = HostContext::new(
+            &mut self.registers,
+            &mut self.flags,
+            &mut self.stack,
+            self.config.output.as_mut(),
+        );
+
+        function(&mut context)
+    }
+}
+
+#[cfg(test)]
+mod sys {
+    use crate::{
+        constant::{Byte, Quarter, Word},
+        error::ExecuteError,
+        flags::Flags,
+        instruction::Instruction,
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn unknown_host_function() {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::HostCall("clock".to_string());
+        let expected = Err(ExecuteError::UnknownHostFunction("clock".to_string()));
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+        assert_eq!(i.program_counter, 1);
+    }
+
+    #[test]
+    fn modifies_registers_and_stack() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.register_host_function("double", |context| {
+            let value = context.register(Register::A);
+            context.set_register(Register::A, value * 2);
+            context.push(value)
+        });
+        i.registers.set(Register::A, 21);
+        let instruction = Instruction::HostCall("double".to_string());
+
+        i.execute(instruction)?;
+        let pushed: Word = i.stack.pop()?;
+
+        assert_eq!(i.registers.get::<Word>(Register::A), 42);
+        assert_eq!(pushed, 21);
+        assert_eq!(i.program_counter, 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn pushes_and_pops_values_of_any_size() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.register_host_function("narrow", |context| {
+            let value: Quarter = context.pop()?;
+            context.push(value as Byte)
+        });
+        i.stack.push::<Quarter>(0x1234)?;
+        let instruction = Instruction::HostCall("narrow".to_string());
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.stack.contents(), [0x34]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn reads_and_sets_flags() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.register_host_function("invert_zero", |context| {
+            let zero = context.flags().zero;
+            context.set_flags(Flags {
+                zero: !zero,
+                ..Flags::default()
+            });
+            Ok(())
+        });
+        i.flags.sign = true;
+        let instruction = Instruction::HostCall("invert_zero".to_string());
+
+        i.execute(instruction)?;
+
+        assert_eq!(
+            i.flags,
+            Flags {
+                sign: false,
+                overflow: false,
+                zero: true,
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn writes_to_output() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.register_host_function("hello", |context| {
+            write!(context.output(), "hello").map_err(|err| ExecuteError::IO(err.to_string()))
+        });
+        let instruction = Instruction::HostCall("hello".to_string());
+
+        i.execute(instruction)?;
+        let actual = i.config.output.get_buffer().unwrap();
+
+        assert_eq!(actual, "hello");
+
+        Ok(())
+    }
+
+    #[test]
+    fn error_from_host_function() {
+        let mut i = Interpreter::new_test();
+        i.register_host_function("fail", |_| {
+            Err(ExecuteError::HostFunction("file not found".to_string()))
+        });
+        let instruction = Instruction::HostCall("fail".to_string());
+        let expected = Err(ExecuteError::HostFunction("file not found".to_string()));
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn keeps_state_between_calls() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let mut counter = 0;
+        i.register_host_function("count", move |context| {
+            counter += 1;
+            context.set_register(Register::B, counter);
+            Ok(())
+        });
+
+        i.execute(Instruction::HostCall("count".to_string()))?;
+        i.execute(Instruction::HostCall("count".to_string()))?;
+
+        assert_eq!(i.registers.get::<Word>(Register::B), 2);
+
+        Ok(())
+    }
+}
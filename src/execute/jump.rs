@@ -3,6 +3,8 @@ use crate::{
     error::ExecuteError,
     instruction::{Jump, Relative},
     operand::Operand,
+    register::Register,
+    registers::RegisterOperations,
     Interpreter,
 };
 
@@ -14,7 +16,20 @@ impl Interpreter {
         operand: Operand<Word>,
         relative: Option<Relative>,
     ) -> Result<bool, ExecuteError> {
-        let jump_condition = match &instruction {
+        let jump_condition = self.condition(&instruction);
+
+        if jump_condition {
+            self.program_counter = self.destination(operand, relative)?;
+        }
+
+        Ok(jump_condition)
+    }
+
+    /// Returns true if the condition of the jump variant is met by the current flags.
+    ///
+    /// The conditions are shared by all conditional instructions, not just jumps.
+    pub(crate) fn condition(&self, condition: &Jump) -> bool {
+        match condition {
             Jump::Unconditional => true,
             Jump::IfZero => self.flags.zero,
             Jump::IfNotZero => !self.flags.zero,
@@ -26,25 +41,55 @@ impl Interpreter {
             Jump::IfLesser => self.flags.overflow && !self.flags.zero,
             Jump::IfGreaterOrEqual => !self.flags.overflow || self.flags.zero,
             Jump::IfLesserOrEqual => self.flags.overflow ^ self.flags.zero,
-        };
+        }
+    }
 
-        if jump_condition {
-            let destination = self.get_operand_value(operand);
-
-            self.program_counter = match relative {
-                Some(Relative::Positive) => self
-                    .program_counter
-                    .checked_add(destination)
-                    .ok_or(ExecuteError::ProgramCounterOverflow)?,
-                Some(Relative::Negative) => self
-                    .program_counter
-                    .checked_sub(destination)
-                    .ok_or(ExecuteError::ProgramCounterUnderflow)?,
-                None => destination,
-            }
+    /// Jumps to the entry of a jump table indicated by the index in the register.
+    ///
+    /// The destination is computed as the base of the table plus the index.
+    pub(super) fn jump_table(
+        &mut self,
+        register: Register,
+        base: Operand<Word>,
+        relative: Option<Relative>,
+        count: Operand<Word>,
+    ) -> Result<(), ExecuteError> {
+        let index = self.registers.get::<Word>(register);
+        let count = self.get_operand_value(count);
+
+        if index >= count {
+            return Err(ExecuteError::JumpTableIndexOutOfBounds(index, count));
         }
 
-        Ok(jump_condition)
+        self.program_counter = self
+            .destination(base, relative)?
+            .checked_add(index)
+            .ok_or(ExecuteError::ProgramCounterOverflow)?;
+
+        Ok(())
+    }
+
+    /// Computes the destination of a jump, which may be relative to the program counter.
+    fn destination(
+        &self,
+        operand: Operand<Word>,
+        relative: Option<Relative>,
+    ) -> Result<Word, ExecuteError> {
+        let destination = self.get_operand_value(operand);
+
+        let destination = match relative {
+            Some(Relative::Positive) => self
+                .program_counter
+                .checked_add(destination)
+                .ok_or(ExecuteError::ProgramCounterOverflow)?,
+            Some(Relative::Negative) => self
+                .program_counter
+                .checked_sub(destination)
+                .ok_or(ExecuteError::ProgramCounterUnderflow)?,
+            None => destination,
+        };
+
+        Ok(destination)
     }
 }
 
@@ -1962,3 +2007,127 @@ mod if_lesser_or_equal {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod table {
+    use crate::{
+        constant::Word,
+        error::ExecuteError,
+        instruction::{Instruction, Relative},
+        operand::Operand,
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn index_out_of_bounds_error() {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::JumpTable(
+            Register::A,
+            Operand::Immediate(10),
+            None,
+            Operand::Immediate(3),
+        );
+        i.registers.set(Register::A, 3);
+        let expected = Err(ExecuteError::JumpTableIndexOutOfBounds(3, 3));
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+        assert_eq!(i.program_counter, 1);
+    }
+
+    #[test]
+    fn program_counter_overflow_error() {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::JumpTable(
+            Register::A,
+            Operand::Immediate(Word::MAX),
+            None,
+            Operand::Immediate(3),
+        );
+        i.registers.set(Register::A, 1);
+        let expected = Err(ExecuteError::ProgramCounterOverflow);
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn program_counter_underflow_error() {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::JumpTable(
+            Register::A,
+            Operand::Immediate(2),
+            Some(Relative::Negative),
+            Operand::Immediate(3),
+        );
+        let expected = Err(ExecuteError::ProgramCounterUnderflow);
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn absolute_base() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::JumpTable(
+            Register::A,
+            Operand::Immediate(10),
+            None,
+            Operand::Register(Register::B),
+        );
+        i.registers.set(Register::A, 2);
+        i.registers.set(Register::B, 3);
+        let expected = 12;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.program_counter, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn relative_positive_base() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::JumpTable(
+            Register::A,
+            Operand::Immediate(1),
+            Some(Relative::Positive),
+            Operand::Immediate(3),
+        );
+        i.program_counter = 5;
+        i.registers.set(Register::A, 2);
+        let expected = 8;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.program_counter, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn relative_negative_base() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::JumpTable(
+            Register::A,
+            Operand::Register(Register::B),
+            Some(Relative::Negative),
+            Operand::Immediate(3),
+        );
+        i.program_counter = 5;
+        i.registers.set(Register::B, 4);
+        let expected = 1;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.program_counter, expected);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,189 @@
+use crate::{
+    constant::{Byte, Half, Quarter, Word},
+    instruction::LeadingZeros,
+    register::Register,
+    registers::RegisterOperations,
+    utils::BitWise,
+    Interpreter,
+};
+
+impl Interpreter {
+    pub(super) fn leading_zeros(&mut self, instruction: LeadingZeros) {
+        match instruction {
+            LeadingZeros::Byte(r) => self.leading_zeros_value::<Byte>(r),
+            LeadingZeros::Quarter(r) => self.leading_zeros_value::<Quarter>(r),
+            LeadingZeros::Half(r) => self.leading_zeros_value::<Half>(r),
+            LeadingZeros::Word(r) => self.leading_zeros_value::<Word>(r),
+        }
+    }
+
+    fn leading_zeros_value<T>(&mut self, register: Register)
+    where
+        T: BitWise,
+    {
+        let value = self.registers.get::<T>(register);
+        let result = value.bit_leading_zeros();
+        self.flags.set(result, false);
+        self.registers.set(register, result);
+    }
+}
+
+#[cfg(test)]
+mod byte {
+    use crate::{
+        constant::Byte,
+        error::ExecuteError,
+        instruction::{Instruction, LeadingZeros},
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn counts_leading_zeros() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Byte>(Register::A, 1);
+        let instruction = Instruction::LeadingZeros(LeadingZeros::Byte(Register::A));
+        let expected: Byte = 7;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Byte>(Register::A), expected);
+        assert!(!i.flags.zero);
+        assert!(!i.flags.overflow);
+
+        Ok(())
+    }
+
+    #[test]
+    fn zero_is_all_leading_zeros() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Byte>(Register::A, 0);
+        let instruction = Instruction::LeadingZeros(LeadingZeros::Byte(Register::A));
+        let expected: Byte = 8;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Byte>(Register::A), expected);
+        assert!(!i.flags.zero);
+        assert!(!i.flags.overflow);
+
+        Ok(())
+    }
+
+    #[test]
+    fn highest_bit_set() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Byte>(Register::A, Byte::MAX);
+        let instruction = Instruction::LeadingZeros(LeadingZeros::Byte(Register::A));
+        let expected: Byte = 0;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Byte>(Register::A), expected);
+        assert!(i.flags.zero);
+        assert!(!i.flags.overflow);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod quarter {
+    use crate::{
+        constant::Quarter,
+        error::ExecuteError,
+        instruction::{Instruction, LeadingZeros},
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn counts_leading_zeros() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Quarter>(Register::A, 1);
+        let instruction = Instruction::LeadingZeros(LeadingZeros::Quarter(Register::A));
+        let expected: Quarter = 15;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Quarter>(Register::A), expected);
+        assert!(!i.flags.zero);
+        assert!(!i.flags.overflow);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod half {
+    use crate::{
+        constant::Half,
+        error::ExecuteError,
+        instruction::{Instruction, LeadingZeros},
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn counts_leading_zeros() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Half>(Register::A, 1);
+        let instruction = Instruction::LeadingZeros(LeadingZeros::Half(Register::A));
+        let expected: Half = 31;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Half>(Register::A), expected);
+        assert!(!i.flags.zero);
+        assert!(!i.flags.overflow);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod word {
+    use crate::{
+        constant::Word,
+        error::ExecuteError,
+        instruction::{Instruction, LeadingZeros},
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn counts_leading_zeros() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Word>(Register::A, 1);
+        let instruction = Instruction::LeadingZeros(LeadingZeros::Word(Register::A));
+        let expected: Word = 63;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Word>(Register::A), expected);
+        assert!(!i.flags.zero);
+        assert!(!i.flags.overflow);
+
+        Ok(())
+    }
+
+    #[test]
+    fn zero_is_all_leading_zeros() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Word>(Register::A, 0);
+        let instruction = Instruction::LeadingZeros(LeadingZeros::Word(Register::A));
+        let expected: Word = 64;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Word>(Register::A), expected);
+        assert!(!i.flags.zero);
+        assert!(!i.flags.overflow);
+
+        Ok(())
+    }
+}
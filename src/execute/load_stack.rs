@@ -0,0 +1,288 @@
+use crate::{
+    constant::{Byte, Half, Quarter, Word},
+    error::ExecuteError,
+    instruction::LoadStack,
+    operand::Operand,
+    register::Register,
+    registers::RegisterOperations,
+    utils::{FromBytes, ToWord},
+    Interpreter,
+};
+
+impl Interpreter {
+    pub(super) fn load_stack(&mut self, instruction: LoadStack) -> Result<(), ExecuteError> {
+        match instruction {
+            LoadStack::Byte(r, o) => self.load_stack_value::<Byte>(r, o)?,
+            LoadStack::Quarter(r, o) => self.load_stack_value::<Quarter>(r, o)?,
+            LoadStack::Half(r, o) => self.load_stack_value::<Half>(r, o)?,
+            LoadStack::Word(r, o) => self.load_stack_value::<Word>(r, o)?,
+        }
+
+        Ok(())
+    }
+
+    fn load_stack_value<T>(
+        &mut self,
+        register: Register,
+        offset: Operand<Word>,
+    ) -> Result<(), ExecuteError>
+    where
+        T: FromBytes + ToWord,
+    {
+        let offset = self.get_operand_value(offset);
+        let value = self.stack.load::<T>(offset)?;
+        self.registers.set(register, value);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod byte {
+    use crate::{
+        constant::Byte,
+        error::ExecuteError,
+        instruction::{Instruction, LoadStack},
+        operand::Operand,
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn stack_underflow() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction =
+            Instruction::LoadStack(LoadStack::Byte(Register::A, Operand::Immediate(1)));
+        i.stack.push(Byte::MAX)?;
+        let expected = Err(ExecuteError::StackUnderflow);
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn load_from_immediate_offset() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction =
+            Instruction::LoadStack(LoadStack::Byte(Register::A, Operand::Immediate(1)));
+        i.stack.push(Byte::MAX)?;
+        i.stack.push(Byte::MIN)?;
+        let expected = Byte::MAX;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Byte>(Register::A), expected);
+        assert_eq!(i.stack.sp(), size_of::<Byte>() + 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn load_from_register_offset() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction =
+            Instruction::LoadStack(LoadStack::Byte(Register::A, Operand::Register(Register::B)));
+        i.stack.push(Byte::MAX)?;
+        i.stack.push(Byte::MIN)?;
+        i.registers.set(Register::B, 1);
+        let expected = Byte::MAX;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Byte>(Register::A), expected);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod quarter {
+    use crate::{
+        constant::{Byte, Quarter},
+        error::ExecuteError,
+        instruction::{Instruction, LoadStack},
+        operand::Operand,
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn stack_underflow() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction =
+            Instruction::LoadStack(LoadStack::Quarter(Register::A, Operand::Immediate(1)));
+        i.stack.push(Quarter::MAX)?;
+        let expected = Err(ExecuteError::StackUnderflow);
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn load_from_immediate_offset() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction =
+            Instruction::LoadStack(LoadStack::Quarter(Register::A, Operand::Immediate(1)));
+        i.stack.push(Quarter::MAX)?;
+        i.stack.push(Byte::MIN)?;
+        let expected = Quarter::MAX;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Quarter>(Register::A), expected);
+        assert_eq!(i.stack.sp(), size_of::<Quarter>() + 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn load_from_register_offset() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::LoadStack(LoadStack::Quarter(
+            Register::A,
+            Operand::Register(Register::B),
+        ));
+        i.stack.push(Quarter::MAX)?;
+        i.stack.push(Byte::MIN)?;
+        i.registers.set(Register::B, 1);
+        let expected = Quarter::MAX;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Quarter>(Register::A), expected);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod half {
+    use crate::{
+        constant::{Byte, Half},
+        error::ExecuteError,
+        instruction::{Instruction, LoadStack},
+        operand::Operand,
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn stack_underflow() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction =
+            Instruction::LoadStack(LoadStack::Half(Register::A, Operand::Immediate(1)));
+        i.stack.push(Half::MAX)?;
+        let expected = Err(ExecuteError::StackUnderflow);
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn load_from_immediate_offset() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction =
+            Instruction::LoadStack(LoadStack::Half(Register::A, Operand::Immediate(1)));
+        i.stack.push(Half::MAX)?;
+        i.stack.push(Byte::MIN)?;
+        let expected = Half::MAX;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Half>(Register::A), expected);
+        assert_eq!(i.stack.sp(), size_of::<Half>() + 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn load_from_register_offset() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction =
+            Instruction::LoadStack(LoadStack::Half(Register::A, Operand::Register(Register::B)));
+        i.stack.push(Half::MAX)?;
+        i.stack.push(Byte::MIN)?;
+        i.registers.set(Register::B, 1);
+        let expected = Half::MAX;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Half>(Register::A), expected);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod word {
+    use crate::{
+        constant::{Byte, Word},
+        error::ExecuteError,
+        instruction::{Instruction, LoadStack},
+        operand::Operand,
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn stack_underflow() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction =
+            Instruction::LoadStack(LoadStack::Word(Register::A, Operand::Immediate(1)));
+        i.stack.push(Word::MAX)?;
+        let expected = Err(ExecuteError::StackUnderflow);
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn load_from_immediate_offset() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction =
+            Instruction::LoadStack(LoadStack::Word(Register::A, Operand::Immediate(1)));
+        i.stack.push(Word::MAX)?;
+        i.stack.push(Byte::MIN)?;
+        let expected = Word::MAX;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Word>(Register::A), expected);
+        assert_eq!(i.stack.sp(), size_of::<Word>() + 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn load_from_register_offset() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction =
+            Instruction::LoadStack(LoadStack::Word(Register::A, Operand::Register(Register::B)));
+        i.stack.push(Word::MAX)?;
+        i.stack.push(Byte::MIN)?;
+        i.registers.set(Register::B, 1);
+        let expected = Word::MAX;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Word>(Register::A), expected);
+
+        Ok(())
+    }
+}
@@ -1,24 +1,48 @@
 use crate::{
-    constant::Word, error::ExecuteError, instruction::Instruction, operand::Operand,
-    registers::RegisterOperations, utils::FromBytes, Interpreter,
+    call_stack::CallFrame, constant::Word, error::ExecuteError, instruction::Instruction,
+    operand::Operand, registers::RegisterOperations, utils::FromBytes, Interpreter,
 };
 
 mod addition;
 mod and;
+mod bit_extract;
+mod bit_insert;
+mod bit_test;
+mod bit_test_and_complement;
+mod bit_test_and_reset;
+mod bit_test_and_set;
+mod byte_swap;
 mod compare;
+mod conditional_move;
+mod discard;
 mod division;
+mod division_remainder;
+mod duplicate;
+mod host_call;
 mod jump;
+mod leading_zeros;
+mod load_stack;
 mod multiplication;
 mod not;
 mod or;
+mod peek;
 mod pop;
+mod pop_count;
 mod print_register;
 mod print_stack;
+mod print_value;
+mod program_counter;
 mod push;
 mod remainder;
 mod set;
+mod set_condition;
+mod stack_pointer;
+mod store_stack;
 mod subtraction;
+mod swap;
 mod test;
+mod trailing_zeros;
+mod widening_multiplication;
 mod xor;
 
 impl Interpreter {
@@ -33,32 +57,76 @@ impl Interpreter {
     }
 
     pub(super) fn execute(&mut self, instruction: Instruction) -> Result<(), ExecuteError> {
+        self.execute_transfer(instruction).map(|_| ())
+    }
+
+    /// Executes the instruction, and returns true if it transferred control to one of its destinations
+    /// rather than continuing on the following line.
+    pub(super) fn execute_transfer(
+        &mut self,
+        instruction: Instruction,
+    ) -> Result<bool, ExecuteError> {
         self.config.instructions_executed += 1;
 
+        let transfers_control = instruction.transfers_control();
         let mut increment_pc = instruction.increment();
 
         match instruction {
             Instruction::Addition(add_ins) => self.add(add_ins),
             Instruction::And(and_ins) => self.and(and_ins),
+            Instruction::BitExtract(bext_ins) => self.bit_extract(bext_ins)?,
+            Instruction::BitInsert(bins_ins) => self.bit_insert(bins_ins)?,
+            Instruction::BitTest(bt_ins) => self.bit_test(bt_ins)?,
+            Instruction::BitTestAndComplement(btc_ins) => self.bit_test_and_complement(btc_ins)?,
+            Instruction::BitTestAndReset(btr_ins) => self.bit_test_and_reset(btr_ins)?,
+            Instruction::BitTestAndSet(bts_ins) => self.bit_test_and_set(bts_ins)?,
+            Instruction::ByteSwap(bswap_ins) => self.byte_swap(bswap_ins),
             Instruction::Call(operand) => self.call(operand)?,
             Instruction::Compare(compare_ins) => self.compare(compare_ins),
+            Instruction::ConditionalMove(condition, set_ins) => {
+                self.conditional_move(condition, set_ins);
+            }
+            Instruction::Discard(discard_ins) => self.discard(discard_ins)?,
             Instruction::Division(div_ins) => self.div(div_ins)?,
+            Instruction::DivisionRemainder(divrem_ins) => self.divrem(divrem_ins)?,
+            Instruction::Duplicate(dup_ins) => self.duplicate(dup_ins)?,
+            Instruction::GetProgramCounter(register) => self.get_program_counter(register),
+            Instruction::GetStackPointer(register) => self.get_stack_pointer(register),
+            Instruction::HostCall(name) => self.host_call(&name)?,
             Instruction::Jump(jump_ins, operand, relative) => {
                 increment_pc = !self.jump(jump_ins, operand, relative)?;
             }
+            Instruction::JumpTable(register, base, relative, count) => {
+                self.jump_table(register, base, relative, count)?;
+            }
+            Instruction::LeadingZeros(clz_ins) => self.leading_zeros(clz_ins),
+            Instruction::LoadStack(load_ins) => self.load_stack(load_ins)?,
             Instruction::Multiplication(mul_ins) => self.mul(mul_ins),
             Instruction::Not(not_ins) => self.not(not_ins),
             Instruction::Or(or_ins) => self.or(or_ins),
+            Instruction::Peek(peek_ins) => self.peek(peek_ins)?,
             Instruction::Pop(pop_ins) => self.pop(pop_ins)?,
             Instruction::PrintRegister(print_reg_ins) => self.print_register(print_reg_ins)?,
+            Instruction::PrintNewline => self.print_separator('\n')?,
+            Instruction::PrintSpace => self.print_separator(' ')?,
             Instruction::PrintStack(print_stack_ins) => self.print_stack(print_stack_ins)?,
+            Instruction::PrintValue(print_value_ins) => self.print_value(print_value_ins)?,
+            Instruction::PopCount(popcnt_ins) => self.pop_count(popcnt_ins),
             Instruction::Push(push_ins) => self.push(push_ins)?,
             Instruction::Remainder(rem_ins) => self.rem(rem_ins)?,
             Instruction::Return => self.ret()?,
             Instruction::Set(set_ins) => self.set(set_ins),
+            Instruction::SetCondition(condition, register) => {
+                self.set_condition(condition, register);
+            }
+            Instruction::SetStackPointer(operand) => self.set_stack_pointer(operand)?,
             Instruction::Stop => self.stop()?,
+            Instruction::StoreStack(store_ins) => self.store_stack(store_ins)?,
             Instruction::Subtraction(sub_ins) => self.sub(sub_ins),
+            Instruction::Swap(swap_ins) => self.swap(swap_ins)?,
             Instruction::Test(test_ins) => self.test(test_ins),
+            Instruction::TrailingZeros(ctz_ins) => self.trailing_zeros(ctz_ins),
+            Instruction::WideningMultiplication(mulx_ins) => self.widening_mul(mulx_ins),
             Instruction::Xor(xor_ins) => self.xor(xor_ins),
         }
 
@@ -66,7 +134,7 @@ impl Interpreter {
             self.program_counter += 1;
         }
 
-        Ok(())
+        Ok(transfers_control && !increment_pc)
     }
 
     fn stop(&mut self) -> Result<(), ExecuteError> {
@@ -89,13 +157,33 @@ impl Interpreter {
         self.stack.push(return_address)?;
 
         let destination = self.get_operand_value(operand);
+        self.call_stack.push(CallFrame {
+            function: self.program.function(destination),
+            call_site: self.pc(),
+            entry: destination,
+            stack_pointer: self.stack.sp(),
+        });
         self.program_counter = destination;
 
         Ok(())
     }
 
+    /// Returns to the address on top of the stack, which must have been pushed by the innermost call.
+    ///
+    /// Returning with anything else on top of the stack means the stack has been corrupted.
     fn ret(&mut self) -> Result<(), ExecuteError> {
-        let destination = self.stack.pop::<Word>()?;
+        let destination = self.stack.load::<Word>(0)?;
+
+        let is_valid = self
+            .call_stack
+            .last()
+            .is_some_and(|frame| frame.return_address() == destination);
+        if !is_valid {
+            return Err(ExecuteError::InvalidReturnAddress(destination));
+        }
+
+        self.stack.pop::<Word>()?;
+        self.call_stack.pop();
         self.program_counter = destination;
 
         Ok(())
@@ -127,6 +215,7 @@ mod stop {
 #[cfg(test)]
 mod call {
     use crate::{
+        call_stack::CallFrame,
         constant::{Byte, Word, TEST_STACK_SIZE},
         error::ExecuteError,
         instruction::Instruction,
@@ -191,11 +280,38 @@ mod call {
 
         Ok(())
     }
+
+    #[test]
+    fn call_records_call_frame() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.stack
+            .push::<Byte>(0)
+            .expect("should be able to push value onto stack");
+        let instruction = Instruction::Call(Operand::Immediate(5));
+        let expected = [CallFrame {
+            function: None,
+            call_site: 1,
+            entry: 5,
+            stack_pointer: 9,
+        }];
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.call_stack, expected);
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod ret {
-    use crate::{constant::Word, error::ExecuteError, instruction::Instruction, Interpreter};
+    use crate::{
+        constant::Word,
+        error::{ExecuteError, InterpreterError},
+        instruction::Instruction,
+        operand::Operand,
+        Interpreter,
+    };
 
     #[test]
     fn stack_underflow() {
@@ -210,16 +326,91 @@ mod ret {
 
     #[test]
     fn valid_return() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.execute(Instruction::Call(Operand::Immediate(5)))?;
+        let instruction = Instruction::Return;
+        let expected = 2;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.program_counter, expected);
+        assert_eq!(i.stack.sp(), 0);
+        assert!(i.call_stack.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn function_removes_its_arguments() -> Result<(), InterpreterError> {
+        let mut i = Interpreter::new_test();
+        let program = [
+            "pshb 5",
+            "call clean_up",
+            "prrb rb",
+            "stop",
+            "fn clean_up:",
+            "popw rh",
+            "popb rb",
+            "pshw rh",
+            "ret",
+        ]
+        .join("\n");
+
+        i.run(&program)?;
+        let actual = i.config.output.get_buffer().unwrap();
+
+        assert_eq!(actual, "rb: 5\n");
+        assert_eq!(i.stack.sp(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn return_address_not_pushed_by_call() {
         let mut i = Interpreter::new_test();
         i.stack
             .push(Word::MAX)
             .expect("should be able to push value onto stack");
         let instruction = Instruction::Return;
-        let expected = Word::MAX;
+        let expected = Err(ExecuteError::InvalidReturnAddress(Word::MAX));
 
-        i.execute(instruction)?;
+        let actual = i.execute(instruction);
 
-        assert_eq!(i.program_counter, expected);
+        assert_eq!(actual, expected);
+        assert_eq!(i.stack.sp(), size_of::<Word>());
+    }
+
+    #[test]
+    fn value_left_on_stack_by_function() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.execute(Instruction::Call(Operand::Immediate(5)))?;
+        i.stack
+            .push::<Word>(7)
+            .expect("should be able to push value onto stack");
+        let instruction = Instruction::Return;
+        let expected = Err(ExecuteError::InvalidReturnAddress(7));
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+        assert_eq!(i.call_stack.len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn return_address_overwritten() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.execute(Instruction::Call(Operand::Immediate(5)))?;
+        i.stack
+            .store::<Word>(0, 9)
+            .expect("should be able to store value on stack");
+        let instruction = Instruction::Return;
+        let expected = Err(ExecuteError::InvalidReturnAddress(9));
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
 
         Ok(())
     }
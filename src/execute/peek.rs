@@ -0,0 +1,184 @@
+use crate::{
+    constant::{Byte, Half, Quarter, Word},
+    error::ExecuteError,
+    instruction::Peek,
+    register::Register,
+    registers::RegisterOperations,
+    utils::{FromBytes, ToWord},
+    Interpreter,
+};
+
+impl Interpreter {
+    pub(super) fn peek(&mut self, instruction: Peek) -> Result<(), ExecuteError> {
+        match instruction {
+            Peek::Byte(r) => self.peek_value::<Byte>(r)?,
+            Peek::Quarter(r) => self.peek_value::<Quarter>(r)?,
+            Peek::Half(r) => self.peek_value::<Half>(r)?,
+            Peek::Word(r) => self.peek_value::<Word>(r)?,
+        }
+
+        Ok(())
+    }
+
+    fn peek_value<T>(&mut self, register: Register) -> Result<(), ExecuteError>
+    where
+        T: FromBytes + ToWord,
+    {
+        let value = self.stack.load::<T>(0)?;
+        self.registers.set(register, value);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod byte {
+    use crate::{
+        constant::Byte,
+        error::ExecuteError,
+        instruction::{Instruction, Peek},
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn stack_underflow() {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::Peek(Peek::Byte(Register::A));
+        let expected = Err(ExecuteError::StackUnderflow);
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn peek_leaves_value_on_stack() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::Peek(Peek::Byte(Register::A));
+        i.stack.push(Byte::MAX)?;
+        let expected = Byte::MAX;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Byte>(Register::A), expected);
+        assert_eq!(i.stack.sp(), size_of::<Byte>());
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod quarter {
+    use crate::{
+        constant::Quarter,
+        error::ExecuteError,
+        instruction::{Instruction, Peek},
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn stack_underflow() {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::Peek(Peek::Quarter(Register::A));
+        let expected = Err(ExecuteError::StackUnderflow);
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn peek_leaves_value_on_stack() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::Peek(Peek::Quarter(Register::A));
+        i.stack.push(Quarter::MAX)?;
+        let expected = Quarter::MAX;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Quarter>(Register::A), expected);
+        assert_eq!(i.stack.sp(), size_of::<Quarter>());
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod half {
+    use crate::{
+        constant::Half,
+        error::ExecuteError,
+        instruction::{Instruction, Peek},
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn stack_underflow() {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::Peek(Peek::Half(Register::A));
+        let expected = Err(ExecuteError::StackUnderflow);
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn peek_leaves_value_on_stack() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::Peek(Peek::Half(Register::A));
+        i.stack.push(Half::MAX)?;
+        let expected = Half::MAX;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Half>(Register::A), expected);
+        assert_eq!(i.stack.sp(), size_of::<Half>());
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod word {
+    use crate::{
+        constant::Word,
+        error::ExecuteError,
+        instruction::{Instruction, Peek},
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn stack_underflow() {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::Peek(Peek::Word(Register::A));
+        let expected = Err(ExecuteError::StackUnderflow);
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn peek_leaves_value_on_stack() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::Peek(Peek::Word(Register::A));
+        i.stack.push(Word::MAX)?;
+        let expected = Word::MAX;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Word>(Register::A), expected);
+        assert_eq!(i.stack.sp(), size_of::<Word>());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,157 @@
+use crate::{
+    constant::{Byte, Half, Quarter, Word},
+    instruction::PopCount,
+    register::Register,
+    registers::RegisterOperations,
+    utils::BitWise,
+    Interpreter,
+};
+
+impl Interpreter {
+    pub(super) fn pop_count(&mut self, instruction: PopCount) {
+        match instruction {
+            PopCount::Byte(r) => self.pop_count_value::<Byte>(r),
+            PopCount::Quarter(r) => self.pop_count_value::<Quarter>(r),
+            PopCount::Half(r) => self.pop_count_value::<Half>(r),
+            PopCount::Word(r) => self.pop_count_value::<Word>(r),
+        }
+    }
+
+    fn pop_count_value<T>(&mut self, register: Register)
+    where
+        T: BitWise,
+    {
+        let value = self.registers.get::<T>(register);
+        let result = value.bit_count_ones();
+        self.flags.set(result, false);
+        self.registers.set(register, result);
+    }
+}
+
+#[cfg(test)]
+mod byte {
+    use crate::{
+        constant::Byte,
+        error::ExecuteError,
+        instruction::{Instruction, PopCount},
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn counts_set_bits() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Byte>(Register::A, 0b1011_0010);
+        let instruction = Instruction::PopCount(PopCount::Byte(Register::A));
+        let expected: Byte = 4;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Byte>(Register::A), expected);
+        assert!(!i.flags.zero);
+        assert!(!i.flags.overflow);
+
+        Ok(())
+    }
+
+    #[test]
+    fn zero_has_no_set_bits() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Byte>(Register::A, 0);
+        let instruction = Instruction::PopCount(PopCount::Byte(Register::A));
+        let expected: Byte = 0;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Byte>(Register::A), expected);
+        assert!(i.flags.zero);
+        assert!(!i.flags.overflow);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod quarter {
+    use crate::{
+        constant::Quarter,
+        error::ExecuteError,
+        instruction::{Instruction, PopCount},
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn counts_set_bits() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Quarter>(Register::A, Quarter::MAX);
+        let instruction = Instruction::PopCount(PopCount::Quarter(Register::A));
+        let expected: Quarter = 16;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Quarter>(Register::A), expected);
+        assert!(!i.flags.zero);
+        assert!(!i.flags.overflow);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod half {
+    use crate::{
+        constant::Half,
+        error::ExecuteError,
+        instruction::{Instruction, PopCount},
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn counts_set_bits() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Half>(Register::A, Half::MAX);
+        let instruction = Instruction::PopCount(PopCount::Half(Register::A));
+        let expected: Half = 32;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Half>(Register::A), expected);
+        assert!(!i.flags.zero);
+        assert!(!i.flags.overflow);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod word {
+    use crate::{
+        constant::Word,
+        error::ExecuteError,
+        instruction::{Instruction, PopCount},
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn counts_set_bits() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Word>(Register::A, Word::MAX);
+        let instruction = Instruction::PopCount(PopCount::Word(Register::A));
+        let expected: Word = 64;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Word>(Register::A), expected);
+        assert!(!i.flags.zero);
+        assert!(!i.flags.overflow);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,442 @@
+use crate::{
+    error::ExecuteError,
+    instruction::{PrintFormat, PrintValue},
+    operand::Operand,
+    utils::{FromBytes, SignExtend, ToWord},
+    Interpreter,
+};
+
+impl Interpreter {
+    pub(super) fn print_value(&mut self, instruction: PrintValue) -> Result<(), ExecuteError> {
+        match instruction {
+            PrintValue::Byte(o, f) => self.print_value_formatted(o, f),
+            PrintValue::Quarter(o, f) => self.print_value_formatted(o, f),
+            PrintValue::Half(o, f) => self.print_value_formatted(o, f),
+            PrintValue::Word(o, f) => self.print_value_formatted(o, f),
+        }
+    }
+
+    fn print_value_formatted<T>(
+        &mut self,
+        operand: Operand<T>,
+        format: PrintFormat,
+    ) -> Result<(), ExecuteError>
+    where
+        T: FromBytes + SignExtend + ToWord + Copy,
+    {
+        let value = self.get_operand_value(operand);
+        let word = value.to_word();
+
+        let result = match format {
+            PrintFormat::Decimal => write!(self.config.output, "{word}"),
+            PrintFormat::Signed => write!(self.config.output, "{}", value.sign_extend()),
+            PrintFormat::Hexadecimal => write!(self.config.output, "{word:x}"),
+            PrintFormat::Binary => write!(self.config.output, "{word:b}"),
+            PrintFormat::Octal => write!(self.config.output, "{word:o}"),
+            PrintFormat::Character => {
+                let character = u32::try_from(word)
+                    .ok()
+                    .and_then(char::from_u32)
+                    .ok_or(ExecuteError::InvalidCharacter(word))?;
+                write!(self.config.output, "{character}")
+            }
+        };
+
+        result.map_err(|err| ExecuteError::IO(err.to_string()))
+    }
+
+    pub(super) fn print_separator(&mut self, separator: char) -> Result<(), ExecuteError> {
+        write!(self.config.output, "{separator}").map_err(|err| ExecuteError::IO(err.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod byte {
+    use crate::{
+        constant::Byte,
+        error::ExecuteError,
+        instruction::{Instruction, PrintFormat, PrintValue},
+        operand::Operand,
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn decimal() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Byte>(Register::A, Byte::MAX);
+        let instruction = Instruction::PrintValue(PrintValue::Byte(
+            Operand::Register(Register::A),
+            PrintFormat::Decimal,
+        ));
+        let expected = "255";
+
+        i.execute(instruction)?;
+        let actual = i.config.output.get_buffer().unwrap();
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn signed() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Byte>(Register::A, Byte::MAX);
+        let instruction = Instruction::PrintValue(PrintValue::Byte(
+            Operand::Register(Register::A),
+            PrintFormat::Signed,
+        ));
+        let expected = "-1";
+
+        i.execute(instruction)?;
+        let actual = i.config.output.get_buffer().unwrap();
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn hexadecimal() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Byte>(Register::A, Byte::MAX);
+        let instruction = Instruction::PrintValue(PrintValue::Byte(
+            Operand::Register(Register::A),
+            PrintFormat::Hexadecimal,
+        ));
+        let expected = "ff";
+
+        i.execute(instruction)?;
+        let actual = i.config.output.get_buffer().unwrap();
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn binary() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Byte>(Register::A, 0b1010);
+        let instruction = Instruction::PrintValue(PrintValue::Byte(
+            Operand::Register(Register::A),
+            PrintFormat::Binary,
+        ));
+        let expected = "1010";
+
+        i.execute(instruction)?;
+        let actual = i.config.output.get_buffer().unwrap();
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn octal() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Byte>(Register::A, 8);
+        let instruction = Instruction::PrintValue(PrintValue::Byte(
+            Operand::Register(Register::A),
+            PrintFormat::Octal,
+        ));
+        let expected = "10";
+
+        i.execute(instruction)?;
+        let actual = i.config.output.get_buffer().unwrap();
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn character() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Byte>(Register::A, b'A');
+        let instruction = Instruction::PrintValue(PrintValue::Byte(
+            Operand::Register(Register::A),
+            PrintFormat::Character,
+        ));
+        let expected = "A";
+
+        i.execute(instruction)?;
+        let actual = i.config.output.get_buffer().unwrap();
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod quarter {
+    use crate::{
+        constant::Quarter,
+        error::ExecuteError,
+        instruction::{Instruction, PrintFormat, PrintValue},
+        operand::Operand,
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn signed() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Quarter>(Register::A, Quarter::MAX);
+        let instruction = Instruction::PrintValue(PrintValue::Quarter(
+            Operand::Register(Register::A),
+            PrintFormat::Signed,
+        ));
+        let expected = "-1";
+
+        i.execute(instruction)?;
+        let actual = i.config.output.get_buffer().unwrap();
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn signed_minimum() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Quarter>(Register::A, 0x8000);
+        let instruction = Instruction::PrintValue(PrintValue::Quarter(
+            Operand::Register(Register::A),
+            PrintFormat::Signed,
+        ));
+        let expected = "-32768";
+
+        i.execute(instruction)?;
+        let actual = i.config.output.get_buffer().unwrap();
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn hexadecimal() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Quarter>(Register::A, 0xBEEF);
+        let instruction = Instruction::PrintValue(PrintValue::Quarter(
+            Operand::Register(Register::A),
+            PrintFormat::Hexadecimal,
+        ));
+        let expected = "beef";
+
+        i.execute(instruction)?;
+        let actual = i.config.output.get_buffer().unwrap();
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn character() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Quarter>(Register::A, 0x00E9);
+        let instruction = Instruction::PrintValue(PrintValue::Quarter(
+            Operand::Register(Register::A),
+            PrintFormat::Character,
+        ));
+        let expected = "é";
+
+        i.execute(instruction)?;
+        let actual = i.config.output.get_buffer().unwrap();
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod half {
+    use crate::{
+        constant::Half,
+        error::ExecuteError,
+        instruction::{Instruction, PrintFormat, PrintValue},
+        operand::Operand,
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn decimal() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Half>(Register::A, Half::MAX);
+        let instruction = Instruction::PrintValue(PrintValue::Half(
+            Operand::Register(Register::A),
+            PrintFormat::Decimal,
+        ));
+        let expected = "4294967295";
+
+        i.execute(instruction)?;
+        let actual = i.config.output.get_buffer().unwrap();
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn signed() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Half>(Register::A, Half::MAX);
+        let instruction = Instruction::PrintValue(PrintValue::Half(
+            Operand::Register(Register::A),
+            PrintFormat::Signed,
+        ));
+        let expected = "-1";
+
+        i.execute(instruction)?;
+        let actual = i.config.output.get_buffer().unwrap();
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn character() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Half>(Register::A, 0x1F600);
+        let instruction = Instruction::PrintValue(PrintValue::Half(
+            Operand::Register(Register::A),
+            PrintFormat::Character,
+        ));
+        let expected = "😀";
+
+        i.execute(instruction)?;
+        let actual = i.config.output.get_buffer().unwrap();
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod word {
+    use crate::{
+        constant::Word,
+        error::ExecuteError,
+        instruction::{Instruction, PrintFormat, PrintValue},
+        operand::Operand,
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn decimal() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Word>(Register::A, Word::MAX);
+        let instruction = Instruction::PrintValue(PrintValue::Word(
+            Operand::Register(Register::A),
+            PrintFormat::Decimal,
+        ));
+        let expected = "18446744073709551615";
+
+        i.execute(instruction)?;
+        let actual = i.config.output.get_buffer().unwrap();
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn signed() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Word>(Register::A, Word::MAX);
+        let instruction = Instruction::PrintValue(PrintValue::Word(
+            Operand::Register(Register::A),
+            PrintFormat::Signed,
+        ));
+        let expected = "-1";
+
+        i.execute(instruction)?;
+        let actual = i.config.output.get_buffer().unwrap();
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn octal() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Word>(Register::A, Word::MAX);
+        let instruction = Instruction::PrintValue(PrintValue::Word(
+            Operand::Register(Register::A),
+            PrintFormat::Octal,
+        ));
+        let expected = "1777777777777777777777";
+
+        i.execute(instruction)?;
+        let actual = i.config.output.get_buffer().unwrap();
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_character() {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::PrintValue(PrintValue::Word(
+            Operand::Immediate(0xD800),
+            PrintFormat::Character,
+        ));
+        let expected = Err(ExecuteError::InvalidCharacter(0xD800));
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+    }
+}
+
+#[cfg(test)]
+mod separator {
+    use crate::{
+        error::ExecuteError,
+        instruction::{Instruction, PrintFormat, PrintValue},
+        operand::Operand,
+        Interpreter,
+    };
+
+    #[test]
+    fn values_separated_by_space_and_newline() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instructions = [
+            Instruction::PrintValue(PrintValue::Byte(
+                Operand::Immediate(1),
+                PrintFormat::Decimal,
+            )),
+            Instruction::PrintSpace,
+            Instruction::PrintValue(PrintValue::Byte(
+                Operand::Immediate(2),
+                PrintFormat::Decimal,
+            )),
+            Instruction::PrintNewline,
+        ];
+        let expected = "1 2\n";
+
+        for instruction in instructions {
+            i.execute(instruction)?;
+        }
+        let actual = i.config.output.get_buffer().unwrap();
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,29 @@
+use crate::{register::Register, registers::RegisterOperations, Interpreter};
+
+impl Interpreter {
+    pub(super) fn get_program_counter(&mut self, register: Register) {
+        self.registers.set(register, self.program_counter);
+    }
+}
+
+#[cfg(test)]
+mod get {
+    use crate::{
+        constant::Word, error::ExecuteError, instruction::Instruction, register::Register,
+        registers::RegisterOperations, Interpreter,
+    };
+
+    #[test]
+    fn stores_current_line() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::GetProgramCounter(Register::A);
+        i.program_counter = 42;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Word>(Register::A), 42);
+        assert_eq!(i.program_counter, 43);
+
+        Ok(())
+    }
+}
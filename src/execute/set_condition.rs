@@ -0,0 +1,63 @@
+use crate::{
+    constant::Word, instruction::Jump, register::Register, registers::RegisterOperations,
+    Interpreter,
+};
+
+impl Interpreter {
+    pub(super) fn set_condition(&mut self, condition: Jump, register: Register) {
+        let value = Word::from(self.condition(&condition));
+        self.registers.set(register, value);
+    }
+}
+
+#[cfg(test)]
+mod regression {
+    use crate::{
+        constant::Word,
+        error::ExecuteError,
+        instruction::{Instruction, Jump},
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn condition_met_clears_remaining_bits() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::SetCondition(Jump::IfSign, Register::A);
+        i.flags.sign = true;
+        i.registers.set(Register::A, Word::MAX);
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Word>(Register::A), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn condition_not_met() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::SetCondition(Jump::IfSign, Register::A);
+        i.registers.set(Register::A, Word::MAX);
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Word>(Register::A), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn flags_are_not_affected() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::SetCondition(Jump::IfNotZero, Register::A);
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Word>(Register::A), 1);
+        assert!(!i.flags.zero);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,105 @@
+use crate::{
+    constant::Word, error::ExecuteError, operand::Operand, register::Register,
+    registers::RegisterOperations, Interpreter,
+};
+
+impl Interpreter {
+    pub(super) fn get_stack_pointer(&mut self, register: Register) {
+        self.registers.set(register, self.stack.sp() as Word);
+    }
+
+    pub(super) fn set_stack_pointer(&mut self, operand: Operand<Word>) -> Result<(), ExecuteError> {
+        let pointer = self.get_operand_value(operand);
+        self.stack.set_sp(pointer)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod get {
+    use crate::{
+        constant::{Half, Word},
+        error::ExecuteError,
+        instruction::Instruction,
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn empty_stack() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::GetStackPointer(Register::A);
+        i.registers.set(Register::A, Word::MAX);
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Word>(Register::A), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn non_empty_stack() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::GetStackPointer(Register::A);
+        i.stack.push(Half::MAX)?;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Word>(Register::A), 4);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod set {
+    use crate::{
+        constant::{Word, TEST_STACK_SIZE},
+        error::ExecuteError,
+        instruction::Instruction,
+        operand::Operand,
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn stack_overflow() {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::SetStackPointer(Operand::Immediate(Word::MAX));
+        let expected = Err(ExecuteError::StackOverflow);
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn set_from_immediate() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::SetStackPointer(Operand::Immediate(TEST_STACK_SIZE as Word));
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.stack.sp(), TEST_STACK_SIZE);
+
+        Ok(())
+    }
+
+    #[test]
+    fn set_from_register() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::SetStackPointer(Operand::Register(Register::A));
+        i.stack.push(Word::MAX)?;
+        i.registers.set(Register::A, 2);
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.stack.sp(), 2);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,288 @@
+use crate::{
+    constant::Word,
+    error::ExecuteError,
+    instruction::StoreStack,
+    operand::Operand,
+    utils::{FromBytes, ToBytes},
+    Interpreter,
+};
+
+impl Interpreter {
+    pub(super) fn store_stack(&mut self, instruction: StoreStack) -> Result<(), ExecuteError> {
+        match instruction {
+            StoreStack::Byte(offset, o) => self.store_stack_value(offset, o)?,
+            StoreStack::Quarter(offset, o) => self.store_stack_value(offset, o)?,
+            StoreStack::Half(offset, o) => self.store_stack_value(offset, o)?,
+            StoreStack::Word(offset, o) => self.store_stack_value(offset, o)?,
+        }
+
+        Ok(())
+    }
+
+    fn store_stack_value<T>(
+        &mut self,
+        offset: Operand<Word>,
+        operand: Operand<T>,
+    ) -> Result<(), ExecuteError>
+    where
+        T: FromBytes + ToBytes,
+    {
+        let offset = self.get_operand_value(offset);
+        let value = self.get_operand_value(operand);
+        self.stack.store(offset, value)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod byte {
+    use crate::{
+        constant::Byte,
+        error::ExecuteError,
+        instruction::{Instruction, StoreStack},
+        operand::Operand,
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn stack_underflow() {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::StoreStack(StoreStack::Byte(
+            Operand::Immediate(0),
+            Operand::Immediate(Byte::MAX),
+        ));
+        let expected = Err(ExecuteError::StackUnderflow);
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn store_immediate_value() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::StoreStack(StoreStack::Byte(
+            Operand::Immediate(1),
+            Operand::Immediate(Byte::MAX),
+        ));
+        i.stack.push::<Byte>(0)?;
+        i.stack.push(Byte::MIN)?;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.stack.pop::<Byte>()?, Byte::MIN);
+        assert_eq!(i.stack.pop::<Byte>()?, Byte::MAX);
+
+        Ok(())
+    }
+
+    #[test]
+    fn store_register_value() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::StoreStack(StoreStack::Byte(
+            Operand::Register(Register::B),
+            Operand::Register(Register::A),
+        ));
+        i.stack.push::<Byte>(0)?;
+        i.registers.set(Register::A, Byte::MAX);
+        i.registers.set(Register::B, 0);
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.stack.pop::<Byte>()?, Byte::MAX);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod quarter {
+    use crate::{
+        constant::{Byte, Quarter},
+        error::ExecuteError,
+        instruction::{Instruction, StoreStack},
+        operand::Operand,
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn stack_underflow() {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::StoreStack(StoreStack::Quarter(
+            Operand::Immediate(0),
+            Operand::Immediate(Quarter::MAX),
+        ));
+        let expected = Err(ExecuteError::StackUnderflow);
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn store_immediate_value() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::StoreStack(StoreStack::Quarter(
+            Operand::Immediate(1),
+            Operand::Immediate(Quarter::MAX),
+        ));
+        i.stack.push::<Quarter>(0)?;
+        i.stack.push(Byte::MIN)?;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.stack.pop::<Byte>()?, Byte::MIN);
+        assert_eq!(i.stack.pop::<Quarter>()?, Quarter::MAX);
+
+        Ok(())
+    }
+
+    #[test]
+    fn store_register_value() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::StoreStack(StoreStack::Quarter(
+            Operand::Register(Register::B),
+            Operand::Register(Register::A),
+        ));
+        i.stack.push::<Quarter>(0)?;
+        i.registers.set(Register::A, Quarter::MAX);
+        i.registers.set(Register::B, 0);
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.stack.pop::<Quarter>()?, Quarter::MAX);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod half {
+    use crate::{
+        constant::{Byte, Half},
+        error::ExecuteError,
+        instruction::{Instruction, StoreStack},
+        operand::Operand,
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn stack_underflow() {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::StoreStack(StoreStack::Half(
+            Operand::Immediate(0),
+            Operand::Immediate(Half::MAX),
+        ));
+        let expected = Err(ExecuteError::StackUnderflow);
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn store_immediate_value() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::StoreStack(StoreStack::Half(
+            Operand::Immediate(1),
+            Operand::Immediate(Half::MAX),
+        ));
+        i.stack.push::<Half>(0)?;
+        i.stack.push(Byte::MIN)?;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.stack.pop::<Byte>()?, Byte::MIN);
+        assert_eq!(i.stack.pop::<Half>()?, Half::MAX);
+
+        Ok(())
+    }
+
+    #[test]
+    fn store_register_value() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::StoreStack(StoreStack::Half(
+            Operand::Register(Register::B),
+            Operand::Register(Register::A),
+        ));
+        i.stack.push::<Half>(0)?;
+        i.registers.set(Register::A, Half::MAX);
+        i.registers.set(Register::B, 0);
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.stack.pop::<Half>()?, Half::MAX);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod word {
+    use crate::{
+        constant::{Byte, Word},
+        error::ExecuteError,
+        instruction::{Instruction, StoreStack},
+        operand::Operand,
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn stack_underflow() {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::StoreStack(StoreStack::Word(
+            Operand::Immediate(0),
+            Operand::Immediate(Word::MAX),
+        ));
+        let expected = Err(ExecuteError::StackUnderflow);
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn store_immediate_value() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::StoreStack(StoreStack::Word(
+            Operand::Immediate(1),
+            Operand::Immediate(Word::MAX),
+        ));
+        i.stack.push::<Word>(0)?;
+        i.stack.push(Byte::MIN)?;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.stack.pop::<Byte>()?, Byte::MIN);
+        assert_eq!(i.stack.pop::<Word>()?, Word::MAX);
+
+        Ok(())
+    }
+
+    #[test]
+    fn store_register_value() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::StoreStack(StoreStack::Word(
+            Operand::Register(Register::B),
+            Operand::Register(Register::A),
+        ));
+        i.stack.push::<Word>(0)?;
+        i.registers.set(Register::A, Word::MAX);
+        i.registers.set(Register::B, 0);
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.stack.pop::<Word>()?, Word::MAX);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,195 @@
+use crate::{
+    constant::{Byte, Half, Quarter, Word},
+    error::ExecuteError,
+    instruction::Swap,
+    utils::{FromBytes, ToBytes},
+    Interpreter,
+};
+
+impl Interpreter {
+    pub(super) fn swap(&mut self, instruction: Swap) -> Result<(), ExecuteError> {
+        match instruction {
+            Swap::Byte => self.swap_values::<Byte>()?,
+            Swap::Quarter => self.swap_values::<Quarter>()?,
+            Swap::Half => self.swap_values::<Half>()?,
+            Swap::Word => self.swap_values::<Word>()?,
+        }
+
+        Ok(())
+    }
+
+    fn swap_values<T>(&mut self) -> Result<(), ExecuteError>
+    where
+        T: FromBytes + ToBytes,
+    {
+        let offset = size_of::<T>() as Word;
+        // both values are read before writing, so the stack is left untouched on underflow
+        let top = self.stack.load::<T>(0)?;
+        let below = self.stack.load::<T>(offset)?;
+
+        self.stack.store(0, below)?;
+        self.stack.store(offset, top)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod byte {
+    use crate::{
+        constant::Byte,
+        error::ExecuteError,
+        instruction::{Instruction, Swap},
+        Interpreter,
+    };
+
+    #[test]
+    fn stack_underflow() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::Swap(Swap::Byte);
+        i.stack.push(Byte::MAX)?;
+        let expected = Err(ExecuteError::StackUnderflow);
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+        assert_eq!(i.stack.pop::<Byte>()?, Byte::MAX);
+
+        Ok(())
+    }
+
+    #[test]
+    fn swaps_two_topmost_values() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::Swap(Swap::Byte);
+        i.stack.push::<Byte>(1)?;
+        i.stack.push(Byte::MAX)?;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.stack.pop::<Byte>()?, 1);
+        assert_eq!(i.stack.pop::<Byte>()?, Byte::MAX);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod quarter {
+    use crate::{
+        constant::Quarter,
+        error::ExecuteError,
+        instruction::{Instruction, Swap},
+        Interpreter,
+    };
+
+    #[test]
+    fn stack_underflow() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::Swap(Swap::Quarter);
+        i.stack.push(Quarter::MAX)?;
+        let expected = Err(ExecuteError::StackUnderflow);
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+        assert_eq!(i.stack.pop::<Quarter>()?, Quarter::MAX);
+
+        Ok(())
+    }
+
+    #[test]
+    fn swaps_two_topmost_values() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::Swap(Swap::Quarter);
+        i.stack.push::<Quarter>(1)?;
+        i.stack.push(Quarter::MAX)?;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.stack.pop::<Quarter>()?, 1);
+        assert_eq!(i.stack.pop::<Quarter>()?, Quarter::MAX);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod half {
+    use crate::{
+        constant::Half,
+        error::ExecuteError,
+        instruction::{Instruction, Swap},
+        Interpreter,
+    };
+
+    #[test]
+    fn stack_underflow() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::Swap(Swap::Half);
+        i.stack.push(Half::MAX)?;
+        let expected = Err(ExecuteError::StackUnderflow);
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+        assert_eq!(i.stack.pop::<Half>()?, Half::MAX);
+
+        Ok(())
+    }
+
+    #[test]
+    fn swaps_two_topmost_values() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::Swap(Swap::Half);
+        i.stack.push::<Half>(1)?;
+        i.stack.push(Half::MAX)?;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.stack.pop::<Half>()?, 1);
+        assert_eq!(i.stack.pop::<Half>()?, Half::MAX);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod word {
+    use crate::{
+        constant::Word,
+        error::ExecuteError,
+        instruction::{Instruction, Swap},
+        Interpreter,
+    };
+
+    #[test]
+    fn stack_underflow() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::Swap(Swap::Word);
+        i.stack.push(Word::MAX)?;
+        let expected = Err(ExecuteError::StackUnderflow);
+
+        let actual = i.execute(instruction);
+
+        assert_eq!(actual, expected);
+        assert_eq!(i.stack.pop::<Word>()?, Word::MAX);
+
+        Ok(())
+    }
+
+    #[test]
+    fn swaps_two_topmost_values() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        let instruction = Instruction::Swap(Swap::Word);
+        i.stack.push::<Word>(1)?;
+        i.stack.push(Word::MAX)?;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.stack.pop::<Word>()?, 1);
+        assert_eq!(i.stack.pop::<Word>()?, Word::MAX);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,189 @@
+use crate::{
+    constant::{Byte, Half, Quarter, Word},
+    instruction::TrailingZeros,
+    register::Register,
+    registers::RegisterOperations,
+    utils::BitWise,
+    Interpreter,
+};
+
+impl Interpreter {
+    pub(super) fn trailing_zeros(&mut self, instruction: TrailingZeros) {
+        match instruction {
+            TrailingZeros::Byte(r) => self.trailing_zeros_value::<Byte>(r),
+            TrailingZeros::Quarter(r) => self.trailing_zeros_value::<Quarter>(r),
+            TrailingZeros::Half(r) => self.trailing_zeros_value::<Half>(r),
+            TrailingZeros::Word(r) => self.trailing_zeros_value::<Word>(r),
+        }
+    }
+
+    fn trailing_zeros_value<T>(&mut self, register: Register)
+    where
+        T: BitWise,
+    {
+        let value = self.registers.get::<T>(register);
+        let result = value.bit_trailing_zeros();
+        self.flags.set(result, false);
+        self.registers.set(register, result);
+    }
+}
+
+#[cfg(test)]
+mod byte {
+    use crate::{
+        constant::Byte,
+        error::ExecuteError,
+        instruction::{Instruction, TrailingZeros},
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn counts_trailing_zeros() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Byte>(Register::A, 0b1000);
+        let instruction = Instruction::TrailingZeros(TrailingZeros::Byte(Register::A));
+        let expected: Byte = 3;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Byte>(Register::A), expected);
+        assert!(!i.flags.zero);
+        assert!(!i.flags.overflow);
+
+        Ok(())
+    }
+
+    #[test]
+    fn zero_is_all_trailing_zeros() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Byte>(Register::A, 0);
+        let instruction = Instruction::TrailingZeros(TrailingZeros::Byte(Register::A));
+        let expected: Byte = 8;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Byte>(Register::A), expected);
+        assert!(!i.flags.zero);
+        assert!(!i.flags.overflow);
+
+        Ok(())
+    }
+
+    #[test]
+    fn lowest_bit_set() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Byte>(Register::A, 1);
+        let instruction = Instruction::TrailingZeros(TrailingZeros::Byte(Register::A));
+        let expected: Byte = 0;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Byte>(Register::A), expected);
+        assert!(i.flags.zero);
+        assert!(!i.flags.overflow);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod quarter {
+    use crate::{
+        constant::Quarter,
+        error::ExecuteError,
+        instruction::{Instruction, TrailingZeros},
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn counts_trailing_zeros() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Quarter>(Register::A, 0x8000);
+        let instruction = Instruction::TrailingZeros(TrailingZeros::Quarter(Register::A));
+        let expected: Quarter = 15;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Quarter>(Register::A), expected);
+        assert!(!i.flags.zero);
+        assert!(!i.flags.overflow);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod half {
+    use crate::{
+        constant::Half,
+        error::ExecuteError,
+        instruction::{Instruction, TrailingZeros},
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn counts_trailing_zeros() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Half>(Register::A, 0x8000_0000);
+        let instruction = Instruction::TrailingZeros(TrailingZeros::Half(Register::A));
+        let expected: Half = 31;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Half>(Register::A), expected);
+        assert!(!i.flags.zero);
+        assert!(!i.flags.overflow);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod word {
+    use crate::{
+        constant::Word,
+        error::ExecuteError,
+        instruction::{Instruction, TrailingZeros},
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn counts_trailing_zeros() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Word>(Register::A, 0x8000_0000_0000_0000);
+        let instruction = Instruction::TrailingZeros(TrailingZeros::Word(Register::A));
+        let expected: Word = 63;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Word>(Register::A), expected);
+        assert!(!i.flags.zero);
+        assert!(!i.flags.overflow);
+
+        Ok(())
+    }
+
+    #[test]
+    fn zero_is_all_trailing_zeros() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Word>(Register::A, 0);
+        let instruction = Instruction::TrailingZeros(TrailingZeros::Word(Register::A));
+        let expected: Word = 64;
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Word>(Register::A), expected);
+        assert!(!i.flags.zero);
+        assert!(!i.flags.overflow);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,282 @@
+use crate::{
+    instruction::WideningMultiplication, operand::Operand, register::Register,
+    registers::RegisterOperations, utils::Arithmetic, Interpreter,
+};
+
+impl Interpreter {
+    pub(super) fn widening_mul(&mut self, instruction: WideningMultiplication) {
+        match instruction {
+            WideningMultiplication::Byte(h, l, o) => self.widening_mul_value(h, l, o),
+            WideningMultiplication::Quarter(h, l, o) => self.widening_mul_value(h, l, o),
+            WideningMultiplication::Half(h, l, o) => self.widening_mul_value(h, l, o),
+            WideningMultiplication::Word(h, l, o) => self.widening_mul_value(h, l, o),
+        }
+    }
+
+    fn widening_mul_value<T>(&mut self, high: Register, low: Register, operand: Operand<T>)
+    where
+        T: Arithmetic,
+    {
+        let a = self.registers.get::<T>(low);
+        let b = self.get_operand_value(operand);
+
+        let (low_result, high_result) = a.overflow_widening_mul(b);
+        self.flags.set(high_result, !high_result.is_zero());
+        self.registers.set(low, low_result);
+        self.registers.set(high, high_result);
+    }
+}
+
+#[cfg(test)]
+mod byte {
+    use crate::{
+        constant::Byte,
+        error::ExecuteError,
+        instruction::{Instruction, WideningMultiplication},
+        operand::Operand,
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn product_fits_in_low_part() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Byte>(Register::B, 5);
+        i.registers.set(Register::A, Byte::MAX);
+        let instruction = Instruction::WideningMultiplication(WideningMultiplication::Byte(
+            Register::A,
+            Register::B,
+            Operand::Immediate(10),
+        ));
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Byte>(Register::A), 0);
+        assert_eq!(i.registers.get::<Byte>(Register::B), 50);
+        assert!(i.flags.zero);
+        assert!(!i.flags.overflow);
+
+        Ok(())
+    }
+
+    #[test]
+    fn product_spills_into_high_part() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set(Register::B, Byte::MAX);
+        i.registers.set(Register::C, Byte::MAX);
+        let instruction = Instruction::WideningMultiplication(WideningMultiplication::Byte(
+            Register::A,
+            Register::B,
+            Operand::Register(Register::C),
+        ));
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Byte>(Register::A), Byte::MAX - 1);
+        assert_eq!(i.registers.get::<Byte>(Register::B), 1);
+        assert!(!i.flags.zero);
+        assert!(i.flags.overflow);
+        assert!(i.flags.sign);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod quarter {
+    use crate::{
+        constant::Quarter,
+        error::ExecuteError,
+        instruction::{Instruction, WideningMultiplication},
+        operand::Operand,
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn product_fits_in_low_part() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Quarter>(Register::B, 5);
+        i.registers.set(Register::A, Quarter::MAX);
+        let instruction = Instruction::WideningMultiplication(WideningMultiplication::Quarter(
+            Register::A,
+            Register::B,
+            Operand::Immediate(10),
+        ));
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Quarter>(Register::A), 0);
+        assert_eq!(i.registers.get::<Quarter>(Register::B), 50);
+        assert!(i.flags.zero);
+        assert!(!i.flags.overflow);
+
+        Ok(())
+    }
+
+    #[test]
+    fn product_spills_into_high_part() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set(Register::B, Quarter::MAX);
+        i.registers.set(Register::C, Quarter::MAX);
+        let instruction = Instruction::WideningMultiplication(WideningMultiplication::Quarter(
+            Register::A,
+            Register::B,
+            Operand::Register(Register::C),
+        ));
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Quarter>(Register::A), Quarter::MAX - 1);
+        assert_eq!(i.registers.get::<Quarter>(Register::B), 1);
+        assert!(!i.flags.zero);
+        assert!(i.flags.overflow);
+        assert!(i.flags.sign);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod half {
+    use crate::{
+        constant::Half,
+        error::ExecuteError,
+        instruction::{Instruction, WideningMultiplication},
+        operand::Operand,
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn product_fits_in_low_part() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Half>(Register::B, 5);
+        i.registers.set(Register::A, Half::MAX);
+        let instruction = Instruction::WideningMultiplication(WideningMultiplication::Half(
+            Register::A,
+            Register::B,
+            Operand::Immediate(10),
+        ));
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Half>(Register::A), 0);
+        assert_eq!(i.registers.get::<Half>(Register::B), 50);
+        assert!(i.flags.zero);
+        assert!(!i.flags.overflow);
+
+        Ok(())
+    }
+
+    #[test]
+    fn product_spills_into_high_part() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set(Register::B, Half::MAX);
+        i.registers.set(Register::C, Half::MAX);
+        let instruction = Instruction::WideningMultiplication(WideningMultiplication::Half(
+            Register::A,
+            Register::B,
+            Operand::Register(Register::C),
+        ));
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Half>(Register::A), Half::MAX - 1);
+        assert_eq!(i.registers.get::<Half>(Register::B), 1);
+        assert!(!i.flags.zero);
+        assert!(i.flags.overflow);
+        assert!(i.flags.sign);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod word {
+    use crate::{
+        constant::Word,
+        error::ExecuteError,
+        instruction::{Instruction, WideningMultiplication},
+        operand::Operand,
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn product_fits_in_low_part() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Word>(Register::B, 5);
+        i.registers.set(Register::A, Word::MAX);
+        let instruction = Instruction::WideningMultiplication(WideningMultiplication::Word(
+            Register::A,
+            Register::B,
+            Operand::Immediate(10),
+        ));
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Word>(Register::A), 0);
+        assert_eq!(i.registers.get::<Word>(Register::B), 50);
+        assert!(i.flags.zero);
+        assert!(!i.flags.overflow);
+
+        Ok(())
+    }
+
+    #[test]
+    fn product_spills_into_high_part() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set(Register::B, Word::MAX);
+        i.registers.set(Register::C, Word::MAX);
+        let instruction = Instruction::WideningMultiplication(WideningMultiplication::Word(
+            Register::A,
+            Register::B,
+            Operand::Register(Register::C),
+        ));
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Word>(Register::A), Word::MAX - 1);
+        assert_eq!(i.registers.get::<Word>(Register::B), 1);
+        assert!(!i.flags.zero);
+        assert!(i.flags.overflow);
+        assert!(i.flags.sign);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod same_register {
+    use crate::{
+        constant::Byte,
+        error::ExecuteError,
+        instruction::{Instruction, WideningMultiplication},
+        operand::Operand,
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn high_part_is_stored_last() -> Result<(), ExecuteError> {
+        let mut i = Interpreter::new_test();
+        i.registers.set::<Byte>(Register::A, 0x40);
+        let instruction = Instruction::WideningMultiplication(WideningMultiplication::Byte(
+            Register::A,
+            Register::A,
+            Operand::Immediate(8),
+        ));
+
+        i.execute(instruction)?;
+
+        assert_eq!(i.registers.get::<Byte>(Register::A), 0x02);
+
+        Ok(())
+    }
+}
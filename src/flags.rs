@@ -23,6 +23,12 @@ impl Flags {
     }
 }
 
+impl Default for Flags {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 // It does not make sense to test `overflow` flag and `zero` flag as they are trivially set.
 //
 // As such, only the `sign` flag has test cases.
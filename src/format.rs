@@ -0,0 +1,431 @@
+//! Formatting of source code into a consistent layout, without changing the meaning of the program.
+//!
+//! Every line is kept, such that line numbers and jump destinations are unchanged. Instructions are
+//! indented inside function bodies, and their mnemonics and operands are aligned into columns within
+//! each block of consecutive instructions. Comments that start a line are kept at the start of it,
+//! as indenting them would turn them into instructions.
+
+use crate::{
+    constant::COMMENT,
+    decode::decode_line,
+    error::{DecodeError, InterpreterError, PreProcessError},
+    instruction::Instruction,
+    preprocess::{preprocess, DATA_SECTION},
+};
+
+/// The indentation of function bodies and the entries of the data section.
+const INDENT: &str = "  ";
+
+/// A line of source code, as it is laid out by the formatter.
+enum Layout<'a> {
+    /// A line that is written as is, apart from trailing whitespace.
+    Verbatim(&'a str),
+    /// A comment that is indented, which is an instruction that fails to decode if executed.
+    Indented(&'a str),
+    /// The line that names a function, which is not aligned with the instructions around it.
+    Function(Vec<&'a str>),
+    /// The declaration of a constant, which is not aligned with the instructions around it either.
+    Constant {
+        indent: &'static str,
+        tokens: Vec<&'a str>,
+    },
+    /// A line of tokens, which are aligned with the surrounding lines of tokens.
+    Tokens {
+        indent: &'static str,
+        tokens: Vec<&'a str>,
+        comment: Option<&'a str>,
+    },
+}
+
+/// Formats the source code of a program.
+///
+/// The formatted program is verified to preprocess and decode into the same instructions as the original.
+///
+/// # Errors
+/// Returns an error if the source code could not be preprocessed, or if formatting would change the meaning of a line.
+pub fn format_source(source_code: &str) -> Result<String, InterpreterError> {
+    let lines: Vec<&str> = source_code.lines().collect();
+    let data_section = data_section_line(source_code);
+
+    let mut in_function = false;
+    let mut layouts = Vec::with_capacity(lines.len());
+    for (index, line) in lines.iter().enumerate() {
+        let line = line.trim_end_matches(|c: char| c.is_ascii_whitespace());
+        let trimmed = line.trim_start_matches(|c: char| c.is_ascii_whitespace());
+        let in_data_section = data_section.is_some_and(|data_section| index > data_section);
+
+        let layout = if data_section == Some(index) {
+            if trimmed == DATA_SECTION {
+                Layout::Verbatim(trimmed)
+            } else {
+                Layout::Verbatim(line)
+            }
+        } else if trimmed.is_empty() || line.starts_with(COMMENT) {
+            Layout::Verbatim(trimmed)
+        } else if trimmed.starts_with(COMMENT) {
+            let indented = in_function || in_data_section;
+            Layout::Indented(if indented { trimmed } else { line })
+        } else {
+            let (code, comment) = split_comment(trimmed);
+            let tokens: Vec<&str> = code.split_ascii_whitespace().collect();
+
+            let indent = if in_function || in_data_section {
+                INDENT
+            } else {
+                ""
+            };
+
+            if tokens.first() == Some(&"fn") && !in_data_section {
+                in_function = true;
+                Layout::Function(trimmed.split_ascii_whitespace().collect())
+            } else if tokens.first() == Some(&"const") && !in_data_section {
+                Layout::Constant {
+                    indent,
+                    tokens: trimmed.split_ascii_whitespace().collect(),
+                }
+            } else {
+                Layout::Tokens {
+                    indent,
+                    tokens,
+                    comment,
+                }
+            }
+        };
+        layouts.push(layout);
+    }
+
+    let formatted = write(&layouts);
+    verify(source_code, &formatted)?;
+
+    Ok(formatted)
+}
+
+/// Returns the index of the line that starts the data section, which is found the same way the preprocessor does.
+fn data_section_line(source_code: &str) -> Option<usize> {
+    let start = source_code.rfind(DATA_SECTION)?;
+    let preceding = source_code.get(..start)?;
+
+    Some(preceding.matches('\n').count())
+}
+
+/// Splits the comment at the end of a line from the code before it.
+fn split_comment(line: &str) -> (&str, Option<&str>) {
+    let comment = line
+        .match_indices(COMMENT)
+        .map(|(index, _)| index)
+        .find(|&index| {
+            // only a comment if it starts a token, as the decoder would otherwise treat it as part of one
+            index == 0
+                || line
+                    .get(..index)
+                    .is_some_and(|code| code.ends_with(|c: char| c.is_ascii_whitespace()))
+        });
+
+    match comment.and_then(|index| line.split_at_checked(index)) {
+        Some((code, comment)) => (code, Some(comment)),
+        None => (line, None),
+    }
+}
+
+/// Writes the lines, where consecutive lines of tokens with the same indentation are aligned into columns.
+fn write(layouts: &[Layout]) -> String {
+    let mut formatted = String::new();
+
+    let mut start = 0;
+    while let Some(layout) = layouts.get(start) {
+        let Layout::Tokens { indent, .. } = layout else {
+            match layout {
+                Layout::Verbatim(line) => formatted.push_str(line),
+                Layout::Indented(line) => {
+                    formatted.push_str(INDENT);
+                    formatted.push_str(line);
+                }
+                Layout::Function(tokens) => formatted.push_str(&tokens.join(" ")),
+                Layout::Constant { indent, tokens } => {
+                    formatted.push_str(indent);
+                    formatted.push_str(&tokens.join(" "));
+                }
+                Layout::Tokens { .. } => unreachable!("lines of tokens are written in blocks"),
+            }
+            formatted.push('\n');
+            start += 1;
+            continue;
+        };
+
+        let block: Vec<(&Vec<&str>, Option<&str>)> = layouts
+            .get(start..)
+            .unwrap_or_default()
+            .iter()
+            .map_while(|layout| match layout {
+                Layout::Tokens {
+                    indent: block_indent,
+                    tokens,
+                    comment,
+                } if block_indent == indent => Some((tokens, *comment)),
+                _ => None,
+            })
+            .collect();
+
+        // the last token of each line is not padded, so it does not contribute to the width of its column
+        let mut widths: Vec<usize> = Vec::new();
+        for (tokens, _) in &block {
+            let padded = tokens.len().saturating_sub(1);
+            for (column, token) in tokens.iter().take(padded).enumerate() {
+                match widths.get_mut(column) {
+                    Some(width) => *width = (*width).max(token.len()),
+                    None => widths.push(token.len()),
+                }
+            }
+        }
+
+        for (tokens, comment) in &block {
+            let mut line = String::from(*indent);
+            for (column, token) in tokens.iter().enumerate() {
+                if column > 0 {
+                    line.push(' ');
+                }
+                if column + 1 < tokens.len() {
+                    let width = widths.get(column).copied().unwrap_or_default();
+                    line.push_str(&format!("{token:<width$}"));
+                } else {
+                    line.push_str(token);
+                }
+            }
+            if let Some(comment) = comment {
+                if !tokens.is_empty() {
+                    line.push(' ');
+                }
+                line.push_str(comment);
+            }
+
+            formatted.push_str(&line);
+            formatted.push('\n');
+        }
+
+        start += block.len();
+    }
+
+    formatted
+}
+
+/// The meaning of each line of a program, which is either a comment, an instruction or a decode error.
+type Meaning = Vec<Result<Option<Instruction>, DecodeError>>;
+
+fn meaning(source_code: &str) -> Result<Meaning, PreProcessError> {
+    let (lines, _) = preprocess(source_code)?;
+    let meaning = lines.iter().map(|line| decode_line(line)).collect();
+
+    Ok(meaning)
+}
+
+/// Verifies the formatted source code has the same meaning as the original.
+fn verify(original: &str, formatted: &str) -> Result<(), InterpreterError> {
+    let expected = meaning(original).map_err(InterpreterError::PreProcess)?;
+    let actual = meaning(formatted).map_err(InterpreterError::PreProcess)?;
+
+    let changed = expected
+        .iter()
+        .zip(&actual)
+        .position(|(expected, actual)| expected != actual)
+        .or((expected.len() != actual.len()).then_some(expected.len().min(actual.len())));
+
+    match changed {
+        Some(index) => Err(InterpreterError::FormatChangedMeaning(index + 1)),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod layout {
+    use super::format_source;
+    use crate::error::{InterpreterError, PreProcessError};
+
+    #[test]
+    fn function_bodies_are_indented() -> Result<(), InterpreterError> {
+        let source = [
+            "call inc_ra",
+            "stop",
+            "fn   inc_ra:",
+            "addb ra 1",
+            "      ret",
+        ]
+        .join("\n");
+        let expected = [
+            "call inc_ra",
+            "stop",
+            "fn inc_ra:",
+            "  addb ra 1",
+            "  ret",
+            "",
+        ]
+        .join("\n");
+
+        let actual = format_source(&source)?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn operands_are_aligned() -> Result<(), InterpreterError> {
+        let source = [
+            "setb   ra 1",
+            "divremq rb rc\t7",
+            "prnl",
+            "",
+            "pshb ra",
+            "cmpb ra 10",
+        ]
+        .join("\n");
+        let expected = [
+            "setb    ra 1",
+            "divremq rb rc 7",
+            "prnl",
+            "",
+            "pshb ra",
+            "cmpb ra 10",
+            "",
+        ]
+        .join("\n");
+
+        let actual = format_source(&source)?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn comments_are_preserved() -> Result<(), InterpreterError> {
+        let source = [
+            "// increments ra   ",
+            "setb ra 1 //  the start",
+            "stop",
+            "fn inc_ra:",
+            "// not indented, as that would make it an instruction",
+            "    // already an instruction, so it may be indented",
+            "addb ra 1",
+            "ret",
+        ]
+        .join("\n");
+        let expected = [
+            "// increments ra",
+            "setb ra 1 //  the start",
+            "stop",
+            "fn inc_ra:",
+            "// not indented, as that would make it an instruction",
+            "  // already an instruction, so it may be indented",
+            "  addb ra 1",
+            "  ret",
+            "",
+        ]
+        .join("\n");
+
+        let actual = format_source(&source)?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn data_section() -> Result<(), InterpreterError> {
+        let source = [
+            "setb ra FIVE",
+            "stop",
+            "",
+            "  DATA:  ",
+            "FIVE    5",
+            "\tFIVE_HUNDRED 500",
+            "",
+            "    // comment",
+            "TEN 10",
+        ]
+        .join("\n");
+        let expected = [
+            "setb ra FIVE",
+            "stop",
+            "",
+            "DATA:",
+            "  FIVE         5",
+            "  FIVE_HUNDRED 500",
+            "",
+            "  // comment",
+            "  TEN 10",
+            "",
+        ]
+        .join("\n");
+
+        let actual = format_source(&source)?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn constants_are_not_aligned() -> Result<(), InterpreterError> {
+        let source = [
+            "const LIMIT:   byte = 200",
+            "setb ra LIMIT",
+            "fn inc_ra:",
+            "const STEP: byte = 1",
+            "addb ra STEP",
+            "ret",
+        ]
+        .join("\n");
+        let expected = [
+            "const LIMIT: byte = 200",
+            "setb ra LIMIT",
+            "fn inc_ra:",
+            "  const STEP: byte = 1",
+            "  addb ra STEP",
+            "  ret",
+            "",
+        ]
+        .join("\n");
+
+        let actual = format_source(&source)?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn lines_are_kept() -> Result<(), InterpreterError> {
+        let source = ["jmp 4", "   ", "", "stop", "", ""].join("\n");
+        let expected = ["jmp 4", "", "", "stop", "", ""].join("\n");
+
+        let actual = format_source(&source)?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn formatting_is_idempotent() -> Result<(), InterpreterError> {
+        let source = std::fs::read_to_string("program.kasm").expect("example program should exist");
+
+        let formatted = format_source(&source)?;
+        let actual = format_source(&formatted)?;
+
+        assert_eq!(actual, formatted);
+
+        Ok(())
+    }
+
+    #[test]
+    fn preprocess_error() {
+        let source = "call undefined";
+        let expected = Err(InterpreterError::PreProcess(
+            PreProcessError::UndefinedFunctionCalled("undefined".to_string()),
+        ));
+
+        let actual = format_source(source);
+
+        assert_eq!(actual, expected);
+    }
+}
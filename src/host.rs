@@ -0,0 +1,86 @@
+use crate::{
+    constant::{Byte, Word},
+    error::ExecuteError,
+    flags::Flags,
+    register::Register,
+    registers::{RegisterOperations, Registers},
+    stack::Stack,
+    utils::{FromBytes, ToBytes, Writer},
+};
+use std::{collections::HashMap, io::Write};
+
+/// A function defined by the host, which can be called from a program by name.
+pub type HostFunction = Box<dyn FnMut(&mut HostContext) -> Result<(), ExecuteError>>;
+
+/// The host functions registered on an interpreter, indexed by name.
+pub(crate) type HostFunctions = HashMap<String, HostFunction>;
+
+/// Gives a host function access to the state of the interpreter while it is being called.
+pub struct HostContext<'a> {
+    registers: &'a mut Registers,
+    flags: &'a mut Flags,
+    stack: &'a mut Stack,
+    output: &'a mut dyn Writer,
+}
+
+impl<'a> HostContext<'a> {
+    pub(crate) fn new(
+        registers: &'a mut Registers,
+        flags: &'a mut Flags,
+        stack: &'a mut Stack,
+        output: &'a mut dyn Writer,
+    ) -> Self {
+        Self {
+            registers,
+            flags,
+            stack,
+            output,
+        }
+    }
+
+    /// Gets the full value of a register.
+    pub fn register(&self, register: Register) -> Word {
+        self.registers.get(register)
+    }
+
+    /// Sets the full value of a register.
+    pub fn set_register(&mut self, register: Register, value: Word) {
+        self.registers.set(register, value);
+    }
+
+    /// Gets the bytes currently on the stack, from the bottom of the stack up to the stack pointer.
+    pub fn stack(&self) -> &[Byte] {
+        self.stack.contents()
+    }
+
+    /// Pushes a value onto the stack, which takes up as many bytes as its type.
+    ///
+    /// # Errors
+    /// Returns an error if the stack overflows.
+    pub fn push<T: ToBytes>(&mut self, value: T) -> Result<(), ExecuteError> {
+        self.stack.push(value)
+    }
+
+    /// Pops a value from the stack, which takes up as many bytes as its type.
+    ///
+    /// # Errors
+    /// Returns an error if the stack underflows.
+    pub fn pop<T: FromBytes>(&mut self) -> Result<T, ExecuteError> {
+        self.stack.pop()
+    }
+
+    /// Gets the flags set by the last instruction that sets them.
+    pub fn flags(&self) -> &Flags {
+        self.flags
+    }
+
+    /// Sets the flags, which are used by conditional jumps and moves of the program.
+    pub fn set_flags(&mut self, flags: Flags) {
+        *self.flags = flags;
+    }
+
+    /// Gets the output that print instructions write to.
+    pub fn output(&mut self) -> &mut dyn Write {
+        &mut *self.output
+    }
+}
@@ -3,11 +3,23 @@ use crate::{
     operand::Operand,
     register::Register,
 };
+use std::fmt::Display;
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum And {
     Byte(Register, Operand<Byte>),
     Quarter(Register, Operand<Quarter>),
     Half(Register, Operand<Half>),
     Word(Register, Operand<Word>),
 }
+
+impl Display for And {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            And::Byte(register, operand) => write!(f, "andb {register} {operand}"),
+            And::Quarter(register, operand) => write!(f, "andq {register} {operand}"),
+            And::Half(register, operand) => write!(f, "andh {register} {operand}"),
+            And::Word(register, operand) => write!(f, "andw {register} {operand}"),
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use crate::{constant::Byte, operand::Operand, register::Register};
+use std::fmt::Display;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum BitExtract {
+    Byte(Register, Operand<Byte>, Operand<Byte>),
+    Quarter(Register, Operand<Byte>, Operand<Byte>),
+    Half(Register, Operand<Byte>, Operand<Byte>),
+    Word(Register, Operand<Byte>, Operand<Byte>),
+}
+
+impl Display for BitExtract {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BitExtract::Byte(register, start, length) => {
+                write!(f, "bextb {register} {start} {length}")
+            }
+            BitExtract::Quarter(register, start, length) => {
+                write!(f, "bextq {register} {start} {length}")
+            }
+            BitExtract::Half(register, start, length) => {
+                write!(f, "bexth {register} {start} {length}")
+            }
+            BitExtract::Word(register, start, length) => {
+                write!(f, "bextw {register} {start} {length}")
+            }
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use crate::{
+    constant::{Byte, Half, Quarter, Word},
+    operand::Operand,
+    register::Register,
+};
+use std::fmt::Display;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum BitInsert {
+    Byte(Register, Operand<Byte>, Operand<Byte>, Operand<Byte>),
+    Quarter(Register, Operand<Quarter>, Operand<Byte>, Operand<Byte>),
+    Half(Register, Operand<Half>, Operand<Byte>, Operand<Byte>),
+    Word(Register, Operand<Word>, Operand<Byte>, Operand<Byte>),
+}
+
+impl Display for BitInsert {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BitInsert::Byte(register, operand, start, length) => {
+                write!(f, "binsb {register} {operand} {start} {length}")
+            }
+            BitInsert::Quarter(register, operand, start, length) => {
+                write!(f, "binsq {register} {operand} {start} {length}")
+            }
+            BitInsert::Half(register, operand, start, length) => {
+                write!(f, "binsh {register} {operand} {start} {length}")
+            }
+            BitInsert::Word(register, operand, start, length) => {
+                write!(f, "binsw {register} {operand} {start} {length}")
+            }
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use crate::{
+    constant::{Byte, Half, Quarter, Word},
+    operand::Operand,
+};
+use std::fmt::Display;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum BitTest {
+    Byte(Operand<Byte>, Operand<Byte>),
+    Quarter(Operand<Quarter>, Operand<Byte>),
+    Half(Operand<Half>, Operand<Byte>),
+    Word(Operand<Word>, Operand<Byte>),
+}
+
+impl Display for BitTest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BitTest::Byte(operand, index) => write!(f, "btb {operand} {index}"),
+            BitTest::Quarter(operand, index) => write!(f, "btq {operand} {index}"),
+            BitTest::Half(operand, index) => write!(f, "bth {operand} {index}"),
+            BitTest::Word(operand, index) => write!(f, "btw {operand} {index}"),
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use crate::{constant::Byte, operand::Operand, register::Register};
+use std::fmt::Display;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum BitTestAndComplement {
+    Byte(Register, Operand<Byte>),
+    Quarter(Register, Operand<Byte>),
+    Half(Register, Operand<Byte>),
+    Word(Register, Operand<Byte>),
+}
+
+impl Display for BitTestAndComplement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BitTestAndComplement::Byte(register, index) => write!(f, "btcb {register} {index}"),
+            BitTestAndComplement::Quarter(register, index) => write!(f, "btcq {register} {index}"),
+            BitTestAndComplement::Half(register, index) => write!(f, "btch {register} {index}"),
+            BitTestAndComplement::Word(register, index) => write!(f, "btcw {register} {index}"),
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use crate::{constant::Byte, operand::Operand, register::Register};
+use std::fmt::Display;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum BitTestAndReset {
+    Byte(Register, Operand<Byte>),
+    Quarter(Register, Operand<Byte>),
+    Half(Register, Operand<Byte>),
+    Word(Register, Operand<Byte>),
+}
+
+impl Display for BitTestAndReset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BitTestAndReset::Byte(register, index) => write!(f, "btrb {register} {index}"),
+            BitTestAndReset::Quarter(register, index) => write!(f, "btrq {register} {index}"),
+            BitTestAndReset::Half(register, index) => write!(f, "btrh {register} {index}"),
+            BitTestAndReset::Word(register, index) => write!(f, "btrw {register} {index}"),
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use crate::{constant::Byte, operand::Operand, register::Register};
+use std::fmt::Display;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum BitTestAndSet {
+    Byte(Register, Operand<Byte>),
+    Quarter(Register, Operand<Byte>),
+    Half(Register, Operand<Byte>),
+    Word(Register, Operand<Byte>),
+}
+
+impl Display for BitTestAndSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BitTestAndSet::Byte(register, index) => write!(f, "btsb {register} {index}"),
+            BitTestAndSet::Quarter(register, index) => write!(f, "btsq {register} {index}"),
+            BitTestAndSet::Half(register, index) => write!(f, "btsh {register} {index}"),
+            BitTestAndSet::Word(register, index) => write!(f, "btsw {register} {index}"),
+        }
+    }
+}
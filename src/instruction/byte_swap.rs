@@ -0,0 +1,21 @@
+use crate::register::Register;
+use std::fmt::Display;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ByteSwap {
+    Byte(Register),
+    Quarter(Register),
+    Half(Register),
+    Word(Register),
+}
+
+impl Display for ByteSwap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ByteSwap::Byte(register) => write!(f, "bswapb {register}"),
+            ByteSwap::Quarter(register) => write!(f, "bswapq {register}"),
+            ByteSwap::Half(register) => write!(f, "bswaph {register}"),
+            ByteSwap::Word(register) => write!(f, "bswapw {register}"),
+        }
+    }
+}
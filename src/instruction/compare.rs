@@ -2,11 +2,23 @@ use crate::{
     constant::{Byte, Half, Quarter, Word},
     operand::Operand,
 };
+use std::fmt::Display;
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum Compare {
     Byte(Operand<Byte>, Operand<Byte>),
     Quarter(Operand<Quarter>, Operand<Quarter>),
     Half(Operand<Half>, Operand<Half>),
     Word(Operand<Word>, Operand<Word>),
 }
+
+impl Display for Compare {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Compare::Byte(lhs, rhs) => write!(f, "cmpb {lhs} {rhs}"),
+            Compare::Quarter(lhs, rhs) => write!(f, "cmpq {lhs} {rhs}"),
+            Compare::Half(lhs, rhs) => write!(f, "cmph {lhs} {rhs}"),
+            Compare::Word(lhs, rhs) => write!(f, "cmpw {lhs} {rhs}"),
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use std::fmt::Display;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Discard {
+    Byte,
+    Quarter,
+    Half,
+    Word,
+}
+
+impl Display for Discard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Discard::Byte => write!(f, "dropb"),
+            Discard::Quarter => write!(f, "dropq"),
+            Discard::Half => write!(f, "droph"),
+            Discard::Word => write!(f, "dropw"),
+        }
+    }
+}
@@ -3,11 +3,23 @@ use crate::{
     operand::Operand,
     register::Register,
 };
+use std::fmt::Display;
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum Division {
     Byte(Register, Operand<Byte>),
     Quarter(Register, Operand<Quarter>),
     Half(Register, Operand<Half>),
     Word(Register, Operand<Word>),
 }
+
+impl Display for Division {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Division::Byte(register, operand) => write!(f, "divb {register} {operand}"),
+            Division::Quarter(register, operand) => write!(f, "divq {register} {operand}"),
+            Division::Half(register, operand) => write!(f, "divh {register} {operand}"),
+            Division::Word(register, operand) => write!(f, "divw {register} {operand}"),
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use crate::{
+    constant::{Byte, Half, Quarter, Word},
+    operand::Operand,
+    register::Register,
+};
+use std::fmt::Display;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum DivisionRemainder {
+    Byte(Register, Register, Operand<Byte>),
+    Quarter(Register, Register, Operand<Quarter>),
+    Half(Register, Register, Operand<Half>),
+    Word(Register, Register, Operand<Word>),
+}
+
+impl Display for DivisionRemainder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DivisionRemainder::Byte(quotient, remainder, operand) => {
+                write!(f, "divremb {quotient} {remainder} {operand}")
+            }
+            DivisionRemainder::Quarter(quotient, remainder, operand) => {
+                write!(f, "divremq {quotient} {remainder} {operand}")
+            }
+            DivisionRemainder::Half(quotient, remainder, operand) => {
+                write!(f, "divremh {quotient} {remainder} {operand}")
+            }
+            DivisionRemainder::Word(quotient, remainder, operand) => {
+                write!(f, "divremw {quotient} {remainder} {operand}")
+            }
+        }
+    }
+}
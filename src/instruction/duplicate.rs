@@ -0,0 +1,20 @@
+use std::fmt::Display;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Duplicate {
+    Byte,
+    Quarter,
+    Half,
+    Word,
+}
+
+impl Display for Duplicate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Duplicate::Byte => write!(f, "dupb"),
+            Duplicate::Quarter => write!(f, "dupq"),
+            Duplicate::Half => write!(f, "duph"),
+            Duplicate::Word => write!(f, "dupw"),
+        }
+    }
+}
@@ -1,4 +1,6 @@
-#[derive(Debug, PartialEq)]
+use std::fmt::Display;
+
+#[derive(Clone, Debug, PartialEq)]
 pub enum Jump {
     Unconditional,
     IfZero,
@@ -13,8 +15,49 @@ pub enum Jump {
     IfLesserOrEqual,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum Relative {
     Positive,
     Negative,
 }
+
+impl Jump {
+    /// Returns the suffix of the condition, as used in the mnemonics of conditional instructions.
+    ///
+    /// The suffix is empty for unconditional jumps, as only jumps have an unconditional variant.
+    pub fn suffix(&self) -> &'static str {
+        match self {
+            Jump::Unconditional => "",
+            Jump::IfZero => "iz",
+            Jump::IfNotZero => "nz",
+            Jump::IfSign => "is",
+            Jump::IfNotSign => "ns",
+            Jump::IfOverflow => "io",
+            Jump::IfNotOverflow => "no",
+            Jump::IfGreater => "ig",
+            Jump::IfLesser => "il",
+            Jump::IfGreaterOrEqual => "ge",
+            Jump::IfLesserOrEqual => "le",
+        }
+    }
+}
+
+/// Writes the mnemonic of the jump.
+impl Display for Jump {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Jump::Unconditional => write!(f, "jmp"),
+            condition => write!(f, "j{}", condition.suffix()),
+        }
+    }
+}
+
+/// Writes the prefix of a relative destination.
+impl Display for Relative {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Relative::Positive => write!(f, "+"),
+            Relative::Negative => write!(f, "-"),
+        }
+    }
+}
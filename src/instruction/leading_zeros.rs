@@ -0,0 +1,21 @@
+use crate::register::Register;
+use std::fmt::Display;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum LeadingZeros {
+    Byte(Register),
+    Quarter(Register),
+    Half(Register),
+    Word(Register),
+}
+
+impl Display for LeadingZeros {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LeadingZeros::Byte(register) => write!(f, "clzb {register}"),
+            LeadingZeros::Quarter(register) => write!(f, "clzq {register}"),
+            LeadingZeros::Half(register) => write!(f, "clzh {register}"),
+            LeadingZeros::Word(register) => write!(f, "clzw {register}"),
+        }
+    }
+}
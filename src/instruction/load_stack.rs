@@ -0,0 +1,21 @@
+use crate::{constant::Word, operand::Operand, register::Register};
+use std::fmt::Display;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum LoadStack {
+    Byte(Register, Operand<Word>),
+    Quarter(Register, Operand<Word>),
+    Half(Register, Operand<Word>),
+    Word(Register, Operand<Word>),
+}
+
+impl Display for LoadStack {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LoadStack::Byte(register, offset) => write!(f, "ldsb {register} {offset}"),
+            LoadStack::Quarter(register, offset) => write!(f, "ldsq {register} {offset}"),
+            LoadStack::Half(register, offset) => write!(f, "ldsh {register} {offset}"),
+            LoadStack::Word(register, offset) => write!(f, "ldsw {register} {offset}"),
+        }
+    }
+}
@@ -1,63 +1,131 @@
-use crate::{constant::Word, operand::Operand};
+use crate::{constant::Word, operand::Operand, register::Register};
+use std::fmt::Display;
 use variant_count::VariantCount;
 
 pub use addition::Addition;
 pub use and::And;
+pub use bit_extract::BitExtract;
+pub use bit_insert::BitInsert;
+pub use bit_test::BitTest;
+pub use bit_test_and_complement::BitTestAndComplement;
+pub use bit_test_and_reset::BitTestAndReset;
+pub use bit_test_and_set::BitTestAndSet;
+pub use byte_swap::ByteSwap;
 pub use compare::Compare;
+pub use discard::Discard;
 pub use division::Division;
+pub use division_remainder::DivisionRemainder;
+pub use duplicate::Duplicate;
 pub use jump::{Jump, Relative};
+pub use leading_zeros::LeadingZeros;
+pub use load_stack::LoadStack;
 pub use multiplication::Multiplication;
 pub use not::Not;
 pub use or::Or;
+pub use peek::Peek;
 pub use pop::Pop;
+pub use pop_count::PopCount;
 pub use print_register::PrintRegister;
 pub use print_stack::PrintStack;
+pub use print_value::{PrintFormat, PrintValue};
 pub use push::Push;
 pub use remainder::Remainder;
 pub use set::Set;
+pub use store_stack::StoreStack;
 pub use subtraction::Subtraction;
+pub use swap::Swap;
 pub use test::Test;
+pub use trailing_zeros::TrailingZeros;
+pub use widening_multiplication::WideningMultiplication;
 pub use xor::Xor;
 
 mod addition;
 mod and;
+mod bit_extract;
+mod bit_insert;
+mod bit_test;
+mod bit_test_and_complement;
+mod bit_test_and_reset;
+mod bit_test_and_set;
+mod byte_swap;
 mod compare;
+mod discard;
 mod division;
+mod division_remainder;
+mod duplicate;
 mod jump;
+mod leading_zeros;
+mod load_stack;
 mod multiplication;
 mod not;
 mod or;
+mod peek;
 mod pop;
+mod pop_count;
 mod print_register;
 mod print_stack;
+mod print_value;
 mod push;
 mod remainder;
 mod set;
+mod store_stack;
 mod subtraction;
+mod swap;
 mod test;
+mod trailing_zeros;
+mod usage;
+mod widening_multiplication;
 mod xor;
 
-#[derive(Debug, PartialEq, VariantCount)]
+#[derive(Clone, Debug, PartialEq, VariantCount)]
 pub enum Instruction {
     Addition(Addition),
     And(And),
+    BitExtract(BitExtract),
+    BitInsert(BitInsert),
+    BitTest(BitTest),
+    BitTestAndComplement(BitTestAndComplement),
+    BitTestAndReset(BitTestAndReset),
+    BitTestAndSet(BitTestAndSet),
+    ByteSwap(ByteSwap),
+    LeadingZeros(LeadingZeros),
+    PopCount(PopCount),
+    TrailingZeros(TrailingZeros),
     Call(Operand<Word>),
     Compare(Compare),
+    ConditionalMove(Jump, Set),
+    Discard(Discard),
     Division(Division),
+    DivisionRemainder(DivisionRemainder),
+    Duplicate(Duplicate),
+    GetProgramCounter(Register),
+    GetStackPointer(Register),
+    HostCall(String),
     Jump(Jump, Operand<Word>, Option<Relative>),
+    JumpTable(Register, Operand<Word>, Option<Relative>, Operand<Word>),
+    LoadStack(LoadStack),
     Multiplication(Multiplication),
     Not(Not),
     Or(Or),
+    Peek(Peek),
     Pop(Pop),
     PrintRegister(PrintRegister),
+    PrintNewline,
+    PrintSpace,
     PrintStack(PrintStack),
+    PrintValue(PrintValue),
     Push(Push),
     Remainder(Remainder),
     Return,
     Set(Set),
+    SetCondition(Jump, Register),
+    SetStackPointer(Operand<Word>),
     Stop,
+    StoreStack(StoreStack),
     Subtraction(Subtraction),
+    Swap(Swap),
     Test(Test),
+    WideningMultiplication(WideningMultiplication),
     Xor(Xor),
 }
 
@@ -67,11 +135,233 @@ impl Instruction {
         use Instruction::*;
 
         match self {
-            Addition(_) | And(_) | Compare(_) | Division(_) | Multiplication(_) | Not(_)
-            | Or(_) | Pop(_) | PrintRegister(_) | PrintStack(_) | Push(_) | Remainder(_)
-            | Set(_) | Subtraction(_) | Test(_) | Xor(_) => true,
+            Addition(_)
+            | And(_)
+            | BitExtract(_)
+            | BitInsert(_)
+            | BitTest(_)
+            | BitTestAndComplement(_)
+            | BitTestAndReset(_)
+            | BitTestAndSet(_)
+            | ByteSwap(_)
+            | Compare(_)
+            | ConditionalMove(_, _)
+            | Discard(_)
+            | Division(_)
+            | DivisionRemainder(_)
+            | Duplicate(_)
+            | GetProgramCounter(_)
+            | GetStackPointer(_)
+            | HostCall(_)
+            | LeadingZeros(_)
+            | LoadStack(_)
+            | Multiplication(_)
+            | Not(_)
+            | Or(_)
+            | Peek(_)
+            | PopCount(_)
+            | Pop(_)
+            | PrintRegister(_)
+            | PrintNewline
+            | PrintSpace
+            | PrintStack(_)
+            | PrintValue(_)
+            | Push(_)
+            | Remainder(_)
+            | Set(_)
+            | SetCondition(_, _)
+            | SetStackPointer(_)
+            | StoreStack(_)
+            | Subtraction(_)
+            | Swap(_)
+            | Test(_)
+            | TrailingZeros(_)
+            | WideningMultiplication(_)
+            | Xor(_) => true,
 
-            Call(_) | Jump(_, _, _) | Return | Stop => false,
+            Call(_) | Jump(_, _, _) | JumpTable(_, _, _, _) | Return | Stop => false,
+        }
+    }
+
+    /// Returns true if this instruction is a jump that depends on the flags.
+    pub fn is_conditional_jump(&self) -> bool {
+        matches!(self, Instruction::Jump(condition, _, _) if *condition != Jump::Unconditional)
+    }
+
+    /// Returns true if this instruction may transfer control to another line of the program.
+    pub fn transfers_control(&self) -> bool {
+        matches!(
+            self,
+            Instruction::Call(_)
+                | Instruction::Jump(_, _, _)
+                | Instruction::JumpTable(_, _, _, _)
+                | Instruction::Return
+        )
+    }
+}
+
+/// Writes the instruction as canonical source code, which decodes back into the same instruction.
+impl Display for Instruction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Instruction::Addition(instruction) => write!(f, "{instruction}"),
+            Instruction::And(instruction) => write!(f, "{instruction}"),
+            Instruction::BitExtract(instruction) => write!(f, "{instruction}"),
+            Instruction::BitInsert(instruction) => write!(f, "{instruction}"),
+            Instruction::BitTest(instruction) => write!(f, "{instruction}"),
+            Instruction::BitTestAndComplement(instruction) => write!(f, "{instruction}"),
+            Instruction::BitTestAndReset(instruction) => write!(f, "{instruction}"),
+            Instruction::BitTestAndSet(instruction) => write!(f, "{instruction}"),
+            Instruction::ByteSwap(instruction) => write!(f, "{instruction}"),
+            Instruction::LeadingZeros(instruction) => write!(f, "{instruction}"),
+            Instruction::PopCount(instruction) => write!(f, "{instruction}"),
+            Instruction::TrailingZeros(instruction) => write!(f, "{instruction}"),
+            Instruction::Call(destination) => write!(f, "call {destination}"),
+            Instruction::Compare(instruction) => write!(f, "{instruction}"),
+            Instruction::ConditionalMove(condition, set) => {
+                let condition = condition.suffix();
+                match set {
+                    Set::Byte(register, operand) => {
+                        write!(f, "cmov{condition}b {register} {operand}")
+                    }
+                    Set::Quarter(register, operand) => {
+                        write!(f, "cmov{condition}q {register} {operand}")
+                    }
+                    Set::Half(register, operand) => {
+                        write!(f, "cmov{condition}h {register} {operand}")
+                    }
+                    Set::Word(register, operand) => {
+                        write!(f, "cmov{condition}w {register} {operand}")
+                    }
+                }
+            }
+            Instruction::Discard(instruction) => write!(f, "{instruction}"),
+            Instruction::Division(instruction) => write!(f, "{instruction}"),
+            Instruction::DivisionRemainder(instruction) => write!(f, "{instruction}"),
+            Instruction::Duplicate(instruction) => write!(f, "{instruction}"),
+            Instruction::GetProgramCounter(register) => write!(f, "getpc {register}"),
+            Instruction::GetStackPointer(register) => write!(f, "getsp {register}"),
+            Instruction::HostCall(name) => write!(f, "sys {name}"),
+            Instruction::Jump(condition, destination, relative) => {
+                write!(f, "{condition} ")?;
+                if let Some(relative) = relative {
+                    write!(f, "{relative}")?;
+                }
+                write!(f, "{destination}")
+            }
+            Instruction::JumpTable(register, base, relative, count) => {
+                write!(f, "jtab {register} ")?;
+                if let Some(relative) = relative {
+                    write!(f, "{relative}")?;
+                }
+                write!(f, "{base} {count}")
+            }
+            Instruction::LoadStack(instruction) => write!(f, "{instruction}"),
+            Instruction::Multiplication(instruction) => write!(f, "{instruction}"),
+            Instruction::Not(instruction) => write!(f, "{instruction}"),
+            Instruction::Or(instruction) => write!(f, "{instruction}"),
+            Instruction::Peek(instruction) => write!(f, "{instruction}"),
+            Instruction::Pop(instruction) => write!(f, "{instruction}"),
+            Instruction::PrintRegister(instruction) => write!(f, "{instruction}"),
+            Instruction::PrintNewline => write!(f, "prnl"),
+            Instruction::PrintSpace => write!(f, "prsp"),
+            Instruction::PrintStack(instruction) => write!(f, "{instruction}"),
+            Instruction::PrintValue(instruction) => write!(f, "{instruction}"),
+            Instruction::Push(instruction) => write!(f, "{instruction}"),
+            Instruction::Remainder(instruction) => write!(f, "{instruction}"),
+            Instruction::Return => write!(f, "ret"),
+            Instruction::Set(instruction) => write!(f, "{instruction}"),
+            Instruction::SetCondition(condition, register) => {
+                write!(f, "set{} {register}", condition.suffix())
+            }
+            Instruction::SetStackPointer(pointer) => write!(f, "setsp {pointer}"),
+            Instruction::Stop => write!(f, "stop"),
+            Instruction::StoreStack(instruction) => write!(f, "{instruction}"),
+            Instruction::Subtraction(instruction) => write!(f, "{instruction}"),
+            Instruction::Swap(instruction) => write!(f, "{instruction}"),
+            Instruction::Test(instruction) => write!(f, "{instruction}"),
+            Instruction::WideningMultiplication(instruction) => write!(f, "{instruction}"),
+            Instruction::Xor(instruction) => write!(f, "{instruction}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod display {
+    use super::{Addition, Instruction};
+    use crate::{
+        decode::{decode, DECODE_TABLE},
+        error::DecodeError,
+        operand::Operand,
+        register::Register,
+    };
+
+    /// Parameters of each kind the instructions accept, combined to find parameters that decode for every mnemonic.
+    const PARAMETERS: [&str; 7] = ["rb", "0", "13", "+2", "-3", "x", "clock"];
+
+    /// Returns every combination of up to four parameters.
+    fn parameter_combinations() -> Vec<String> {
+        let mut combinations = vec![String::new()];
+        for _ in 0..4 {
+            let longer: Vec<String> = combinations
+                .iter()
+                .flat_map(|combination| {
+                    PARAMETERS
+                        .iter()
+                        .map(move |parameter| format!("{combination} {parameter}"))
+                })
+                .collect();
+            combinations.extend(longer);
+        }
+
+        combinations
+    }
+
+    #[test]
+    fn canonical_source() {
+        let instruction = Instruction::Addition(Addition::Byte(Register::A, Operand::Immediate(3)));
+
+        assert_eq!(instruction.to_string(), "addb ra 3");
+    }
+
+    #[test]
+    fn relative_jump() -> Result<(), DecodeError> {
+        let instruction = decode("jnz   -2")?;
+
+        assert_eq!(instruction.to_string(), "jnz -2");
+
+        Ok(())
+    }
+
+    #[test]
+    fn default_print_format_is_left_out() -> Result<(), DecodeError> {
+        assert_eq!(decode("prvq rb d")?.to_string(), "prvq rb");
+        assert_eq!(decode("prvq rb c")?.to_string(), "prvq rb c");
+
+        Ok(())
+    }
+
+    #[test]
+    fn round_trip() {
+        let combinations = parameter_combinations();
+
+        for mnemonic in DECODE_TABLE.keys() {
+            let mut decoded = 0;
+            for parameters in &combinations {
+                let Ok(instruction) = decode(&format!("{mnemonic}{parameters}")) else {
+                    continue;
+                };
+
+                let source = instruction.to_string();
+                assert_eq!(
+                    decode(&source).as_ref(),
+                    Ok(&instruction),
+                    "'{mnemonic}{parameters}' was written as '{source}'"
+                );
+                decoded += 1;
+            }
+
+            assert!(decoded > 0, "no parameters decoded for '{mnemonic}'");
         }
     }
 }
@@ -3,11 +3,23 @@ use crate::{
     operand::Operand,
     register::Register,
 };
+use std::fmt::Display;
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum Multiplication {
     Byte(Register, Operand<Byte>),
     Quarter(Register, Operand<Quarter>),
     Half(Register, Operand<Half>),
     Word(Register, Operand<Word>),
 }
+
+impl Display for Multiplication {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Multiplication::Byte(register, operand) => write!(f, "mulb {register} {operand}"),
+            Multiplication::Quarter(register, operand) => write!(f, "mulq {register} {operand}"),
+            Multiplication::Half(register, operand) => write!(f, "mulh {register} {operand}"),
+            Multiplication::Word(register, operand) => write!(f, "mulw {register} {operand}"),
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use crate::register::Register;
+use std::fmt::Display;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Peek {
+    Byte(Register),
+    Quarter(Register),
+    Half(Register),
+    Word(Register),
+}
+
+impl Display for Peek {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Peek::Byte(register) => write!(f, "peekb {register}"),
+            Peek::Quarter(register) => write!(f, "peekq {register}"),
+            Peek::Half(register) => write!(f, "peekh {register}"),
+            Peek::Word(register) => write!(f, "peekw {register}"),
+        }
+    }
+}
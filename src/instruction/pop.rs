@@ -1,9 +1,21 @@
 use crate::register::Register;
+use std::fmt::Display;
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum Pop {
     Byte(Register),
     Quarter(Register),
     Half(Register),
     Word(Register),
 }
+
+impl Display for Pop {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Pop::Byte(register) => write!(f, "popb {register}"),
+            Pop::Quarter(register) => write!(f, "popq {register}"),
+            Pop::Half(register) => write!(f, "poph {register}"),
+            Pop::Word(register) => write!(f, "popw {register}"),
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use crate::register::Register;
+use std::fmt::Display;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum PopCount {
+    Byte(Register),
+    Quarter(Register),
+    Half(Register),
+    Word(Register),
+}
+
+impl Display for PopCount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PopCount::Byte(register) => write!(f, "popcntb {register}"),
+            PopCount::Quarter(register) => write!(f, "popcntq {register}"),
+            PopCount::Half(register) => write!(f, "popcnth {register}"),
+            PopCount::Word(register) => write!(f, "popcntw {register}"),
+        }
+    }
+}
@@ -1,9 +1,21 @@
 use crate::register::Register;
+use std::fmt::Display;
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum PrintRegister {
     Byte(Register),
     Quarter(Register),
     Half(Register),
     Word(Register),
 }
+
+impl Display for PrintRegister {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PrintRegister::Byte(register) => write!(f, "prrb {register}"),
+            PrintRegister::Quarter(register) => write!(f, "prrq {register}"),
+            PrintRegister::Half(register) => write!(f, "prrh {register}"),
+            PrintRegister::Word(register) => write!(f, "prrw {register}"),
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use crate::{constant::Word, operand::Operand};
+use std::fmt::Display;
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum PrintStack {
     Byte(Operand<Word>),
     Quarter(Operand<Word>),
@@ -8,3 +9,15 @@ pub enum PrintStack {
     Word(Operand<Word>),
     Str(Operand<Word>),
 }
+
+impl Display for PrintStack {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PrintStack::Byte(amount) => write!(f, "prsb {amount}"),
+            PrintStack::Quarter(amount) => write!(f, "prsq {amount}"),
+            PrintStack::Half(amount) => write!(f, "prsh {amount}"),
+            PrintStack::Word(amount) => write!(f, "prsw {amount}"),
+            PrintStack::Str(amount) => write!(f, "prss {amount}"),
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use crate::{
+    constant::{Byte, Half, Quarter, Word},
+    operand::Operand,
+};
+use std::fmt::Display;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum PrintValue {
+    Byte(Operand<Byte>, PrintFormat),
+    Quarter(Operand<Quarter>, PrintFormat),
+    Half(Operand<Half>, PrintFormat),
+    Word(Operand<Word>, PrintFormat),
+}
+
+/// The format a value is printed in.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PrintFormat {
+    Decimal,
+    Signed,
+    Hexadecimal,
+    Binary,
+    Octal,
+    Character,
+}
+
+impl Display for PrintValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PrintValue::Byte(operand, format) => write!(f, "prvb {operand}{format}"),
+            PrintValue::Quarter(operand, format) => write!(f, "prvq {operand}{format}"),
+            PrintValue::Half(operand, format) => write!(f, "prvh {operand}{format}"),
+            PrintValue::Word(operand, format) => write!(f, "prvw {operand}{format}"),
+        }
+    }
+}
+
+/// Writes the format as the optional parameter of a print instruction, which is left out for the default decimal format.
+impl Display for PrintFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            PrintFormat::Decimal => return Ok(()),
+            PrintFormat::Signed => "s",
+            PrintFormat::Hexadecimal => "x",
+            PrintFormat::Binary => "b",
+            PrintFormat::Octal => "o",
+            PrintFormat::Character => "c",
+        };
+
+        write!(f, " {s}")
+    }
+}
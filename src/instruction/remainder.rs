@@ -3,11 +3,23 @@ use crate::{
     operand::Operand,
     register::Register,
 };
+use std::fmt::Display;
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum Remainder {
     Byte(Register, Operand<Byte>),
     Quarter(Register, Operand<Quarter>),
     Half(Register, Operand<Half>),
     Word(Register, Operand<Word>),
 }
+
+impl Display for Remainder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Remainder::Byte(register, operand) => write!(f, "remb {register} {operand}"),
+            Remainder::Quarter(register, operand) => write!(f, "remq {register} {operand}"),
+            Remainder::Half(register, operand) => write!(f, "remh {register} {operand}"),
+            Remainder::Word(register, operand) => write!(f, "remw {register} {operand}"),
+        }
+    }
+}
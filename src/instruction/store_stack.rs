@@ -0,0 +1,24 @@
+use crate::{
+    constant::{Byte, Half, Quarter, Word},
+    operand::Operand,
+};
+use std::fmt::Display;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum StoreStack {
+    Byte(Operand<Word>, Operand<Byte>),
+    Quarter(Operand<Word>, Operand<Quarter>),
+    Half(Operand<Word>, Operand<Half>),
+    Word(Operand<Word>, Operand<Word>),
+}
+
+impl Display for StoreStack {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StoreStack::Byte(offset, operand) => write!(f, "stsb {offset} {operand}"),
+            StoreStack::Quarter(offset, operand) => write!(f, "stsq {offset} {operand}"),
+            StoreStack::Half(offset, operand) => write!(f, "stsh {offset} {operand}"),
+            StoreStack::Word(offset, operand) => write!(f, "stsw {offset} {operand}"),
+        }
+    }
+}
@@ -3,11 +3,23 @@ use crate::{
     operand::Operand,
     register::Register,
 };
+use std::fmt::Display;
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum Subtraction {
     Byte(Register, Operand<Byte>),
     Quarter(Register, Operand<Quarter>),
     Half(Register, Operand<Half>),
     Word(Register, Operand<Word>),
 }
+
+impl Display for Subtraction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Subtraction::Byte(register, operand) => write!(f, "subb {register} {operand}"),
+            Subtraction::Quarter(register, operand) => write!(f, "subq {register} {operand}"),
+            Subtraction::Half(register, operand) => write!(f, "subh {register} {operand}"),
+            Subtraction::Word(register, operand) => write!(f, "subw {register} {operand}"),
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use std::fmt::Display;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Swap {
+    Byte,
+    Quarter,
+    Half,
+    Word,
+}
+
+impl Display for Swap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Swap::Byte => write!(f, "swapb"),
+            Swap::Quarter => write!(f, "swapq"),
+            Swap::Half => write!(f, "swaph"),
+            Swap::Word => write!(f, "swapw"),
+        }
+    }
+}
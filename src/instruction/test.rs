@@ -2,11 +2,23 @@ use crate::{
     constant::{Byte, Half, Quarter, Word},
     operand::Operand,
 };
+use std::fmt::Display;
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum Test {
     Byte(Operand<Byte>, Operand<Byte>),
     Quarter(Operand<Quarter>, Operand<Quarter>),
     Half(Operand<Half>, Operand<Half>),
     Word(Operand<Word>, Operand<Word>),
 }
+
+impl Display for Test {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Test::Byte(lhs, rhs) => write!(f, "tstb {lhs} {rhs}"),
+            Test::Quarter(lhs, rhs) => write!(f, "tstq {lhs} {rhs}"),
+            Test::Half(lhs, rhs) => write!(f, "tsth {lhs} {rhs}"),
+            Test::Word(lhs, rhs) => write!(f, "tstw {lhs} {rhs}"),
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use crate::register::Register;
+use std::fmt::Display;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum TrailingZeros {
+    Byte(Register),
+    Quarter(Register),
+    Half(Register),
+    Word(Register),
+}
+
+impl Display for TrailingZeros {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TrailingZeros::Byte(register) => write!(f, "ctzb {register}"),
+            TrailingZeros::Quarter(register) => write!(f, "ctzq {register}"),
+            TrailingZeros::Half(register) => write!(f, "ctzh {register}"),
+            TrailingZeros::Word(register) => write!(f, "ctzw {register}"),
+        }
+    }
+}
@@ -0,0 +1,359 @@
+use super::{
+    Addition, And, BitExtract, BitInsert, BitTest, BitTestAndComplement, BitTestAndReset,
+    BitTestAndSet, ByteSwap, Compare, Discard, Division, DivisionRemainder, Duplicate, Instruction,
+    LeadingZeros, LoadStack, Multiplication, Not, Or, Peek, Pop, PopCount, PrintRegister,
+    PrintStack, PrintValue, Push, Remainder, Set, StoreStack, Subtraction, Swap, Test,
+    TrailingZeros, WideningMultiplication, Xor,
+};
+use crate::{
+    constant::{Byte, Half, Quarter, Word},
+    operand::Operand,
+    register::Register,
+};
+
+/// The effect of an instruction on the stack, in bytes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StackEffect {
+    /// The amount of bytes that must be on the stack for the instruction to succeed.
+    pub required: Word,
+
+    /// The change of the stack pointer.
+    pub change: i64,
+}
+
+/// Returns the size in bytes of the value an instruction operates on.
+macro_rules! size {
+    ($value:expr, $name:ident) => {
+        match $value {
+            $name::Byte { .. } => size_of::<Byte>(),
+            $name::Quarter { .. } => size_of::<Quarter>(),
+            $name::Half { .. } => size_of::<Half>(),
+            $name::Word { .. } => size_of::<Word>(),
+        }
+    };
+}
+
+/// Matches every size variant of an instruction, evaluating the same expression for each of them.
+macro_rules! each_size {
+    ($value:expr, $name:ident($($parameter:ident),+) => $result:expr) => {
+        match $value {
+            $name::Byte($($parameter),+) => $result,
+            $name::Quarter($($parameter),+) => $result,
+            $name::Half($($parameter),+) => $result,
+            $name::Word($($parameter),+) => $result,
+        }
+    };
+}
+
+impl Instruction {
+    /// Returns the registers whose values are used by the instruction.
+    pub fn reads(&self) -> Vec<Register> {
+        let registers = match self {
+            Instruction::Addition(i) => {
+                each_size!(i, Addition(r, o) => vec![Some(*r), o.register()])
+            }
+            Instruction::And(i) => each_size!(i, And(r, o) => vec![Some(*r), o.register()]),
+            Instruction::BitExtract(i) => each_size!(i, BitExtract(r, s, l) => {
+                vec![Some(*r), s.register(), l.register()]
+            }),
+            Instruction::BitInsert(i) => each_size!(i, BitInsert(r, o, s, l) => {
+                vec![Some(*r), o.register(), s.register(), l.register()]
+            }),
+            Instruction::BitTest(i) => {
+                each_size!(i, BitTest(o, b) => vec![o.register(), b.register()])
+            }
+            Instruction::BitTestAndComplement(i) => {
+                each_size!(i, BitTestAndComplement(r, b) => vec![Some(*r), b.register()])
+            }
+            Instruction::BitTestAndReset(i) => {
+                each_size!(i, BitTestAndReset(r, b) => vec![Some(*r), b.register()])
+            }
+            Instruction::BitTestAndSet(i) => {
+                each_size!(i, BitTestAndSet(r, b) => vec![Some(*r), b.register()])
+            }
+            Instruction::ByteSwap(i) => each_size!(i, ByteSwap(r) => vec![Some(*r)]),
+            Instruction::LeadingZeros(i) => each_size!(i, LeadingZeros(r) => vec![Some(*r)]),
+            Instruction::PopCount(i) => each_size!(i, PopCount(r) => vec![Some(*r)]),
+            Instruction::TrailingZeros(i) => each_size!(i, TrailingZeros(r) => vec![Some(*r)]),
+            Instruction::Call(destination) => vec![destination.register()],
+            Instruction::Compare(i) => {
+                each_size!(i, Compare(a, b) => vec![a.register(), b.register()])
+            }
+            // the register keeps its value if the condition is not met, so it is only set conditionally
+            Instruction::ConditionalMove(_, set) => {
+                each_size!(set, Set(_r, o) => vec![o.register()])
+            }
+            Instruction::Division(i) => {
+                each_size!(i, Division(r, o) => vec![Some(*r), o.register()])
+            }
+            Instruction::DivisionRemainder(i) => {
+                each_size!(i, DivisionRemainder(q, _r, o) => vec![Some(*q), o.register()])
+            }
+            Instruction::Jump(_, destination, _) => vec![destination.register()],
+            Instruction::JumpTable(r, base, _, count) => {
+                vec![Some(*r), base.register(), count.register()]
+            }
+            Instruction::LoadStack(i) => each_size!(i, LoadStack(_r, o) => vec![o.register()]),
+            Instruction::Multiplication(i) => {
+                each_size!(i, Multiplication(r, o) => vec![Some(*r), o.register()])
+            }
+            Instruction::Not(i) => each_size!(i, Not(r) => vec![Some(*r)]),
+            Instruction::Or(i) => each_size!(i, Or(r, o) => vec![Some(*r), o.register()]),
+            Instruction::PrintRegister(i) => each_size!(i, PrintRegister(r) => vec![Some(*r)]),
+            Instruction::PrintStack(i) => match i {
+                PrintStack::Byte(o)
+                | PrintStack::Quarter(o)
+                | PrintStack::Half(o)
+                | PrintStack::Word(o)
+                | PrintStack::Str(o) => vec![o.register()],
+            },
+            Instruction::PrintValue(i) => each_size!(i, PrintValue(o, _f) => vec![o.register()]),
+            Instruction::Push(i) => each_size!(i, Push(o) => vec![o.register()]),
+            Instruction::Remainder(i) => {
+                each_size!(i, Remainder(r, o) => vec![Some(*r), o.register()])
+            }
+            Instruction::Set(i) => each_size!(i, Set(_r, o) => vec![o.register()]),
+            Instruction::SetStackPointer(pointer) => vec![pointer.register()],
+            Instruction::StoreStack(i) => {
+                each_size!(i, StoreStack(offset, o) => vec![offset.register(), o.register()])
+            }
+            Instruction::Subtraction(i) => {
+                each_size!(i, Subtraction(r, o) => vec![Some(*r), o.register()])
+            }
+            Instruction::Test(i) => each_size!(i, Test(a, b) => vec![a.register(), b.register()]),
+            Instruction::WideningMultiplication(i) => {
+                each_size!(i, WideningMultiplication(_h, l, o) => vec![Some(*l), o.register()])
+            }
+            Instruction::Xor(i) => each_size!(i, Xor(r, o) => vec![Some(*r), o.register()]),
+            Instruction::Discard(_)
+            | Instruction::Duplicate(_)
+            | Instruction::GetProgramCounter(_)
+            | Instruction::GetStackPointer(_)
+            | Instruction::HostCall(_)
+            | Instruction::Peek(_)
+            | Instruction::Pop(_)
+            | Instruction::PrintNewline
+            | Instruction::PrintSpace
+            | Instruction::Return
+            | Instruction::SetCondition(_, _)
+            | Instruction::Stop
+            | Instruction::Swap(_) => Vec::new(),
+        };
+
+        registers.into_iter().flatten().collect()
+    }
+
+    /// Returns the registers that are always set by the instruction.
+    ///
+    /// Host calls may set any register, and are therefore considered to set all of them.
+    pub fn writes(&self) -> Vec<Register> {
+        match self {
+            Instruction::Addition(i) => each_size!(i, Addition(r, _o) => vec![*r]),
+            Instruction::And(i) => each_size!(i, And(r, _o) => vec![*r]),
+            Instruction::BitExtract(i) => each_size!(i, BitExtract(r, _s, _l) => vec![*r]),
+            Instruction::BitInsert(i) => each_size!(i, BitInsert(r, _o, _s, _l) => vec![*r]),
+            Instruction::BitTestAndComplement(i) => {
+                each_size!(i, BitTestAndComplement(r, _b) => vec![*r])
+            }
+            Instruction::BitTestAndReset(i) => each_size!(i, BitTestAndReset(r, _b) => vec![*r]),
+            Instruction::BitTestAndSet(i) => each_size!(i, BitTestAndSet(r, _b) => vec![*r]),
+            Instruction::ByteSwap(i) => each_size!(i, ByteSwap(r) => vec![*r]),
+            Instruction::LeadingZeros(i) => each_size!(i, LeadingZeros(r) => vec![*r]),
+            Instruction::PopCount(i) => each_size!(i, PopCount(r) => vec![*r]),
+            Instruction::TrailingZeros(i) => each_size!(i, TrailingZeros(r) => vec![*r]),
+            Instruction::Division(i) => each_size!(i, Division(r, _o) => vec![*r]),
+            Instruction::DivisionRemainder(i) => {
+                each_size!(i, DivisionRemainder(q, r, _o) => vec![*q, *r])
+            }
+            Instruction::GetProgramCounter(r) | Instruction::GetStackPointer(r) => vec![*r],
+            Instruction::HostCall(_) => Register::ALL.to_vec(),
+            Instruction::LoadStack(i) => each_size!(i, LoadStack(r, _o) => vec![*r]),
+            Instruction::Multiplication(i) => each_size!(i, Multiplication(r, _o) => vec![*r]),
+            Instruction::Not(i) => each_size!(i, Not(r) => vec![*r]),
+            Instruction::Or(i) => each_size!(i, Or(r, _o) => vec![*r]),
+            Instruction::Peek(i) => each_size!(i, Peek(r) => vec![*r]),
+            Instruction::Pop(i) => each_size!(i, Pop(r) => vec![*r]),
+            Instruction::Remainder(i) => each_size!(i, Remainder(r, _o) => vec![*r]),
+            Instruction::Set(i) => each_size!(i, Set(r, _o) => vec![*r]),
+            Instruction::SetCondition(_, r) => vec![*r],
+            Instruction::Subtraction(i) => each_size!(i, Subtraction(r, _o) => vec![*r]),
+            Instruction::WideningMultiplication(i) => {
+                each_size!(i, WideningMultiplication(h, l, _o) => vec![*h, *l])
+            }
+            Instruction::Xor(i) => each_size!(i, Xor(r, _o) => vec![*r]),
+            Instruction::BitTest(_)
+            | Instruction::Call(_)
+            | Instruction::Compare(_)
+            | Instruction::ConditionalMove(_, _)
+            | Instruction::Discard(_)
+            | Instruction::Duplicate(_)
+            | Instruction::Jump(_, _, _)
+            | Instruction::JumpTable(_, _, _, _)
+            | Instruction::PrintRegister(_)
+            | Instruction::PrintNewline
+            | Instruction::PrintSpace
+            | Instruction::PrintStack(_)
+            | Instruction::PrintValue(_)
+            | Instruction::Push(_)
+            | Instruction::Return
+            | Instruction::SetStackPointer(_)
+            | Instruction::Stop
+            | Instruction::StoreStack(_)
+            | Instruction::Swap(_)
+            | Instruction::Test(_) => Vec::new(),
+        }
+    }
+
+    /// Returns true if the instruction divides by an immediate zero, which always fails.
+    pub fn divides_by_zero(&self) -> bool {
+        match self {
+            Instruction::Division(i) => each_size!(i, Division(_r, o) => o.is_zero()),
+            Instruction::DivisionRemainder(i) => {
+                each_size!(i, DivisionRemainder(_q, _r, o) => o.is_zero())
+            }
+            Instruction::Remainder(i) => each_size!(i, Remainder(_r, o) => o.is_zero()),
+            _ => false,
+        }
+    }
+
+    /// Returns the effect of the instruction on the stack, which is unknown if the stack pointer
+    /// is set directly or the instruction is a host call, as host functions may use the stack.
+    ///
+    /// Calls push the return address, which is popped by returns. Operands in registers are assumed
+    /// to only access the value on top of the stack.
+    pub fn stack_effect(&self) -> Option<StackEffect> {
+        let effect = |required: usize, change: i64| StackEffect {
+            required: required as Word,
+            change,
+        };
+        let immediate = |operand: &Operand<Word>| match operand {
+            Operand::Immediate(value) => *value,
+            Operand::Register(_) => 0,
+        };
+        let address = size_of::<Word>();
+
+        let effect = match self {
+            Instruction::Push(i) => {
+                let size = size!(i, Push);
+                effect(0, size as i64)
+            }
+            Instruction::Pop(i) => {
+                let size = size!(i, Pop);
+                effect(size, -(size as i64))
+            }
+            Instruction::Discard(i) => {
+                let size = size!(i, Discard);
+                effect(size, -(size as i64))
+            }
+            Instruction::Duplicate(i) => {
+                let size = size!(i, Duplicate);
+                effect(size, size as i64)
+            }
+            Instruction::Swap(i) => effect(2 * size!(i, Swap), 0),
+            Instruction::Peek(i) => effect(size!(i, Peek), 0),
+            Instruction::LoadStack(i) => {
+                let size = size!(i, LoadStack);
+                let offset = each_size!(i, LoadStack(_r, offset) => immediate(offset));
+                StackEffect {
+                    required: offset.saturating_add(size as Word),
+                    change: 0,
+                }
+            }
+            Instruction::StoreStack(i) => {
+                let size = size!(i, StoreStack);
+                let offset = each_size!(i, StoreStack(offset, _o) => immediate(offset));
+                StackEffect {
+                    required: offset.saturating_add(size as Word),
+                    change: 0,
+                }
+            }
+            Instruction::PrintStack(i) => {
+                let (size, amount) = match i {
+                    PrintStack::Byte(amount) | PrintStack::Str(amount) => {
+                        (size_of::<Byte>(), amount)
+                    }
+                    PrintStack::Quarter(amount) => (size_of::<Quarter>(), amount),
+                    PrintStack::Half(amount) => (size_of::<Half>(), amount),
+                    PrintStack::Word(amount) => (size_of::<Word>(), amount),
+                };
+                StackEffect {
+                    required: immediate(amount).saturating_mul(size as Word),
+                    change: 0,
+                }
+            }
+            Instruction::Call(_) => effect(0, address as i64),
+            Instruction::Return => effect(address, -(address as i64)),
+            Instruction::HostCall(_) | Instruction::SetStackPointer(_) => return None,
+            _ => effect(0, 0),
+        };
+
+        Some(effect)
+    }
+}
+
+#[cfg(test)]
+mod registers {
+    use crate::{decode::decode, error::DecodeError, register::Register};
+
+    #[test]
+    fn reads_and_writes() -> Result<(), DecodeError> {
+        let instruction = decode("divremq ra rb rc")?;
+
+        assert_eq!(instruction.reads(), [Register::A, Register::C]);
+        assert_eq!(instruction.writes(), [Register::A, Register::B]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn immediate_operands_are_not_read() -> Result<(), DecodeError> {
+        let instruction = decode("setb ra 5")?;
+
+        assert!(instruction.reads().is_empty());
+        assert_eq!(instruction.writes(), [Register::A]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn conditional_move_does_not_always_write() -> Result<(), DecodeError> {
+        let instruction = decode("cmovizb ra rb")?;
+
+        assert_eq!(instruction.reads(), [Register::B]);
+        assert!(instruction.writes().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn stack_effect() -> Result<(), DecodeError> {
+        let effect = |line: &str| -> Result<_, DecodeError> {
+            let effect = decode(line)?.stack_effect();
+            Ok(effect.map(|effect| (effect.required, effect.change)))
+        };
+
+        assert_eq!(effect("pshq 1")?, Some((0, 2)));
+        assert_eq!(effect("poph ra")?, Some((4, -4)));
+        assert_eq!(effect("dupw")?, Some((8, 8)));
+        assert_eq!(effect("swapb")?, Some((2, 0)));
+        assert_eq!(effect("ldsq ra 3")?, Some((5, 0)));
+        assert_eq!(effect("prsw 2")?, Some((16, 0)));
+        assert_eq!(effect("call 5")?, Some((0, 8)));
+        assert_eq!(effect("ret")?, Some((8, -8)));
+        assert_eq!(effect("addb ra 1")?, Some((0, 0)));
+        assert_eq!(effect("setsp 0")?, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn divides_by_zero() -> Result<(), DecodeError> {
+        assert!(decode("divb ra 0")?.divides_by_zero());
+        assert!(decode("remw ra 0")?.divides_by_zero());
+        assert!(decode("divremq ra rb 0")?.divides_by_zero());
+        assert!(!decode("divb ra rb")?.divides_by_zero());
+        assert!(!decode("divb ra 2")?.divides_by_zero());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,33 @@
+use crate::{
+    constant::{Byte, Half, Quarter, Word},
+    operand::Operand,
+    register::Register,
+};
+use std::fmt::Display;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum WideningMultiplication {
+    Byte(Register, Register, Operand<Byte>),
+    Quarter(Register, Register, Operand<Quarter>),
+    Half(Register, Register, Operand<Half>),
+    Word(Register, Register, Operand<Word>),
+}
+
+impl Display for WideningMultiplication {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WideningMultiplication::Byte(high, low, operand) => {
+                write!(f, "mulxb {high} {low} {operand}")
+            }
+            WideningMultiplication::Quarter(high, low, operand) => {
+                write!(f, "mulxq {high} {low} {operand}")
+            }
+            WideningMultiplication::Half(high, low, operand) => {
+                write!(f, "mulxh {high} {low} {operand}")
+            }
+            WideningMultiplication::Word(high, low, operand) => {
+                write!(f, "mulxw {high} {low} {operand}")
+            }
+        }
+    }
+}
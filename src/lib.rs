@@ -1,32 +1,70 @@
-use cli::parse_stack_size;
-pub use cli::Arguments;
+pub use builder::InterpreterBuilder;
+pub use bytecode::{assemble, is_bytecode};
+pub use call_stack::{Backtrace, CallFrame};
+pub use cfg::{Block, ControlFlowGraph, Dot, Edge, EdgeKind, Function, Json};
 use cli::Configuration;
-use constant::{Word, COMMENT, DEBUG_HELP, DEBUG_INITIAL};
+pub use cli::{
+    Arguments, AssembleArguments, CfgArguments, CfgFormat, Cli, Command, DebugArguments,
+    DisasmArguments, FmtArguments, LintArguments, ReplArguments, StackArguments,
+};
+use constant::{Word, CRASH_REPORT_HISTORY, TIMEOUT_CHECK_INTERVAL};
+pub use coverage::{AnnotatedCoverage, Coverage, Lcov};
+pub use crash_report::CrashReport;
+pub use disassembly::Disassembly;
 pub use error::ArgumentError;
+pub use error::BytecodeError;
+pub use error::ExecuteError;
 pub use error::InterpreterError;
-use flags::Flags;
+pub use error::SnapshotError;
+pub use flags::Flags;
+pub use format::format_source;
+use host::HostFunctions;
+pub use host::{HostContext, HostFunction};
 use instruction::Instruction;
-use preprocess::expand_data_section;
-use preprocess::expand_function_calls;
-use program::Program;
-use register::Register;
-use registers::Registers;
+pub use lint::{lint, Diagnostic, LintReport, Rule};
+pub use profile::{FoldedStacks, Profile, ProfileReport};
+use program::{Line, Program};
+pub use register::Register;
+use registers::{RegisterOperations, Registers};
+pub use repl::Repl;
 use stack::Stack;
-use std::io::stdin;
-
+pub use stack_depth::{Depth, StackAnalysis, StackDiagnostic, StackListing};
+use std::{
+    collections::{BTreeSet, VecDeque},
+    time::Instant,
+};
+pub use stepping::Status;
+pub use utils::{FromBytes, ToBytes};
+
+mod builder;
+mod bytecode;
+mod call_stack;
+mod cfg;
 mod cli;
 mod constant;
+mod coverage;
+mod crash_report;
+mod debugger;
 mod decode;
+mod disassembly;
 mod error;
 mod execute;
 mod flags;
+mod format;
+mod host;
 mod instruction;
+mod lint;
 mod operand;
 mod preprocess;
+mod profile;
 mod program;
 mod register;
 mod registers;
+mod repl;
+mod snapshot;
 mod stack;
+mod stack_depth;
+mod stepping;
 mod utils;
 
 pub struct Interpreter {
@@ -36,36 +74,75 @@ pub struct Interpreter {
     running: bool,
     stack: Stack,
     config: Configuration,
+    host_functions: HostFunctions,
+    program: Program,
+    breakpoints: BTreeSet<usize>,
+    /// The calls that have not yet returned, with the innermost call last.
+    call_stack: Vec<CallFrame>,
+    /// The most recently executed lines, with the latest at the back.
+    history: VecDeque<usize>,
 }
 
 impl Interpreter {
     pub fn try_new(args: Arguments) -> Result<Self, InterpreterError> {
-        let stack_size = parse_stack_size(&args.stack_size).map_err(InterpreterError::Argument)?;
-        let config = Configuration::try_from(args)?;
-
-        let p = Self {
-            registers: [0; Register::VARIANT_COUNT],
-            program_counter: 1,
-            flags: Flags::new(),
-            running: true,
-            stack: Stack::new(stack_size),
-            config,
-        };
-        Ok(p)
+        InterpreterBuilder::try_from(args).map(InterpreterBuilder::build)
     }
 
     #[cfg(test)]
     pub fn new_test() -> Self {
         use constant::TEST_STACK_SIZE;
 
-        Self {
-            registers: [0; Register::VARIANT_COUNT],
-            program_counter: 1,
-            flags: Flags::new(),
-            running: true,
-            stack: Stack::new(TEST_STACK_SIZE),
-            config: Configuration::new_test(),
-        }
+        Self::builder()
+            .stack_size(TEST_STACK_SIZE)
+            .writer(Box::new(Vec::new()))
+            .input(std::io::empty())
+            .debug_writer(Box::new(Vec::new()))
+            .build()
+    }
+
+    /// Creates a builder for an interpreter, which does not require command line arguments.
+    pub fn builder() -> InterpreterBuilder {
+        InterpreterBuilder::new()
+    }
+
+    /// Registers a host function, which programs can call by name with the `sys` instruction.
+    ///
+    /// Registering a function with the name of an already registered function replaces it.
+    pub fn register_host_function<F>(&mut self, name: impl Into<String>, function: F)
+    where
+        F: FnMut(&mut HostContext) -> Result<(), ExecuteError> + 'static,
+    {
+        self.host_functions.insert(name.into(), Box::new(function));
+    }
+
+    /// Gets the full value of a register.
+    pub fn register(&self, register: Register) -> Word {
+        self.registers.get(register)
+    }
+
+    /// Gets the current state of the flags.
+    pub fn flags(&self) -> &Flags {
+        &self.flags
+    }
+
+    /// Gets the bytes currently on the stack, from the bottom of the stack up to the stack pointer.
+    pub fn stack(&self) -> &[u8] {
+        self.stack.contents()
+    }
+
+    /// Gets the stack pointer, which is the amount of bytes currently on the stack.
+    pub fn stack_pointer(&self) -> usize {
+        self.stack.sp()
+    }
+
+    /// Gets the line of the next instruction to be executed.
+    pub fn program_counter(&self) -> Word {
+        self.program_counter
+    }
+
+    /// Gets the amount of instructions executed so far.
+    pub fn instructions_executed(&self) -> u64 {
+        self.config.instructions_executed
     }
 
     /// Gets the program counter as a usize.
@@ -73,70 +150,112 @@ impl Interpreter {
         self.program_counter as usize
     }
 
+    /// Loads the source code as the program and runs it until it stops or an error occurs.
+    ///
+    /// The program is interpreted in debug mode if the interpreter is configured to do so.
     pub fn run(&mut self, source_code: &str) -> Result<(), InterpreterError> {
-        let data_expanded_source_code =
-            expand_data_section(source_code).map_err(InterpreterError::PreProcess)?;
-        let final_source_code = expand_function_calls(data_expanded_source_code)
-            .map_err(InterpreterError::PreProcess)?;
-        let program = Program::new(final_source_code);
+        self.load(source_code)?;
+        self.run_loaded()
+    }
 
+    /// Runs the loaded program from the current state until it stops or an error occurs.
+    ///
+    /// This allows resuming a program after restoring a snapshot of it.
+    pub fn run_loaded(&mut self) -> Result<(), InterpreterError> {
         if self.config.debug {
-            self.debug(program)?;
+            self.debug()?;
         } else {
-            self.full(program)?;
+            self.full()?;
         }
 
         Ok(())
     }
 
-    fn advance(&mut self, program: &Program) -> Result<(), InterpreterError> {
-        let line = program.get(self.pc())?;
+    fn advance(&mut self) -> Result<(), InterpreterError> {
+        let line_number = self.pc();
+        self.check_limits()?;
 
-        if line.starts_with(COMMENT) {
-            self.program_counter += 1;
-            return Ok(());
+        let instruction = match self.program.line(line_number)? {
+            Line::Comment => {
+                self.program_counter += 1;
+                return Ok(());
+            }
+            Line::Source(line) => decode::decode(line),
+            Line::Decoded(instruction) => Ok(instruction.clone()),
+        };
+
+        if self.history.len() == CRASH_REPORT_HISTORY {
+            self.history.pop_front();
         }
+        self.history.push_back(line_number);
 
-        let instruction =
-            decode::decode(line).map_err(|e| InterpreterError::Decode(self.pc(), e))?;
+        if let Some(profile) = &mut self.config.profile {
+            let line = self.program.get(line_number)?;
+            profile.record(line_number, line, &self.call_stack);
+        }
+
+        let instruction = instruction.map_err(|e| InterpreterError::Decode(line_number, e))?;
+
+        let jump_taken = match &instruction {
+            Instruction::Jump(condition, _, _) if instruction.is_conditional_jump() => {
+                Some(self.condition(condition))
+            }
+            _ => None,
+        };
+        if let Some(coverage) = &mut self.config.coverage {
+            coverage.record(line_number, jump_taken);
+        }
 
-        self.execute(instruction)
-            .map_err(|e| InterpreterError::Execute(self.pc(), e))?;
+        let transferred = self
+            .execute_transfer(instruction)
+            .map_err(|e| InterpreterError::Execute(line_number, e))?;
+
+        // conditional jumps that are not taken continue on the following line like any other instruction,
+        // while every transfer that is taken has to land inside the program
+        let destination = self.program_counter;
+        if transferred && !self.program.contains(destination) {
+            let err = ExecuteError::JumpOutOfBounds(destination, self.program.len());
+            return Err(InterpreterError::Execute(line_number, err));
+        }
 
         Ok(())
     }
 
-    fn full(&mut self, program: Program) -> Result<(), InterpreterError> {
-        while self.running {
-            self.advance(&program)?;
+    /// Returns an error if the program has reached the maximum amount of instructions, or has timed out.
+    ///
+    /// The timeout is measured from the first line executed after the program was loaded.
+    fn check_limits(&mut self) -> Result<(), InterpreterError> {
+        let executed = self.config.instructions_executed;
+        if self
+            .config
+            .max_instructions
+            .is_some_and(|max| executed >= max)
+        {
+            return Err(InterpreterError::InstructionLimitReached(
+                self.pc(),
+                executed,
+            ));
+        }
+
+        let Some(timeout) = self.config.timeout else {
+            return Ok(());
+        };
+        let deadline = *self
+            .config
+            .deadline
+            .get_or_insert_with(|| Instant::now() + timeout);
+
+        // reading the clock is comparatively expensive, so it is only done periodically
+        if executed.is_multiple_of(TIMEOUT_CHECK_INTERVAL) && Instant::now() >= deadline {
+            return Err(InterpreterError::Timeout(self.pc(), executed));
         }
 
         Ok(())
     }
 
-    fn debug(&mut self, program: Program) -> Result<(), InterpreterError> {
-        println!("{DEBUG_INITIAL}");
-
+    fn full(&mut self) -> Result<(), InterpreterError> {
         while self.running {
-            let mut action = String::new();
-            stdin().read_line(&mut action).unwrap();
-
-            match action.trim() {
-                "help" | "h" => {
-                    println!("{DEBUG_HELP}");
-                }
-                "next" | "n" => {
-                    self.advance(&program)?;
-                }
-                "stop" | "s" => {
-                    self.execute(Instruction::Stop)
-                        .map_err(|err| InterpreterError::Execute(self.pc(), err))?;
-                    break;
-                }
-                unknown => println!("unknown action: '{unknown}'"),
-            }
-
-            println!("pc: {}, sp: {}", self.pc(), self.stack.sp());
+            self.advance()?;
         }
 
         Ok(())
@@ -146,7 +265,7 @@ impl Interpreter {
 #[cfg(test)]
 mod integration {
     use crate::{
-        constant::{Byte, Word, COMMENT},
+        constant::{Byte, Quarter, Word, COMMENT},
         error::{DecodeError, ExecuteError, InterpreterError},
         register::Register,
         registers::RegisterOperations,
@@ -305,5 +424,273 @@ mod integration {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn function_with_local_variable_on_stack() -> Result<(), InterpreterError> {
+        let mut i = Interpreter::new_test();
+        let program = [
+            "pshb 5",
+            "call 5",
+            "popb ra",
+            "stop",
+            "getsp rh",
+            "pshb 0",
+            "ldsb rb 9",
+            "addb rb 10",
+            "stsb 0 rb",
+            "peekb rc",
+            "stsb 9 rc",
+            "setsp rh",
+            "ret",
+        ]
+        .join("\n");
+
+        i.run(&program)?;
+
+        assert_eq!(i.registers.get::<Byte>(Register::A), 15);
+        assert_eq!(i.registers.get::<Word>(Register::H), 9);
+        assert_eq!(i.stack.sp(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn jump_table_selects_entry() -> Result<(), InterpreterError> {
+        let mut i = Interpreter::new_test();
+        let program = [
+            "setw ra 2",
+            "jtab ra +1 3",
+            "jmp +3",
+            "jmp +4",
+            "jmp +5",
+            "setb rb 1",
+            "stop",
+            "setb rb 2",
+            "stop",
+            "setb rb 3",
+            "stop",
+        ]
+        .join("\n");
+
+        i.run(&program)?;
+
+        assert_eq!(i.registers.get::<Byte>(Register::B), 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn jump_past_last_line_out_of_bounds() {
+        let mut i = Interpreter::new_test();
+        let program = ["setb ra 1", "jmp 3"].join("\n");
+        let expected = Err(InterpreterError::Execute(
+            2,
+            ExecuteError::JumpOutOfBounds(3, 2),
+        ));
+
+        let actual = i.run(&program);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn computed_jump_out_of_bounds() {
+        let mut i = Interpreter::new_test();
+        let program = ["getpc ra", "addw ra 10", "jmp ra", "stop"].join("\n");
+        let expected = Err(InterpreterError::Execute(
+            3,
+            ExecuteError::JumpOutOfBounds(11, 4),
+        ));
+
+        let actual = i.run(&program);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn conditional_jump_not_taken_on_last_line() {
+        let mut i = Interpreter::new_test();
+        let program = ["cmpb 1 0", "jiz 1"].join("\n");
+        let expected = Err(InterpreterError::InvalidProgramCounter(3));
+
+        let actual = i.run(&program);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn maximum_and_comparison_result_without_jumps() -> Result<(), InterpreterError> {
+        let mut i = Interpreter::new_test();
+        let program = [
+            "setb ra 7",
+            "setb rb 42",
+            "cmpb ra rb",
+            "setil rc",
+            "cmovilb ra rb",
+            "stop",
+        ]
+        .join("\n");
+
+        i.run(&program)?;
+
+        assert_eq!(i.registers.get::<Byte>(Register::A), 42);
+        assert_eq!(i.registers.get::<Word>(Register::C), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn pack_and_unpack_bit_fields() -> Result<(), InterpreterError> {
+        let mut i = Interpreter::new_test();
+        let program = [
+            "setq ra 0",
+            "binsq ra 5 0 4",
+            "binsq ra 9 4 4",
+            "btsq ra 15",
+            "setq rb ra",
+            "bextq rb 4 4",
+            "popcntq ra",
+            "btq ra 0",
+            "stop",
+        ]
+        .join("\n");
+
+        i.run(&program)?;
+
+        // 0b1000_0000_1001_0101 has five set bits, which leaves bit 0 set
+        assert_eq!(i.registers.get::<Quarter>(Register::A), 5);
+        assert_eq!(i.registers.get::<Quarter>(Register::B), 9);
+        assert!(!i.flags.zero);
+
+        Ok(())
+    }
+
+    #[test]
+    fn convert_byte_product_to_decimal_digits() -> Result<(), InterpreterError> {
+        let mut i = Interpreter::new_test();
+        let program = [
+            "setb rb 200",
+            "mulxb ra rb 3",
+            "divremb rb rc 10",
+            "divremb rb rd 10",
+            "stop",
+        ]
+        .join("\n");
+
+        i.run(&program)?;
+
+        // 200 * 3 = 600 = 2 * 256 + 88
+        assert_eq!(i.registers.get::<Byte>(Register::A), 2);
+        assert_eq!(i.registers.get::<Byte>(Register::B), 0);
+        assert_eq!(i.registers.get::<Byte>(Register::C), 8);
+        assert_eq!(i.registers.get::<Byte>(Register::D), 8);
+        assert!(i.flags.zero);
+
+        Ok(())
+    }
+
+    #[test]
+    fn print_values_in_several_formats() -> Result<(), InterpreterError> {
+        let mut i = Interpreter::new_test();
+        let program = [
+            "setb ra 255",
+            "prvb ra",
+            "prsp",
+            "prvb ra s",
+            "prsp",
+            "prvb ra x",
+            "prsp",
+            "prvq 5 b",
+            "prnl",
+            "prvb 72 c",
+            "prvb 105 c",
+            "prnl",
+            "stop",
+        ]
+        .join("\n");
+        let expected = "255 -1 ff 101\nHi\n";
+
+        i.run(&program)?;
+        let actual = i
+            .config
+            .output
+            .get_buffer()
+            .expect("interpreter test instance should return buffer");
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn host_function_reads_arguments_from_stack() {
+        let mut i = Interpreter::new_test();
+        i.register_host_function("sum", |context| {
+            let a: Word = context.pop()?;
+            let b: Word = context.pop()?;
+            context.set_register(Register::A, a + b);
+            Ok(())
+        });
+        let program = ["pshw 40", "pshw 2", "sys sum", "ecall sum", "stop"].join("\n");
+        let expected = Err(InterpreterError::Execute(4, ExecuteError::StackUnderflow));
+
+        let actual = i.run(&program);
+
+        assert_eq!(actual, expected);
+        assert_eq!(i.registers.get::<Word>(Register::A), 42);
+    }
+
+    #[test]
+    fn functions_with_same_local_labels() -> Result<(), InterpreterError> {
+        let mut i = Interpreter::new_test();
+        let program = [
+            "setb ra 3",
+            "call count_down",
+            "call count_up",
+            "stop",
+            "fn count_down:",
+            "  .loop:",
+            "  subb ra 1",
+            "  addb rb 1",
+            "  cmpb ra 0",
+            "  jnz .loop",
+            "  ret",
+            "fn count_up:",
+            "  .loop:",
+            "  addb ra 1",
+            "  cmpb ra 5",
+            "  jnz .loop",
+            "  ret",
+        ]
+        .join("\n");
+
+        i.run(&program)?;
+
+        assert_eq!(i.registers.get::<Byte>(Register::A), 5);
+        assert_eq!(i.registers.get::<Byte>(Register::B), 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn typed_constants() -> Result<(), InterpreterError> {
+        let mut i = Interpreter::new_test();
+        let program = [
+            "const LIMIT: byte = 200",
+            "const STEP: byte = 50",
+            "setw ra 0",
+            "addb ra STEP",
+            "cmpb ra LIMIT",
+            "jnz 4",
+            "stop",
+        ]
+        .join("\n");
+
+        i.run(&program)?;
+
+        assert_eq!(i.registers.get::<Word>(Register::A), 200);
+
+        Ok(())
+    }
+
     // something to do with calling functions
 }
@@ -0,0 +1,921 @@
+//! Static analysis of source code, which finds common mistakes without running the program.
+//!
+//! The program is analysed after preprocessing, such that line numbers are the same as when it is executed.
+//! Control flow is followed through the blocks of its control flow graph from the first line, where calls
+//! continue on the following line only if the called function can return. Jumps to a register cannot be
+//! followed, so the lines they reach are unknown.
+
+use crate::{
+    cfg::{destinations, Block, ControlFlowGraph, EdgeKind},
+    constant::{Word, COMMENT},
+    disassembly::destination_line,
+    error::InterpreterError,
+    instruction::Instruction,
+    operand::Operand,
+    preprocess::{preprocess, DATA_SECTION},
+    program::{DecodedLine, Functions, Program},
+    register::Register,
+};
+use clap::ValueEnum;
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    fmt::Display,
+};
+
+/// A kind of mistake that is found by the linter, which can be disabled by its name.
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+pub enum Rule {
+    /// Instructions that can never be executed, such as those following a `stop`, `jmp` or `ret`.
+    UnreachableCode,
+    /// Functions that can continue into the header of the next function, which fails to decode.
+    FallThrough,
+    /// Jumps to an immediate destination outside of the program.
+    JumpOutOfBounds,
+    /// Calls to functions that never return.
+    MissingReturn,
+    /// Keys of the data section that are never used.
+    UnusedData,
+    /// Registers that may be read before they are set.
+    UninitializedRegister,
+    /// Divisions and remainders by an immediate zero.
+    DivisionByZero,
+}
+
+impl Display for Rule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Rule::UnreachableCode => "unreachable-code",
+            Rule::FallThrough => "fall-through",
+            Rule::JumpOutOfBounds => "jump-out-of-bounds",
+            Rule::MissingReturn => "missing-return",
+            Rule::UnusedData => "unused-data",
+            Rule::UninitializedRegister => "uninitialized-register",
+            Rule::DivisionByZero => "division-by-zero",
+        };
+
+        write!(f, "{s}")
+    }
+}
+
+/// A mistake found on a line of the source code.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Diagnostic {
+    pub line: usize,
+    pub rule: Rule,
+    pub message: String,
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}: {} [{}]", self.line, self.message, self.rule)
+    }
+}
+
+/// The mistakes found in a program, ordered by line.
+pub struct LintReport {
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl LintReport {
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        &self.diagnostics
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.diagnostics.is_empty()
+    }
+}
+
+impl Display for LintReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for diagnostic in &self.diagnostics {
+            writeln!(f, "{diagnostic}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Finds common mistakes in the source code of a program, apart from those of the disabled rules.
+///
+/// # Errors
+/// Returns an error if the source code could not be preprocessed.
+pub fn lint(source_code: &str, disabled: &[Rule]) -> Result<LintReport, InterpreterError> {
+    let (lines, functions) = preprocess(source_code).map_err(InterpreterError::PreProcess)?;
+    let analysis = Analysis::new(&Program::new(lines, functions));
+
+    let mut diagnostics = Vec::new();
+    for rule in Rule::value_variants() {
+        if disabled.contains(rule) {
+            continue;
+        }
+
+        let found = match rule {
+            Rule::UnreachableCode => analysis.unreachable_code(),
+            Rule::FallThrough => analysis.fall_through(),
+            Rule::JumpOutOfBounds => analysis.jumps_out_of_bounds(),
+            Rule::MissingReturn => analysis.missing_returns(),
+            Rule::UnusedData => unused_data(source_code),
+            Rule::UninitializedRegister => analysis.uninitialized_registers(),
+            Rule::DivisionByZero => analysis.divisions_by_zero(),
+        };
+        diagnostics.extend(found);
+    }
+    diagnostics.sort_by_key(|diagnostic| diagnostic.line);
+
+    Ok(LintReport { diagnostics })
+}
+
+/// The blocks that may be executed after a block.
+enum Successors {
+    Known(Vec<usize>),
+    /// The block transfers control to a destination in a register.
+    Unknown,
+}
+
+struct Analysis {
+    graph: ControlFlowGraph,
+    functions: Functions,
+    /// Whether each called function can return, by the block it starts with.
+    returns: HashMap<usize, bool>,
+}
+
+impl Analysis {
+    fn new(program: &Program) -> Self {
+        let mut analysis = Self {
+            graph: ControlFlowGraph::new(program),
+            functions: program.functions().clone(),
+            returns: HashMap::new(),
+        };
+        analysis.compute_returns();
+
+        analysis
+    }
+
+    fn line(&self, line: usize) -> Option<&DecodedLine> {
+        line.checked_sub(1)
+            .and_then(|index| self.graph.lines().get(index))
+    }
+
+    fn instructions(&self) -> impl Iterator<Item = (usize, &Instruction)> {
+        self.graph.blocks().iter().flat_map(|block| {
+            block
+                .instructions
+                .iter()
+                .map(|(line, instruction)| (*line, instruction))
+        })
+    }
+
+    fn contains(&self, line: Word) -> bool {
+        line != 0 && line as usize <= self.graph.lines().len()
+    }
+
+    /// Returns the function that starts on the line, or the line itself if it is not the start of one.
+    fn function_name(&self, line: usize) -> String {
+        match self.functions.get(&line) {
+            Some(name) => format!("`{name}`"),
+            None => format!("on line {line}"),
+        }
+    }
+
+    /// Returns the block called by the last instruction of the block, if it is a call to a line of the program.
+    fn callee(&self, block: &Block) -> Option<usize> {
+        match block.instructions.last() {
+            Some((_, Instruction::Call(Operand::Immediate(destination)))) => {
+                self.graph.block_at(*destination as usize)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns true if the called function starting with the block can return.
+    fn can_return(&self, callee: usize) -> bool {
+        self.returns.get(&callee).copied().unwrap_or_default()
+    }
+
+    fn ends_with_return(&self, block: usize) -> bool {
+        matches!(
+            self.graph
+                .blocks()
+                .get(block)
+                .and_then(|block| block.instructions.last()),
+            Some((_, Instruction::Return))
+        )
+    }
+
+    /// Returns the blocks that may be executed after the block within the same function,
+    /// where calls continue on the following line if the called function can return.
+    fn successors(&self, index: usize) -> Successors {
+        let Some(block) = self.graph.blocks().get(index) else {
+            return Successors::Known(Vec::new());
+        };
+        if block.has_unknown_destination() {
+            return Successors::Unknown;
+        }
+
+        let mut successors: Vec<usize> = self
+            .graph
+            .edges()
+            .iter()
+            .filter(|edge| edge.from == index)
+            .filter(|edge| !matches!(edge.kind, EdgeKind::Call | EdgeKind::Return))
+            .map(|edge| edge.to)
+            .collect();
+        if self
+            .callee(block)
+            .is_some_and(|callee| self.can_return(callee))
+        {
+            successors.extend(self.graph.block_at(block.end() + 1));
+        }
+
+        Successors::Known(successors)
+    }
+
+    /// Returns the blocks reachable from the entry, and whether any of them has unknown successors.
+    ///
+    /// Calls are followed into the called functions if `follow_calls` is set.
+    fn reachable(&self, entry: usize, follow_calls: bool) -> (BTreeSet<usize>, bool) {
+        let mut reached = BTreeSet::new();
+        let mut unknown = false;
+        let mut pending = vec![entry];
+
+        while let Some(index) = pending.pop() {
+            if !reached.insert(index) {
+                continue;
+            }
+
+            match self.successors(index) {
+                Successors::Known(successors) => pending.extend(successors),
+                Successors::Unknown => unknown = true,
+            }
+
+            let callee = self
+                .graph
+                .blocks()
+                .get(index)
+                .and_then(|block| self.callee(block));
+            if let Some(callee) = callee.filter(|_| follow_calls) {
+                pending.push(callee);
+            }
+        }
+
+        (reached, unknown)
+    }
+
+    /// Determines which of the called functions can return, which depends on the functions they call.
+    fn compute_returns(&mut self) {
+        let callees: BTreeSet<usize> = self
+            .graph
+            .blocks()
+            .iter()
+            .filter_map(|block| self.callee(block))
+            .collect();
+        self.returns = callees.iter().map(|&callee| (callee, false)).collect();
+
+        // a function returning can only cause other functions to return, so this converges
+        loop {
+            let returns = callees
+                .iter()
+                .map(|&callee| {
+                    let (reached, unknown) = self.reachable(callee, false);
+                    let can_return =
+                        unknown || reached.iter().any(|&block| self.ends_with_return(block));
+                    (callee, can_return)
+                })
+                .collect();
+
+            if returns == self.returns {
+                return;
+            }
+            self.returns = returns;
+        }
+    }
+
+    fn unreachable_code(&self) -> Vec<Diagnostic> {
+        let (reached, unknown) = match self.graph.block_at(1) {
+            Some(entry) => self.reachable(entry, true),
+            None => (BTreeSet::new(), false),
+        };
+        // any line may be reached by a jump to a register
+        if unknown {
+            return Vec::new();
+        }
+        let reached: BTreeSet<usize> = reached
+            .iter()
+            .filter_map(|&index| self.graph.blocks().get(index))
+            .flat_map(|block| block.instructions.iter().map(|(line, _)| *line))
+            .collect();
+
+        let mut diagnostics = Vec::new();
+        let mut run: Option<(usize, usize)> = None;
+        for (index, line) in self.graph.lines().iter().enumerate() {
+            let line_number = index + 1;
+            let unreachable = match line {
+                DecodedLine::Instruction(_) => !reached.contains(&line_number),
+                DecodedLine::Comment => continue,
+                DecodedLine::Invalid(source) if is_blank(source) => continue,
+                DecodedLine::Invalid(_) => false,
+            };
+
+            match (&mut run, unreachable) {
+                (Some((_, last)), true) => *last = line_number,
+                (None, true) => run = Some((line_number, line_number)),
+                (_, false) => diagnostics.extend(run.take().map(unreachable_diagnostic)),
+            }
+        }
+        diagnostics.extend(run.map(unreachable_diagnostic));
+
+        diagnostics
+    }
+
+    fn fall_through(&self) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+
+        for (&start, name) in &self.functions {
+            let header = start - 1;
+            let previous = (1..header).rev().find(|&line| match self.line(line) {
+                Some(DecodedLine::Comment) => false,
+                Some(DecodedLine::Invalid(source)) => !is_blank(source),
+                _ => true,
+            });
+            let Some((previous, Some(DecodedLine::Instruction(instruction)))) =
+                previous.map(|previous| (previous, self.line(previous)))
+            else {
+                continue;
+            };
+
+            // the header cannot be decoded, so it has no block to find the edges to it by
+            let falls_through = match instruction {
+                Instruction::Call(Operand::Immediate(destination)) => self
+                    .graph
+                    .block_at(*destination as usize)
+                    .is_some_and(|callee| self.can_return(callee)),
+                _ => destinations(self.graph.lines(), previous, instruction)
+                    .iter()
+                    .any(|(destination, _)| *destination == previous + 1),
+            };
+            if falls_through {
+                diagnostics.push(Diagnostic {
+                    line: previous,
+                    rule: Rule::FallThrough,
+                    message: format!(
+                        "execution can continue into the header of function `{name}` on line {header}"
+                    ),
+                });
+            }
+        }
+
+        diagnostics
+    }
+
+    fn jumps_out_of_bounds(&self) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+
+        for (line, instruction) in self.instructions() {
+            let destinations = match instruction {
+                Instruction::Jump(_, destination @ Operand::Immediate(_), relative) => {
+                    vec![destination_line(line, destination, relative)]
+                }
+                Instruction::JumpTable(
+                    _,
+                    base @ Operand::Immediate(_),
+                    relative,
+                    Operand::Immediate(count),
+                ) if *count > 0 => {
+                    let first = destination_line(line, base, relative);
+                    let last = first.and_then(|first| first.checked_add(*count - 1));
+                    vec![first, last]
+                }
+                _ => continue,
+            };
+
+            let outside = destinations
+                .into_iter()
+                .find(|destination| !destination.is_some_and(|line| self.contains(line)));
+            let Some(destination) = outside else {
+                continue;
+            };
+
+            let message = match destination {
+                Some(destination) => format!(
+                    "jumps to line {destination}, which is outside of the program of {} lines",
+                    self.graph.lines().len()
+                ),
+                None => "jumps to before the start of the program".to_string(),
+            };
+            diagnostics.push(Diagnostic {
+                line,
+                rule: Rule::JumpOutOfBounds,
+                message,
+            });
+        }
+
+        diagnostics
+    }
+
+    fn missing_returns(&self) -> Vec<Diagnostic> {
+        self.instructions()
+            .filter_map(|(line, instruction)| {
+                let Instruction::Call(Operand::Immediate(destination)) = instruction else {
+                    return None;
+                };
+                let destination = *destination as usize;
+                let callee = self.graph.block_at(destination)?;
+                if self.can_return(callee) {
+                    return None;
+                }
+
+                Some(Diagnostic {
+                    line,
+                    rule: Rule::MissingReturn,
+                    message: format!(
+                        "calls function {}, which never returns",
+                        self.function_name(destination)
+                    ),
+                })
+            })
+            .collect()
+    }
+
+    /// Finds the registers that may be read before being set, by following which registers are set
+    /// on every path to each block.
+    fn uninitialized_registers(&self) -> Vec<Diagnostic> {
+        let blocks = self.graph.blocks();
+        let bodies: HashMap<usize, BTreeSet<usize>> = self
+            .returns
+            .keys()
+            .map(|&callee| (callee, self.reachable(callee, false).0))
+            .collect();
+        // the registers set after the block, given those set before it
+        let exit = |block: &Block, state: RegisterSet| {
+            block
+                .instructions
+                .iter()
+                .fold(state, |state, (_, instruction)| {
+                    state | mask(&instruction.writes())
+                })
+        };
+
+        // the registers set on every path to each block, where a block without a state is not reached yet
+        let mut states: Vec<Option<RegisterSet>> = vec![None; blocks.len()];
+        if let Some(state) = self
+            .graph
+            .block_at(1)
+            .and_then(|entry| states.get_mut(entry))
+        {
+            *state = Some(0);
+        }
+
+        let mut changed = true;
+        while changed {
+            changed = false;
+
+            for (index, block) in blocks.iter().enumerate() {
+                let Some(state) = states.get(index).copied().flatten() else {
+                    continue;
+                };
+                let state = exit(block, state);
+
+                let mut outgoing = Vec::new();
+                match self.callee(block) {
+                    Some(callee) => {
+                        outgoing.push((callee, state));
+
+                        // the registers set by the function on every path to one of its returns
+                        let returned = bodies
+                            .get(&callee)
+                            .into_iter()
+                            .flatten()
+                            .filter(|&&body| self.ends_with_return(body))
+                            .filter_map(|&body| {
+                                Some(exit(
+                                    blocks.get(body)?,
+                                    states.get(body).copied().flatten()?,
+                                ))
+                            })
+                            .reduce(|a, b| a & b);
+                        let next = self.graph.block_at(block.end() + 1);
+                        if let (Some(returned), Some(next)) = (returned, next) {
+                            outgoing.push((next, state | returned));
+                        }
+                    }
+                    None => {
+                        if let Successors::Known(successors) = self.successors(index) {
+                            outgoing.extend(successors.into_iter().map(|block| (block, state)));
+                        }
+                    }
+                }
+
+                for (successor, state) in outgoing {
+                    let Some(current) = states.get_mut(successor) else {
+                        continue;
+                    };
+                    let met = current.map_or(state, |current| current & state);
+                    if *current != Some(met) {
+                        *current = Some(met);
+                        changed = true;
+                    }
+                }
+            }
+        }
+
+        let mut diagnostics = Vec::new();
+        for (block, state) in blocks.iter().zip(states) {
+            let Some(mut state) = state else {
+                continue;
+            };
+
+            for (line, instruction) in &block.instructions {
+                let mut unset: Vec<Register> = Vec::new();
+                for register in instruction.reads() {
+                    if state & mask(&[register]) == 0 && !unset.contains(&register) {
+                        unset.push(register);
+                    }
+                }
+
+                diagnostics.extend(unset.into_iter().map(|register| Diagnostic {
+                    line: *line,
+                    rule: Rule::UninitializedRegister,
+                    message: format!("{register} may be read before it is set"),
+                }));
+                state |= mask(&instruction.writes());
+            }
+        }
+
+        diagnostics
+    }
+
+    fn divisions_by_zero(&self) -> Vec<Diagnostic> {
+        self.instructions()
+            .filter(|(_, instruction)| instruction.divides_by_zero())
+            .map(|(line, _)| Diagnostic {
+                line,
+                rule: Rule::DivisionByZero,
+                message: "divides by an immediate zero, which always fails".to_string(),
+            })
+            .collect()
+    }
+}
+
+/// Finds the keys of the data section that are not used by the program before it.
+fn unused_data(source_code: &str) -> Vec<Diagnostic> {
+    let Some(start) = source_code.rfind(DATA_SECTION) else {
+        return Vec::new();
+    };
+    let Some((program, data)) = source_code.split_at_checked(start) else {
+        return Vec::new();
+    };
+    let header = program.matches('\n').count() + 1;
+    // keys are only used as whole operands, so a key that is part of another word is not a use of it
+    let tokens: HashSet<&str> = program
+        .lines()
+        .map(str::trim_start)
+        .filter(|line| !line.starts_with(COMMENT))
+        .flat_map(str::split_ascii_whitespace)
+        .collect();
+
+    let mut diagnostics = Vec::new();
+    for (offset, line) in data.lines().enumerate().skip(1) {
+        let line = line.trim_start();
+        if line.is_empty() || line.starts_with(COMMENT) {
+            continue;
+        }
+
+        let Some(key) = line.split_ascii_whitespace().next() else {
+            continue;
+        };
+        if !tokens.contains(key) {
+            diagnostics.push(Diagnostic {
+                line: header + offset,
+                rule: Rule::UnusedData,
+                message: format!("{key} is never used"),
+            });
+        }
+    }
+
+    diagnostics
+}
+
+fn unreachable_diagnostic((first, last): (usize, usize)) -> Diagnostic {
+    let message = if first == last {
+        "unreachable code".to_string()
+    } else {
+        format!("unreachable code, up to line {last}")
+    };
+
+    Diagnostic {
+        line: first,
+        rule: Rule::UnreachableCode,
+        message,
+    }
+}
+
+fn is_blank(line: &str) -> bool {
+    line.trim().is_empty()
+}
+
+/// A set of registers, where each register is the bit of its index.
+type RegisterSet = u16;
+
+const _: () = assert!(Register::ALL.len() <= RegisterSet::BITS as usize);
+
+/// Returns the bits of the registers, where each register is a bit of its index.
+fn mask(registers: &[Register]) -> RegisterSet {
+    registers
+        .iter()
+        .fold(0, |mask, &register| mask | 1 << register as usize)
+}
+
+#[cfg(test)]
+mod rules {
+    use super::{lint, Diagnostic, Rule};
+    use crate::error::InterpreterError;
+    use clap::ValueEnum;
+
+    /// Lints the source code with only the rule enabled.
+    fn only(rule: Rule, source: &[&str]) -> Result<Vec<Diagnostic>, InterpreterError> {
+        let disabled: Vec<Rule> = Rule::value_variants()
+            .iter()
+            .copied()
+            .filter(|&other| other != rule)
+            .collect();
+
+        Ok(lint(&source.join("\n"), &disabled)?.diagnostics().to_vec())
+    }
+
+    fn lines(diagnostics: &[Diagnostic]) -> Vec<usize> {
+        diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.line)
+            .collect()
+    }
+
+    #[test]
+    fn clean_program() -> Result<(), InterpreterError> {
+        let source = [
+            "// sums the numbers from one to five",
+            "setb ra 5",
+            "setb rb 0",
+            "call add_ra",
+            "subb ra 1",
+            "jnz -2",
+            "prrb rb",
+            "stop",
+            "fn add_ra:",
+            "addb rb ra",
+            "ret",
+        ]
+        .join("\n");
+
+        let report = lint(&source, &[])?;
+
+        assert!(report.is_empty(), "{report}");
+
+        Ok(())
+    }
+
+    #[test]
+    fn example_program() -> Result<(), InterpreterError> {
+        let source = std::fs::read_to_string("program.kasm").expect("example program should exist");
+
+        let expected =
+            "line 1: calls function `calc_fib_num`, which never returns [missing-return]\n";
+
+        let actual = lint(&source, &[])?;
+
+        // the function stops the program instead of returning
+        assert_eq!(actual.to_string(), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn unreachable_code() -> Result<(), InterpreterError> {
+        let source = [
+            "jmp 4",
+            "setb ra 1",
+            "setb rb 1",
+            "stop",
+            "",
+            "setb rc 1",
+            "fn unused:",
+            "ret",
+        ];
+
+        let actual = only(Rule::UnreachableCode, &source)?;
+
+        assert_eq!(lines(&actual), [2, 6, 8]);
+        assert_eq!(
+            actual.first().map(|diagnostic| diagnostic.message.as_str()),
+            Some("unreachable code, up to line 3")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn unreachable_code_is_unknown_with_register_jumps() -> Result<(), InterpreterError> {
+        let source = ["setw ra 3", "jmp ra", "stop", "setb rb 1"];
+
+        let actual = only(Rule::UnreachableCode, &source)?;
+
+        assert!(actual.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn fall_through() -> Result<(), InterpreterError> {
+        let source = [
+            "call first",
+            "stop",
+            "fn first:",
+            "addb ra 1",
+            "jiz -1",
+            "// the comment does not stop execution",
+            "fn second:",
+            "ret",
+        ];
+        let expected = vec![Diagnostic {
+            line: 5,
+            rule: Rule::FallThrough,
+            message: "execution can continue into the header of function `second` on line 7"
+                .to_string(),
+        }];
+
+        let actual = only(Rule::FallThrough, &source)?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn jump_out_of_bounds() -> Result<(), InterpreterError> {
+        let source = ["jmp +5", "jnz -3", "jtab ra +1 3", "jmp 1", "stop"];
+
+        let actual = only(Rule::JumpOutOfBounds, &source)?;
+
+        assert_eq!(lines(&actual), [1, 2, 3]);
+        assert_eq!(
+            actual.first().map(|diagnostic| diagnostic.message.as_str()),
+            Some("jumps to line 6, which is outside of the program of 5 lines")
+        );
+        assert_eq!(
+            actual.get(1).map(|diagnostic| diagnostic.message.as_str()),
+            Some("jumps to before the start of the program")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn large_jump_table() -> Result<(), InterpreterError> {
+        let source = ["setb ra 0", "jtab ra +1 18446744073709551615", "stop"];
+
+        let actual = only(Rule::UnreachableCode, &source)?;
+
+        assert!(actual.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn missing_return() -> Result<(), InterpreterError> {
+        let source = [
+            "call exits",
+            "call returns",
+            "stop",
+            "fn exits:",
+            "stop",
+            "fn returns:",
+            "call exits",
+            "ret",
+        ];
+
+        let actual = only(Rule::MissingReturn, &source)?;
+
+        assert_eq!(lines(&actual), [1, 2, 7]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn unused_data() -> Result<(), InterpreterError> {
+        let source = [
+            "setb ra FIVE",
+            "stop",
+            "DATA:",
+            "FIVE 5",
+            "// comment",
+            "SIX 6",
+        ];
+        let expected = vec![Diagnostic {
+            line: 6,
+            rule: Rule::UnusedData,
+            message: "SIX is never used".to_string(),
+        }];
+
+        let actual = only(Rule::UnusedData, &source)?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn unused_data_is_matched_by_whole_operands() -> Result<(), InterpreterError> {
+        let source = [
+            "// SIX is not used by this program",
+            "setb ra SIXTY",
+            "stop",
+            "DATA:",
+            "SIXTY 60",
+            "SIX 6",
+        ];
+
+        let actual = only(Rule::UnusedData, &source)?;
+
+        assert_eq!(lines(&actual), [6]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn uninitialized_register() -> Result<(), InterpreterError> {
+        let source = [
+            "setb ra 1",
+            "cmpb ra 2",
+            "jiz +2",
+            "setb rb 1",
+            "addb ra rb",
+            "call set_rc",
+            "prrb rc",
+            "prrb rd",
+            "stop",
+            "fn set_rc:",
+            "setb rc 1",
+            "ret",
+        ];
+        let expected = vec![
+            Diagnostic {
+                line: 5,
+                rule: Rule::UninitializedRegister,
+                message: "rb may be read before it is set".to_string(),
+            },
+            Diagnostic {
+                line: 8,
+                rule: Rule::UninitializedRegister,
+                message: "rd may be read before it is set".to_string(),
+            },
+        ];
+
+        let actual = only(Rule::UninitializedRegister, &source)?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn division_by_zero() -> Result<(), InterpreterError> {
+        let source = [
+            "setb ra 1",
+            "divb ra 0",
+            "remb ra 2",
+            "divremb ra rb 0",
+            "stop",
+        ];
+
+        let actual = only(Rule::DivisionByZero, &source)?;
+
+        assert_eq!(lines(&actual), [2, 4]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn rules_can_be_disabled() -> Result<(), InterpreterError> {
+        let source = ["stop", "divb ra 0"].join("\n");
+
+        let all = lint(&source, &[])?;
+        let disabled = lint(&source, &[Rule::UnreachableCode])?;
+
+        assert_eq!(all.diagnostics().len(), 2);
+        assert_eq!(
+            disabled.to_string(),
+            "line 2: divides by an immediate zero, which always fails [division-by-zero]\n"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn rule_names() {
+        for rule in Rule::value_variants() {
+            let name = rule.to_string();
+
+            assert_eq!(Rule::from_str(&name, false), Ok(*rule));
+        }
+    }
+}
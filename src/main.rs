@@ -1,63 +1,413 @@
 use clap::Parser;
-use kasm::{ArgumentError, Arguments, Interpreter, InterpreterError};
-use std::{fs::File, io::Read};
+use kasm::{
+    ArgumentError, Arguments, AssembleArguments, CfgArguments, CfgFormat, Cli, Command,
+    DisasmArguments, FmtArguments, Interpreter, InterpreterError, LintArguments, Repl,
+    ReplArguments, StackArguments,
+};
+use std::{
+    fs::File,
+    io::{stdin, stdout, Read},
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
 
-fn main() {
-    let args = Arguments::parse();
-    let file_name = args.file_name.clone();
-    if !file_name.is_file() {
-        eprintln!(
-            "{}",
-            InterpreterError::Argument(ArgumentError::NotAFile(file_name))
-        );
-        return;
+fn main() -> ExitCode {
+    match Cli::parse().command() {
+        Command::Run(args) => run(args),
+        Command::Assemble(args) => assemble(args),
+        Command::Disasm(args) => disasm(args),
+        Command::Cfg(args) => cfg(args),
+        Command::Fmt(args) => fmt(args),
+        Command::Lint(args) => lint(args),
+        Command::Stack(args) => stack(args),
+        Command::Repl(args) => repl(args),
+        Command::Debug(args) => run(args.into()),
     }
+}
+
+/// Runs the program, and writes the requested reports of the run.
+///
+/// Fails if the program could not be loaded or run, or a report could not be written.
+fn run(args: Arguments) -> ExitCode {
+    let resume = args.resume.clone();
+    let snapshot_on_error = args.snapshot_on_error.clone();
+    let crash_report = args.crash_report.clone();
+    let profile = args.profile.clone();
+    let coverage = args.coverage.clone();
+    let file_name = args.file_name.clone();
+
+    let content = match read_program_file(file_name.clone()) {
+        Ok(content) => content,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
 
     let mut i = match Interpreter::try_new(args) {
         Ok(p) => p,
         Err(err) => {
             eprintln!("{err}");
-            return;
+            return ExitCode::FAILURE;
         }
     };
 
-    let mut file = match File::options()
-        .read(true)
-        .write(false)
-        .truncate(false)
-        .create(false)
-        .open(file_name.clone())
-    {
-        Ok(f) => f,
-        Err(err) => {
-            let error_msg = match err.kind() {
-                std::io::ErrorKind::NotFound => {
-                    InterpreterError::Argument(ArgumentError::FileNotFound(file_name))
-                }
-                std::io::ErrorKind::PermissionDenied => {
-                    InterpreterError::Argument(ArgumentError::LackingPermissions(
-                        "open the specified program file".to_string(),
-                    ))
+    let result = load(&mut i, &content, resume.as_deref()).and_then(|()| i.run_loaded());
+    let mut exit_code = ExitCode::SUCCESS;
+
+    if let Some(path) = profile {
+        if let Err(err) = write_profile(&i, &path) {
+            let err = ArgumentError::FailedProfileWrite(err.to_string());
+            eprintln!("{}", InterpreterError::Argument(err));
+            exit_code = ExitCode::FAILURE;
+        }
+    }
+
+    if let Some(path) = coverage {
+        if let Err(err) = write_coverage(&i, &path, &file_name) {
+            let err = ArgumentError::FailedCoverageWrite(err.to_string());
+            eprintln!("{}", InterpreterError::Argument(err));
+            exit_code = ExitCode::FAILURE;
+        }
+    }
+
+    if let Err(err) = result {
+        eprintln!("{err}");
+        exit_code = ExitCode::FAILURE;
+
+        let Some(line) = err.line() else {
+            return exit_code;
+        };
+
+        if !i.call_stack().is_empty() && crash_report.is_none() {
+            eprint!("backtrace:\n{}", i.backtrace(line));
+        }
+
+        match crash_report {
+            Some(Some(path)) => {
+                if let Err(err) = std::fs::write(path, i.crash_report(&err).to_string()) {
+                    let err = ArgumentError::FailedCrashReportWrite(err.to_string());
+                    eprintln!("{}", InterpreterError::Argument(err));
                 }
-                _ => InterpreterError::Argument(ArgumentError::UnknownProgramFileIssue(
-                    err.to_string(),
-                )),
-            };
-            eprintln!("{error_msg}");
-            return;
+            }
+            Some(None) => eprint!("\n{}", i.crash_report(&err)),
+            None => {}
+        }
+
+        if let Some(path) = snapshot_on_error {
+            if let Err(err) = std::fs::write(path, i.snapshot()) {
+                let err = ArgumentError::FailedSnapshotWrite(err.to_string());
+                eprintln!("{}", InterpreterError::Argument(err));
+            }
+        }
+    }
+
+    exit_code
+}
+
+/// Assembles the program into bytecode.
+///
+/// Fails if the program could not be assembled, or the bytecode could not be written.
+fn assemble(args: AssembleArguments) -> ExitCode {
+    let content = match read_program_file(args.file_name.clone()) {
+        Ok(content) => content,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
         }
     };
 
-    let mut content = String::new();
-    if file.read_to_string(&mut content).is_err() {
+    let Ok(source_code) = String::from_utf8(content) else {
         eprintln!(
             "{}",
             InterpreterError::Argument(ArgumentError::ProgramFileInvalidEncoding)
         );
-        return;
+        return ExitCode::FAILURE;
+    };
+
+    let bytecode = match kasm::assemble(&source_code, !args.no_line_table) {
+        Ok(bytecode) => bytecode,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if let Err(err) = std::fs::write(args.output(), bytecode) {
+        let err = ArgumentError::FailedBytecodeWrite(err.to_string());
+        eprintln!("{}", InterpreterError::Argument(err));
+        return ExitCode::FAILURE;
     }
 
-    if let Err(err) = i.run(&content) {
+    ExitCode::SUCCESS
+}
+
+/// Prints the disassembly of the program.
+///
+/// Fails if the program could not be loaded.
+fn disasm(args: DisasmArguments) -> ExitCode {
+    let content = match read_program_file(args.file_name) {
+        Ok(content) => content,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut i = Interpreter::builder().build();
+    match load(&mut i, &content, None) {
+        Ok(()) => {
+            print!("{}", i.disassembly());
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Prints the control flow graph of the program.
+///
+/// Fails if the program could not be loaded.
+fn cfg(args: CfgArguments) -> ExitCode {
+    let content = match read_program_file(args.file_name) {
+        Ok(content) => content,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut i = Interpreter::builder().build();
+    if let Err(err) = load(&mut i, &content, None) {
         eprintln!("{err}");
+        return ExitCode::FAILURE;
     }
+
+    let graph = i.control_flow_graph();
+    match args.format {
+        CfgFormat::Dot => print!("{}", graph.dot()),
+        CfgFormat::Json => print!("{}", graph.json()),
+    }
+
+    ExitCode::SUCCESS
+}
+
+/// Runs an interactive session on stdin and stdout, until the input is exhausted or the session is quit.
+fn repl(args: ReplArguments) -> ExitCode {
+    let stack_size = match args.stack_size() {
+        Ok(stack_size) => stack_size,
+        Err(err) => {
+            eprintln!("{}", InterpreterError::Argument(err));
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let i = Interpreter::builder().stack_size(stack_size).build();
+    match Repl::new(i).run(stdin().lock(), stdout()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Prints the depth of the stack the program requires, and the paths that misuse it.
+///
+/// Fails if the program could not be loaded, or if any path misuses the stack.
+fn stack(args: StackArguments) -> ExitCode {
+    let content = match read_program_file(args.file_name) {
+        Ok(content) => content,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut i = Interpreter::builder().build();
+    if let Err(err) = load(&mut i, &content, None) {
+        eprintln!("{err}");
+        return ExitCode::FAILURE;
+    }
+
+    let analysis = i.stack_analysis();
+    if args.lines {
+        print!("{}", analysis.listing());
+    }
+    print!("{analysis}");
+
+    if analysis.diagnostics().is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+/// Formats the files in place, or only reports the files that are not formatted when checking.
+///
+/// Fails if any file could not be formatted, or is not formatted when checking.
+fn fmt(args: FmtArguments) -> ExitCode {
+    let mut failed = false;
+
+    for file_name in args.file_names {
+        let source_code = read_program_file(file_name.clone()).and_then(|content| {
+            String::from_utf8(content)
+                .map_err(|_| InterpreterError::Argument(ArgumentError::ProgramFileInvalidEncoding))
+        });
+        let formatted = source_code.and_then(|source_code| {
+            let formatted = kasm::format_source(&source_code)?;
+            Ok((source_code, formatted))
+        });
+
+        match formatted {
+            Ok((source_code, formatted)) if source_code == formatted => {}
+            Ok(_) if args.check => {
+                println!("{} is not formatted", file_name.display());
+                failed = true;
+            }
+            Ok((_, formatted)) => {
+                if let Err(err) = std::fs::write(&file_name, formatted) {
+                    let err = ArgumentError::FailedFormatWrite(err.to_string());
+                    eprintln!("{}", InterpreterError::Argument(err));
+                    failed = true;
+                }
+            }
+            Err(err) => {
+                eprintln!("{}: {err}", file_name.display());
+                failed = true;
+            }
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Reports the mistakes found in each file, prefixed by the name of the file.
+///
+/// Fails if any file could not be linted, or if any mistakes were found.
+fn lint(args: LintArguments) -> ExitCode {
+    let mut failed = false;
+
+    for file_name in args.file_names {
+        let report = read_program_file(file_name.clone())
+            .and_then(|content| {
+                String::from_utf8(content).map_err(|_| {
+                    InterpreterError::Argument(ArgumentError::ProgramFileInvalidEncoding)
+                })
+            })
+            .and_then(|source_code| kasm::lint(&source_code, &args.disabled));
+
+        match report {
+            Ok(report) => {
+                for diagnostic in report.diagnostics() {
+                    println!("{}: {diagnostic}", file_name.display());
+                }
+                failed |= !report.is_empty();
+            }
+            Err(err) => {
+                eprintln!("{}: {err}", file_name.display());
+                failed = true;
+            }
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Reads the contents of the program file, which is either source code or bytecode.
+fn read_program_file(file_name: PathBuf) -> Result<Vec<u8>, InterpreterError> {
+    if !file_name.is_file() {
+        return Err(InterpreterError::Argument(ArgumentError::NotAFile(
+            file_name,
+        )));
+    }
+
+    let mut file = File::options()
+        .read(true)
+        .write(false)
+        .truncate(false)
+        .create(false)
+        .open(file_name.clone())
+        .map_err(|err| match err.kind() {
+            std::io::ErrorKind::NotFound => {
+                InterpreterError::Argument(ArgumentError::FileNotFound(file_name))
+            }
+            std::io::ErrorKind::PermissionDenied => InterpreterError::Argument(
+                ArgumentError::LackingPermissions("open the specified program file".to_string()),
+            ),
+            _ => {
+                InterpreterError::Argument(ArgumentError::UnknownProgramFileIssue(err.to_string()))
+            }
+        })?;
+
+    let mut content = Vec::new();
+    file.read_to_end(&mut content).map_err(|err| {
+        InterpreterError::Argument(ArgumentError::UnknownProgramFileIssue(err.to_string()))
+    })?;
+
+    Ok(content)
+}
+
+/// Loads the program from source code or bytecode, and restores the state from the snapshot file if given.
+fn load(
+    i: &mut Interpreter,
+    content: &[u8],
+    resume: Option<&Path>,
+) -> Result<(), InterpreterError> {
+    if kasm::is_bytecode(content) {
+        i.load_bytecode(content)?;
+    } else {
+        let source_code = std::str::from_utf8(content)
+            .map_err(|_| InterpreterError::Argument(ArgumentError::ProgramFileInvalidEncoding))?;
+        i.load(source_code)?;
+    }
+
+    if let Some(path) = resume {
+        let snapshot = std::fs::read(path).map_err(|err| {
+            InterpreterError::Argument(ArgumentError::FailedSnapshotRead(err.to_string()))
+        })?;
+        i.restore(&snapshot).map_err(InterpreterError::Snapshot)?;
+    }
+
+    Ok(())
+}
+
+/// Writes the profile report to the path, and the folded call stacks to the path with a '.folded' suffix.
+fn write_profile(i: &Interpreter, path: &Path) -> std::io::Result<()> {
+    if let Some(report) = i.profile_report() {
+        std::fs::write(path, report.to_string())?;
+    }
+
+    if let Some(folded_stacks) = i.folded_stacks() {
+        let mut folded_path = path.as_os_str().to_owned();
+        folded_path.push(".folded");
+        std::fs::write(folded_path, folded_stacks.to_string())?;
+    }
+
+    Ok(())
+}
+
+/// Writes the coverage in the LCOV format to the path, and the annotated source to the path with an '.annotated' suffix.
+fn write_coverage(i: &Interpreter, path: &Path, source_file: &Path) -> std::io::Result<()> {
+    if let Some(lcov) = i.lcov(source_file) {
+        std::fs::write(path, lcov.to_string())?;
+    }
+
+    if let Some(annotated) = i.annotated_coverage() {
+        let mut annotated_path = path.as_os_str().to_owned();
+        annotated_path.push(".annotated");
+        std::fs::write(annotated_path, annotated.to_string())?;
+    }
+
+    Ok(())
 }
@@ -1,11 +1,11 @@
 use crate::{error::DecodeError, register::Register};
-use std::str::FromStr;
+use std::{fmt::Display, str::FromStr};
 
 /// Represents a register or an immediate value.
 ///
 /// This type is used when an argument of an instruction
 /// could be either a register, or an immediately defined value.
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum Operand<Size> {
     Register(Register),
     Immediate(Size),
@@ -40,6 +40,32 @@ impl<T: FromStr> TryFrom<&str> for Operand<T> {
     }
 }
 
+impl<T> Operand<T> {
+    /// Returns the register, if the operand is one.
+    pub fn register(&self) -> Option<Register> {
+        match self {
+            Operand::Register(register) => Some(*register),
+            Operand::Immediate(_) => None,
+        }
+    }
+}
+
+impl<T: Default + PartialEq> Operand<T> {
+    /// Returns true if the operand is an immediate value of zero.
+    pub fn is_zero(&self) -> bool {
+        matches!(self, Operand::Immediate(value) if *value == T::default())
+    }
+}
+
+impl<T: Display> Display for Operand<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Operand::Register(register) => write!(f, "{register}"),
+            Operand::Immediate(value) => write!(f, "{value}"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod is_possible_register {
     use crate::operand::is_possible_register;
@@ -1,12 +1,21 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, sync::Arc};
 
 use crate::{
-    constant::{Word, COMMENT},
-    decode::DECODE_TABLE,
+    constant::{Byte, Half, Quarter, Word, COMMENT, LABEL_PREFIX},
+    decode::{OperandType, DECODE_TABLE, OPERAND_TYPES},
     error::PreProcessError,
+    program::Functions,
 };
 
-const DATA_SECTION: &str = "DATA:";
+pub const DATA_SECTION: &str = "DATA:";
+
+/// Expands the data section and then the function calls of the source code, which gives the program as it is
+/// executed along with the names of its functions.
+pub(crate) fn preprocess(source_code: &str) -> Result<(Box<[String]>, Functions), PreProcessError> {
+    let data_expanded_source_code = expand_data_section(source_code)?;
+
+    expand_function_calls(data_expanded_source_code)
+}
 
 pub fn expand_data_section(s: &str) -> Result<String, PreProcessError> {
     if let Some(data_section_start) = s.rfind(DATA_SECTION) {
@@ -48,7 +57,22 @@ pub fn expand_data_section(s: &str) -> Result<String, PreProcessError> {
 // This implementation is quite poor IMO - ideally this is changed to something clearer
 // and more performant in the future. Test cases are written so similar behaviour can be
 // asserted with a new implementation.
-pub fn expand_function_calls(s_program: String) -> Result<Box<[String]>, PreProcessError> {
+//
+// Alongside the expanded program, the names of the functions are returned by the line they start on.
+pub fn expand_function_calls(
+    s_program: String,
+) -> Result<(Box<[String]>, Functions), PreProcessError> {
+    let (program, functions, _) = expand_definitions(s_program)?;
+
+    Ok((program, functions))
+}
+
+/// Expands the program the same way as [`expand_function_calls`], and also returns the constants it declares.
+///
+/// The functions and constants allow further lines to be expanded by [`expand_line`].
+pub(crate) fn expand_definitions(
+    s_program: String,
+) -> Result<(Box<[String]>, Functions, Constants), PreProcessError> {
     let mut program: Vec<String> = s_program.lines().map(|l| l.to_string()).collect();
     let mut f_index = HashMap::new();
 
@@ -86,32 +110,336 @@ pub fn expand_function_calls(s_program: String) -> Result<Box<[String]>, PreProc
         }
     }
 
+    let constants = expand_constants(&mut program)?;
+    expand_labels(&mut program)?;
+
     // substitute function named for line number at call sites
+    for line in program.iter_mut() {
+        expand_call(line, |f_name| f_index.get(f_name).copied())?;
+    }
+
+    let functions = f_index
+        .into_iter()
+        .map(|(f_name, line_number)| (line_number, Arc::from(f_name)))
+        .collect();
+
+    Ok((program.into_boxed_slice(), functions, constants))
+}
+
+/// Expands a line as if it was part of a program with the functions and constants, without expanding the program.
+///
+/// The line may not declare functions or constants itself.
+pub(crate) fn expand_line(
+    line: &str,
+    functions: &Functions,
+    constants: &Constants,
+) -> Result<String, PreProcessError> {
+    let mut line = [line.to_string()];
+    expand_labels(&mut line)?;
+
+    let [mut line] = line;
+    substitute_constants(&mut line, constants)?;
+    expand_call(&mut line, |f_name| {
+        functions
+            .iter()
+            .find(|(_, name)| ***name == *f_name)
+            .map(|(line_number, _)| *line_number)
+    })?;
+
+    Ok(line)
+}
+
+/// Substitutes the name of the called function for the line it starts on, if the line is a call.
+fn expand_call<F>(line: &mut String, destination: F) -> Result<(), PreProcessError>
+where
+    F: Fn(&str) -> Option<usize>,
+{
+    let trim = line.trim_start();
+    if trim.is_empty() || trim.starts_with(COMMENT) {
+        return Ok(());
+    }
+
+    let mut trim_iter = trim.split_ascii_whitespace();
+    if trim_iter.next().is_some_and(|s| s == "call") {
+        if let Some(possible_function) = trim_iter.next() {
+            let Some(destination) = destination(possible_function) else {
+                if possible_function.parse::<Word>().is_ok() {
+                    return Ok(());
+                }
+
+                return Err(PreProcessError::UndefinedFunctionCalled(
+                    possible_function.to_string(),
+                ));
+            };
+
+            *line = line.replacen(possible_function, &destination.to_string(), 1);
+        }
+    }
+
+    Ok(())
+}
+
+/// The types of constants, with their size in bytes and largest value.
+const CONSTANT_TYPES: [(&str, usize, Word); 4] = [
+    ("byte", size_of::<Byte>(), Byte::MAX as Word),
+    ("quarter", size_of::<Quarter>(), Quarter::MAX as Word),
+    ("half", size_of::<Half>(), Half::MAX as Word),
+    ("word", size_of::<Word>(), Word::MAX),
+];
+
+/// A constant declared by `const NAME: type = value`.
+pub(crate) struct Constant {
+    value: Word,
+    type_name: &'static str,
+    size: usize,
+}
+
+/// The constants of a program, by their name.
+pub(crate) type Constants = HashMap<String, Constant>;
+
+/// Substitutes constants for their value.
+///
+/// A constant is declared on a line of its own, such as `const LIMIT: byte = 200`, and may be used anywhere in
+/// the program. Each use is checked to fit the type of the operand it is used as, which for most operands is given
+/// by the size suffix of the instruction. The
+/// declarations are turned into comments, which keeps the line numbers of the program unchanged.
+///
+/// The declared constants are returned, such that further lines can use them.
+fn expand_constants(program: &mut [String]) -> Result<Constants, PreProcessError> {
+    let mut constants = HashMap::new();
+
     for line in program.iter_mut() {
         let trim = line.trim_start();
-        if trim.is_empty() || trim.starts_with(COMMENT) {
+        let Some(declaration) = trim.strip_prefix("const ") else {
+            continue;
+        };
+
+        let declaration = declaration
+            .split_once(COMMENT)
+            .map_or(declaration, |(declaration, _)| declaration);
+
+        let invalid = || PreProcessError::InvalidConstantDeclaration(trim.to_string());
+        let (name, rest) = declaration.split_once(':').ok_or_else(invalid)?;
+        let (type_name, value) = rest.split_once('=').ok_or_else(invalid)?;
+        let (name, type_name, value) = (name.trim(), type_name.trim(), value.trim());
+
+        if name.is_empty() || !is_screaming_snake_case(name) {
+            return Err(PreProcessError::InvalidConstantNameFormat(name.to_string()));
+        }
+
+        let Some(&(type_name, size, max)) = CONSTANT_TYPES
+            .iter()
+            .find(|(constant_type, _, _)| *constant_type == type_name)
+        else {
+            return Err(PreProcessError::UnknownConstantType(
+                name.to_string(),
+                type_name.to_string(),
+            ));
+        };
+
+        let value = value
+            .parse::<Word>()
+            .ok()
+            .filter(|value| *value <= max)
+            .ok_or_else(|| {
+                PreProcessError::InvalidConstantValue(
+                    name.to_string(),
+                    value.to_string(),
+                    type_name.to_string(),
+                )
+            })?;
+
+        let constant = Constant {
+            value,
+            type_name,
+            size,
+        };
+        if constants.insert(name.to_string(), constant).is_some() {
+            return Err(PreProcessError::DuplicateConstant(name.to_string()));
+        }
+
+        *line = format!("{COMMENT}{trim}");
+    }
+
+    if !constants.is_empty() {
+        for line in program.iter_mut() {
+            substitute_constants(line, &constants)?;
+        }
+    }
+
+    Ok(constants)
+}
+
+/// Substitutes the constants used as operands of the line for their value.
+fn substitute_constants(line: &mut String, constants: &Constants) -> Result<(), PreProcessError> {
+    let trim = line.trim_start();
+    if trim.starts_with(COMMENT) {
+        return Ok(());
+    }
+
+    let mut trim_iter = trim.split_ascii_whitespace();
+    let Some(mnemonic) = trim_iter.next() else {
+        return Ok(());
+    };
+
+    // the position of each use is kept, so the value replaces the operand rather than any other text
+    let used: Vec<(usize, usize, &str)> = trim_iter
+        .take_while(|s| !s.starts_with(COMMENT))
+        .enumerate()
+        .filter(|(_, s)| constants.contains_key(*s))
+        .map(|(position, s)| (position, s.as_ptr() as usize - trim.as_ptr() as usize, s))
+        .collect();
+    if used.is_empty() {
+        return Ok(());
+    }
+
+    let mut expanded = trim.to_string();
+    for (position, offset, name) in used.into_iter().rev() {
+        let Some(constant) = constants.get(name) else {
             continue;
+        };
+
+        if operand_size(mnemonic, position).is_some_and(|size| constant.size > size) {
+            return Err(PreProcessError::ConstantTooLarge(
+                name.to_string(),
+                constant.type_name.to_string(),
+                trim.to_string(),
+            ));
         }
 
+        expanded.replace_range(offset..offset + name.len(), &constant.value.to_string());
+    }
+
+    let indent = line.len() - trim.len();
+    line.truncate(indent);
+    line.push_str(&expanded);
+
+    Ok(())
+}
+
+/// Returns the size of the operand at the position, if it is an operand of an instruction with a size suffix.
+///
+/// The suffix is the first letter of the type of the size, and the type of each operand is given by
+/// [`OPERAND_TYPES`].
+fn operand_size(mnemonic: &str, position: usize) -> Option<usize> {
+    let suffix = mnemonic.chars().last()?;
+    let stem = mnemonic.strip_suffix(suffix)?;
+    let operand_type = OPERAND_TYPES.get(stem)?.get(position)?;
+
+    let size_of_type = |name: &str| {
+        CONSTANT_TYPES
+            .iter()
+            .find(|(type_name, _, _)| *type_name == name)
+            .map(|(_, size, _)| *size)
+    };
+
+    match operand_type {
+        OperandType::Register => None,
+        OperandType::Byte => size_of_type("byte"),
+        OperandType::Word => size_of_type("word"),
+        OperandType::Sized => CONSTANT_TYPES
+            .iter()
+            .find(|(type_name, _, _)| type_name.starts_with(suffix))
+            .map(|(_, size, _)| *size),
+    }
+}
+
+/// Substitutes local labels for the line they are defined on.
+///
+/// A label is defined on a line of its own, such as `.loop:`, and is scoped to the function it is defined in,
+/// or to the code before the first function. The definitions are turned into comments, which keeps the line
+/// numbers of the program unchanged.
+fn expand_labels(program: &mut [String]) -> Result<(), PreProcessError> {
+    let mut labels: HashMap<(Option<String>, String), usize> = HashMap::new();
+    let mut scopes = Vec::with_capacity(program.len());
+
+    // index labels by the function they are defined in
+    let mut scope: Option<String> = None;
+    for (line_number, line) in program.iter_mut().enumerate() {
+        let trim = line.trim_start();
         let mut trim_iter = trim.split_ascii_whitespace();
-        if trim_iter.next().is_some_and(|s| s == "call") {
-            if let Some(possible_function) = trim_iter.next() {
-                let Some(destination) = f_index.get(possible_function) else {
-                    if possible_function.parse::<Word>().is_ok() {
-                        continue;
-                    }
-
-                    return Err(PreProcessError::UndefinedFunctionCalled(
-                        possible_function.to_string(),
-                    ));
-                };
-
-                *line = line.replacen(possible_function, &destination.to_string(), 1);
+        let definition = match trim_iter.next() {
+            _ if trim.starts_with(COMMENT) => None,
+            Some("fn") => {
+                scope = trim_iter
+                    .next()
+                    .map(|f_name| f_name.trim_end_matches(':').to_string());
+                None
+            }
+            Some(token) if token.starts_with(LABEL_PREFIX) => {
+                let label = token
+                    .strip_suffix(':')
+                    .ok_or(PreProcessError::MissingColonSuffix)?;
+
+                let is_valid = label
+                    .strip_prefix(LABEL_PREFIX)
+                    .is_some_and(|name| !name.is_empty() && is_snake_case(name));
+                if !is_valid {
+                    return Err(PreProcessError::InvalidLabelFormat(label.to_string()));
+                }
+
+                if trim_iter.next().is_some_and(|s| !s.starts_with(COMMENT)) {
+                    return Err(PreProcessError::LabelNotOnOwnLine(label.to_string()));
+                }
+
+                // adding 1 because we are one-indexing the source code
+                let key = (scope.clone(), label.to_string());
+                if labels.insert(key, line_number + 1).is_some() {
+                    return Err(PreProcessError::DuplicateLabel(label.to_string()));
+                }
+
+                Some(format!("{COMMENT}{trim}"))
             }
+            _ => None,
+        };
+
+        if let Some(definition) = definition {
+            *line = definition;
+        }
+        scopes.push(scope.clone());
+    }
+
+    // substitute labels for line number at jump sites, which may be any operand
+    for (line, scope) in program.iter_mut().zip(scopes) {
+        let trim = line.trim_start();
+        if trim.starts_with(COMMENT) {
+            continue;
+        }
+
+        let used: Vec<String> = trim
+            .split_ascii_whitespace()
+            .skip(1)
+            .take_while(|s| !s.starts_with(COMMENT))
+            .filter(|s| s.starts_with(LABEL_PREFIX))
+            .map(str::to_string)
+            .collect();
+
+        // labels are substituted from left to right, so the first occurrence is always the operand itself
+        for label in used {
+            let key = (scope.clone(), label);
+            let Some(destination) = labels.get(&key) else {
+                let (_, label) = key;
+                let other_scope = labels
+                    .iter()
+                    .filter(|((_, other), _)| *other == label)
+                    .min_by_key(|(_, line_number)| **line_number)
+                    .map(|((other_scope, _), _)| match other_scope {
+                        Some(f_name) => format!("function '{f_name}'"),
+                        None => "the code outside of functions".to_string(),
+                    });
+
+                return Err(match other_scope {
+                    Some(other_scope) => PreProcessError::LabelOutOfScope(label, other_scope),
+                    None => PreProcessError::UndefinedLabel(label),
+                });
+            };
+
+            let (_, label) = &key;
+            *line = line.replacen(label.as_str(), &destination.to_string(), 1);
         }
     }
 
-    Ok(program.into_boxed_slice())
+    Ok(())
 }
 
 #[inline]
@@ -314,7 +642,8 @@ mod is_snake_case {
 
 #[cfg(test)]
 mod expand_function_calls {
-    use crate::{error::PreProcessError, preprocess::expand_function_calls};
+    use crate::{error::PreProcessError, preprocess::expand_function_calls, program::Functions};
+    use std::sync::Arc;
 
     #[test]
     fn missing_function_name_error() {
@@ -400,7 +729,7 @@ mod expand_function_calls {
             String::from("  ret"),
         ]);
 
-        let actual = expand_function_calls(input)?;
+        let (actual, _) = expand_function_calls(input)?;
 
         assert_eq!(actual, expected);
 
@@ -418,7 +747,7 @@ mod expand_function_calls {
             String::from("call 2"),
         ]);
 
-        let actual = expand_function_calls(input)?;
+        let (actual, _) = expand_function_calls(input)?;
 
         assert_eq!(actual, expected);
 
@@ -436,7 +765,7 @@ mod expand_function_calls {
             String::from("  ret"),
         ]);
 
-        let actual = expand_function_calls(input)?;
+        let (actual, _) = expand_function_calls(input)?;
 
         assert_eq!(actual, expected);
 
@@ -471,7 +800,30 @@ mod expand_function_calls {
             String::from("call 6"),
         ]);
 
-        let actual = expand_function_calls(input)?;
+        let (actual, _) = expand_function_calls(input)?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn functions_indexed_by_first_line() -> Result<(), PreProcessError> {
+        let input = [
+            "fn inc_ra:",
+            "  addb ra 1",
+            "  ret",
+            "",
+            "fn sub_ra:",
+            "  subb ra 1",
+            "  ret",
+        ]
+        .join("\n");
+        let expected: Functions = [(2, Arc::from("inc_ra")), (6, Arc::from("sub_ra"))]
+            .into_iter()
+            .collect();
+
+        let (_, actual) = expand_function_calls(input)?;
 
         assert_eq!(actual, expected);
 
@@ -500,10 +852,350 @@ mod expand_function_calls {
             String::from("call 3"),
         ]);
 
-        let actual = expand_function_calls(input)?;
+        let (actual, _) = expand_function_calls(input)?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod expand_labels {
+    use crate::{error::PreProcessError, preprocess::expand_function_calls};
+
+    #[test]
+    fn labels_scoped_to_functions() -> Result<(), PreProcessError> {
+        let input = [
+            ".start:",
+            "call inc_ra",
+            "jmp .start",
+            "fn inc_ra:",
+            "  .loop:",
+            "  addb ra 1",
+            "  jnz .loop",
+            "  ret",
+            "fn dec_ra:",
+            "  .loop:",
+            "  subb ra 1",
+            "  jnz .loop // back to .loop",
+            "  ret",
+        ]
+        .join("\n");
+        let expected: Box<[String]> = Box::new([
+            String::from("//.start:"),
+            String::from("call 5"),
+            String::from("jmp 1"),
+            String::from("fn inc_ra:"),
+            String::from("//.loop:"),
+            String::from("  addb ra 1"),
+            String::from("  jnz 5"),
+            String::from("  ret"),
+            String::from("fn dec_ra:"),
+            String::from("//.loop:"),
+            String::from("  subb ra 1"),
+            String::from("  jnz 10 // back to .loop"),
+            String::from("  ret"),
+        ]);
+
+        let (actual, _) = expand_function_calls(input)?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn label_in_other_function_error() {
+        let input = [
+            "fn inc_ra:",
+            "  .loop:",
+            "  addb ra 1",
+            "  ret",
+            "fn dec_ra:",
+            "  subb ra 1",
+            "  jnz .loop",
+            "  ret",
+        ]
+        .join("\n");
+        let expected = Err(PreProcessError::LabelOutOfScope(
+            ".loop".to_string(),
+            "function 'inc_ra'".to_string(),
+        ));
+
+        let actual = expand_function_calls(input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn label_outside_of_functions_error() {
+        let input = [".end:", "stop", "fn inc_ra:", "  jmp .end"].join("\n");
+        let expected = Err(PreProcessError::LabelOutOfScope(
+            ".end".to_string(),
+            "the code outside of functions".to_string(),
+        ));
+
+        let actual = expand_function_calls(input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn undefined_label_error() {
+        let input = ["jmp .nowhere"].join("\n");
+        let expected = Err(PreProcessError::UndefinedLabel(".nowhere".to_string()));
+
+        let actual = expand_function_calls(input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn duplicate_label_error() {
+        let input = ["fn inc_ra:", "  .loop:", "  .loop:", "  ret"].join("\n");
+        let expected = Err(PreProcessError::DuplicateLabel(".loop".to_string()));
+
+        let actual = expand_function_calls(input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn invalid_label_format_error() {
+        let input = [".Loop:", "jmp .Loop"].join("\n");
+        let expected = Err(PreProcessError::InvalidLabelFormat(".Loop".to_string()));
+
+        let actual = expand_function_calls(input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn label_not_on_own_line_error() {
+        let input = [".loop: addb ra 1", "jmp .loop"].join("\n");
+        let expected = Err(PreProcessError::LabelNotOnOwnLine(".loop".to_string()));
+
+        let actual = expand_function_calls(input);
+
+        assert_eq!(actual, expected);
+    }
+}
+
+#[cfg(test)]
+mod expand_constants {
+    use crate::{error::PreProcessError, preprocess::expand_function_calls};
+
+    #[test]
+    fn constants_substituted() -> Result<(), PreProcessError> {
+        let input = [
+            "const LIMIT: byte = 200",
+            "const STEP:quarter=2 // a comment",
+            "  setb ra LIMIT",
+            "addw ra STEP // adds STEP",
+            "cmpb LIMIT LIMIT",
+            "jmp LIMIT",
+        ]
+        .join("\n");
+        let expected: Box<[String]> = Box::new([
+            String::from("//const LIMIT: byte = 200"),
+            String::from("//const STEP:quarter=2 // a comment"),
+            String::from("  setb ra 200"),
+            String::from("addw ra 2 // adds STEP"),
+            String::from("cmpb 200 200"),
+            String::from("jmp 200"),
+        ]);
+
+        let (actual, _) = expand_function_calls(input)?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn constant_too_large_for_instruction_error() {
+        let input = ["const TARGET: quarter = 300", "cmpb rd TARGET"].join("\n");
+        let expected = Err(PreProcessError::ConstantTooLarge(
+            "TARGET".to_string(),
+            "quarter".to_string(),
+            "cmpb rd TARGET".to_string(),
+        ));
+
+        let actual = expand_function_calls(input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn word_constant_as_offset_of_byte_instruction() -> Result<(), PreProcessError> {
+        let input = ["const OFF: word = 1", "ldsb ra OFF", "stsb OFF 2"].join("\n");
+        let expected: Box<[String]> = Box::new([
+            String::from("//const OFF: word = 1"),
+            String::from("ldsb ra 1"),
+            String::from("stsb 1 2"),
+        ]);
+
+        let (actual, _) = expand_function_calls(input)?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn byte_constant_as_bit_index_of_word_instruction() -> Result<(), PreProcessError> {
+        let input = ["const BIT: byte = 63", "btsw ra BIT", "bextw ra BIT 1"].join("\n");
+        let expected: Box<[String]> = Box::new([
+            String::from("//const BIT: byte = 63"),
+            String::from("btsw ra 63"),
+            String::from("bextw ra 63 1"),
+        ]);
+
+        let (actual, _) = expand_function_calls(input)?;
 
         assert_eq!(actual, expected);
 
         Ok(())
     }
+
+    #[test]
+    fn constant_too_large_for_bit_index_error() {
+        let input = ["const BIT: quarter = 3", "btw ra BIT"].join("\n");
+        let expected = Err(PreProcessError::ConstantTooLarge(
+            "BIT".to_string(),
+            "quarter".to_string(),
+            "btw ra BIT".to_string(),
+        ));
+
+        let actual = expand_function_calls(input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn invalid_constant_value_error() {
+        let input = ["const TARGET: byte = 300", "cmpb rd TARGET"].join("\n");
+        let expected = Err(PreProcessError::InvalidConstantValue(
+            "TARGET".to_string(),
+            "300".to_string(),
+            "byte".to_string(),
+        ));
+
+        let actual = expand_function_calls(input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn unknown_constant_type_error() {
+        let input = ["const TARGET: int = 3"].join("\n");
+        let expected = Err(PreProcessError::UnknownConstantType(
+            "TARGET".to_string(),
+            "int".to_string(),
+        ));
+
+        let actual = expand_function_calls(input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn invalid_constant_declaration_error() {
+        let input = ["const TARGET byte 3"].join("\n");
+        let expected = Err(PreProcessError::InvalidConstantDeclaration(
+            "const TARGET byte 3".to_string(),
+        ));
+
+        let actual = expand_function_calls(input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn invalid_constant_name_format_error() {
+        let input = ["const target: byte = 3"].join("\n");
+        let expected = Err(PreProcessError::InvalidConstantNameFormat(
+            "target".to_string(),
+        ));
+
+        let actual = expand_function_calls(input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn duplicate_constant_error() {
+        let input = ["const TARGET: byte = 3", "const TARGET: word = 4"].join("\n");
+        let expected = Err(PreProcessError::DuplicateConstant("TARGET".to_string()));
+
+        let actual = expand_function_calls(input);
+
+        assert_eq!(actual, expected);
+    }
+}
+
+#[cfg(test)]
+mod expand_line {
+    use crate::{
+        error::PreProcessError,
+        preprocess::{expand_definitions, expand_line},
+    };
+
+    #[test]
+    fn uses_definitions() -> Result<(), PreProcessError> {
+        let definitions = ["const LIMIT: byte = 200", "fn noop:", "ret"].join("\n");
+        let (_, functions, constants) = expand_definitions(definitions)?;
+
+        let call = expand_line("call noop", &functions, &constants)?;
+        let constant = expand_line("  setb ra LIMIT", &functions, &constants)?;
+
+        assert_eq!(call, "call 3");
+        assert_eq!(constant, "  setb ra 200");
+
+        Ok(())
+    }
+
+    #[test]
+    fn undefined_function_called_error() -> Result<(), PreProcessError> {
+        let (_, functions, constants) = expand_definitions(String::new())?;
+        let expected = Err(PreProcessError::UndefinedFunctionCalled("noop".to_string()));
+
+        let actual = expand_line("call noop", &functions, &constants);
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod operand_size {
+    use crate::preprocess::operand_size;
+
+    #[test]
+    fn sized_operands() {
+        assert_eq!(operand_size("cmpb", 0), Some(1));
+        assert_eq!(operand_size("pshq", 0), Some(2));
+        assert_eq!(operand_size("addh", 1), Some(4));
+        assert_eq!(operand_size("divremw", 2), Some(8));
+    }
+
+    #[test]
+    fn operands_of_fixed_type() {
+        assert_eq!(operand_size("ldsb", 1), Some(8));
+        assert_eq!(operand_size("stsb", 0), Some(8));
+        assert_eq!(operand_size("stsb", 1), Some(1));
+        assert_eq!(operand_size("btw", 1), Some(1));
+        assert_eq!(operand_size("bextw", 2), Some(1));
+    }
+
+    #[test]
+    fn unsized_operands() {
+        assert_eq!(operand_size("addb", 0), None);
+        assert_eq!(operand_size("cmpb", 2), None);
+        assert_eq!(operand_size("jtab", 0), None);
+        assert_eq!(operand_size("jmp", 0), None);
+        assert_eq!(operand_size("call", 0), None);
+    }
 }
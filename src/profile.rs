@@ -0,0 +1,311 @@
+//! Collection of execution counts, such that the hotspots of a program can be found.
+
+use crate::{call_stack::CallFrame, program::Program, Interpreter};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    sync::Arc,
+};
+
+/// The name used for the code that is not part of any function.
+const TOP_LEVEL: &str = "[top level]";
+
+/// The name used for calls to lines that are not the start of a function.
+const UNKNOWN_FUNCTION: &str = "?";
+
+/// The execution counts of a program, recorded once per executed instruction.
+pub struct Profile {
+    lines: HashMap<usize, u64>,
+    mnemonics: HashMap<String, u64>,
+    /// The amount of instructions executed with each call stack, by the names of the called functions.
+    stacks: HashMap<Box<[Arc<str>]>, u64>,
+    /// Reused between instructions to avoid allocating a call stack for every lookup.
+    stack_buffer: Vec<Arc<str>>,
+    unknown_function: Arc<str>,
+}
+
+impl Profile {
+    pub fn new() -> Self {
+        Self {
+            lines: HashMap::new(),
+            mnemonics: HashMap::new(),
+            stacks: HashMap::new(),
+            stack_buffer: Vec::new(),
+            unknown_function: Arc::from(UNKNOWN_FUNCTION),
+        }
+    }
+
+    /// Records the execution of the instruction on the line, while the calls of the call stack are active.
+    pub fn record(&mut self, line_number: usize, line: &str, call_stack: &[CallFrame]) {
+        *self.lines.entry(line_number).or_default() += 1;
+
+        let mnemonic = line.split_ascii_whitespace().next().unwrap_or_default();
+        match self.mnemonics.get_mut(mnemonic) {
+            Some(count) => *count += 1,
+            None => {
+                self.mnemonics.insert(mnemonic.to_string(), 1);
+            }
+        }
+
+        self.stack_buffer.clear();
+        self.stack_buffer.extend(call_stack.iter().map(|frame| {
+            frame
+                .function
+                .clone()
+                .unwrap_or_else(|| self.unknown_function.clone())
+        }));
+        match self.stacks.get_mut(self.stack_buffer.as_slice()) {
+            Some(count) => *count += 1,
+            None => {
+                self.stacks
+                    .insert(self.stack_buffer.clone().into_boxed_slice(), 1);
+            }
+        }
+    }
+
+    /// Returns the total amount of recorded instructions.
+    pub fn total(&self) -> u64 {
+        self.lines.values().sum()
+    }
+
+    /// Returns the inclusive and exclusive counts of each function.
+    ///
+    /// The inclusive count includes the instructions of the functions called by the function,
+    /// while the exclusive count only includes the instructions of the function itself.
+    fn functions(&self) -> HashMap<&str, (u64, u64)> {
+        let mut functions: HashMap<&str, (u64, u64)> = HashMap::new();
+
+        for (stack, &count) in &self.stacks {
+            let innermost = stack.last().map_or(TOP_LEVEL, |function| function);
+            functions.entry(innermost).or_default().1 += count;
+
+            // recursive functions appear multiple times in the stack, but only count once
+            let active: HashSet<&str> = stack
+                .iter()
+                .map(|function| function.as_ref())
+                .chain([TOP_LEVEL])
+                .collect();
+            for function in active {
+                functions.entry(function).or_default().0 += count;
+            }
+        }
+
+        functions
+    }
+}
+
+impl Default for Profile {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A report of the recorded execution counts, sorted by the most executed first.
+pub struct ProfileReport<'a> {
+    profile: &'a Profile,
+    program: &'a Program,
+}
+
+/// The recorded call stacks in the folded format, which is consumed by flame graph tools.
+///
+/// Each line contains the functions of a call stack separated by semicolons, followed by the amount of instructions.
+pub struct FoldedStacks<'a>(&'a Profile);
+
+impl Interpreter {
+    /// Gets the recorded execution counts, if the interpreter is configured to profile.
+    pub fn profile(&self) -> Option<&Profile> {
+        self.config.profile.as_ref()
+    }
+
+    /// Creates a report of the recorded execution counts, if the interpreter is configured to profile.
+    pub fn profile_report(&self) -> Option<ProfileReport<'_>> {
+        let profile = self.config.profile.as_ref()?;
+
+        Some(ProfileReport {
+            profile,
+            program: &self.program,
+        })
+    }
+
+    /// Gets the recorded call stacks in the folded format, if the interpreter is configured to profile.
+    pub fn folded_stacks(&self) -> Option<FoldedStacks<'_>> {
+        self.config.profile.as_ref().map(FoldedStacks)
+    }
+}
+
+/// Sorts the counts by the highest count first, using the key to order equal counts.
+fn sorted<K: Ord, V: Ord + Copy>(counts: impl IntoIterator<Item = (K, V)>) -> Vec<(K, V)> {
+    let mut counts: Vec<(K, V)> = counts.into_iter().collect();
+    counts.sort_by(|(k1, v1), (k2, v2)| v2.cmp(v1).then_with(|| k1.cmp(k2)));
+    counts
+}
+
+impl Display for ProfileReport<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let total = self.profile.total();
+        let percent = |count: u64| count as f64 * 100.0 / total.max(1) as f64;
+
+        writeln!(f, "profile of {total} executed instructions")?;
+
+        writeln!(f, "\nlines:")?;
+        writeln!(f, "{:>12} {:>8} {:>6}  source", "count", "percent", "line")?;
+        for (line, count) in sorted(self.profile.lines.iter().map(|(&l, &c)| (l, c))) {
+            let source = self.program.get(line).unwrap_or_default().trim();
+            writeln!(
+                f,
+                "{count:>12} {:>7.2}% {line:>6}  {source}",
+                percent(count)
+            )?;
+        }
+
+        writeln!(f, "\nmnemonics:")?;
+        writeln!(f, "{:>12} {:>8}  mnemonic", "count", "percent")?;
+        for (mnemonic, count) in sorted(self.profile.mnemonics.iter().map(|(m, &c)| (m, c))) {
+            writeln!(f, "{count:>12} {:>7.2}%  {mnemonic}", percent(count))?;
+        }
+
+        writeln!(f, "\nfunctions:")?;
+        writeln!(f, "{:>12} {:>12}  function", "inclusive", "exclusive")?;
+        for (function, (inclusive, exclusive)) in sorted(self.profile.functions()) {
+            writeln!(f, "{inclusive:>12} {exclusive:>12}  {function}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for FoldedStacks<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut stacks: Vec<(String, u64)> = self
+            .0
+            .stacks
+            .iter()
+            .map(|(stack, &count)| {
+                let functions = std::iter::once(TOP_LEVEL)
+                    .chain(stack.iter().map(|function| function.as_ref()))
+                    .collect::<Vec<_>>();
+                (functions.join(";"), count)
+            })
+            .collect();
+        stacks.sort();
+
+        for (stack, count) in stacks {
+            writeln!(f, "{stack} {count}")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod report {
+    use crate::{error::InterpreterError, profile::Profile, Interpreter};
+
+    fn profiled() -> Result<Interpreter, InterpreterError> {
+        let mut i = Interpreter::new_test();
+        i.config.profile = Some(Profile::new());
+        let program = [
+            "setb ra 3",
+            "call dec_ra",
+            "jnz -1",
+            "stop",
+            "fn dec_ra:",
+            "subb ra 1",
+            "call noop",
+            "ret",
+            "fn noop:",
+            "ret",
+        ]
+        .join("\n");
+
+        i.run(&program)?;
+
+        Ok(i)
+    }
+
+    #[test]
+    fn total_matches_instructions_executed() -> Result<(), InterpreterError> {
+        let i = profiled()?;
+
+        let actual = i.profile().map(Profile::total);
+
+        assert_eq!(actual, Some(i.instructions_executed()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn lines_sorted_by_count() -> Result<(), InterpreterError> {
+        let i = profiled()?;
+
+        let actual = i
+            .profile_report()
+            .expect("interpreter should be profiling")
+            .to_string();
+        let (_, lines) = actual
+            .split_once("source\n")
+            .expect("report should contain lines");
+
+        assert!(lines.starts_with("           3   15.00%      2  call 6\n"));
+        assert!(lines.contains("           1    5.00%      4  stop\n"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn mnemonics() -> Result<(), InterpreterError> {
+        let i = profiled()?;
+
+        let actual = i
+            .profile_report()
+            .expect("interpreter should be profiling")
+            .to_string();
+
+        assert!(actual.contains("           6   30.00%  ret\n"));
+        assert!(actual.contains("           1    5.00%  setb\n"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn inclusive_and_exclusive_function_counts() -> Result<(), InterpreterError> {
+        let i = profiled()?;
+
+        let actual = i
+            .profile_report()
+            .expect("interpreter should be profiling")
+            .to_string();
+
+        assert!(actual.contains("          20            8  [top level]\n"));
+        assert!(actual.contains("          12            9  dec_ra\n"));
+        assert!(actual.contains("           3            3  noop\n"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn folded_stacks() -> Result<(), InterpreterError> {
+        let i = profiled()?;
+        let expected = "[top level] 8\n[top level];dec_ra 9\n[top level];dec_ra;noop 3\n";
+
+        let actual = i
+            .folded_stacks()
+            .expect("interpreter should be profiling")
+            .to_string();
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn not_profiling_by_default() -> Result<(), InterpreterError> {
+        let mut i = Interpreter::new_test();
+        i.run("stop")?;
+
+        assert!(i.profile().is_none());
+        assert!(i.profile_report().is_none());
+
+        Ok(())
+    }
+}
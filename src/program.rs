@@ -1,22 +1,191 @@
-use crate::InterpreterError;
+use crate::{
+    constant::{Word, COMMENT},
+    decode::decode_line,
+    instruction::Instruction,
+    utils::fnv1a,
+    InterpreterError,
+};
+use std::{collections::HashMap, fmt::Display, sync::Arc};
 
-pub struct Program(Box<[String]>);
+/// The names of the functions of a program, by the line they start on.
+pub type Functions = HashMap<usize, Arc<str>>;
+
+/// A line of a program that has already been decoded, such as a program loaded from bytecode.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DecodedLine {
+    Comment,
+    Instruction(Instruction),
+    /// A line that could not be decoded, which is kept such that the error occurs if the line is executed.
+    Invalid(String),
+}
+
+impl DecodedLine {
+    /// Decodes a line of a preprocessed program, which is kept as is if it cannot be decoded.
+    pub(crate) fn new(line: &str) -> Self {
+        match decode_line(line) {
+            Ok(Some(instruction)) => DecodedLine::Instruction(instruction),
+            Ok(None) => DecodedLine::Comment,
+            Err(_) => DecodedLine::Invalid(line.to_string()),
+        }
+    }
+}
+
+/// Writes the line as source code, where comments are written without their text.
+impl Display for DecodedLine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DecodedLine::Comment => write!(f, "{COMMENT}"),
+            DecodedLine::Instruction(instruction) => write!(f, "{instruction}"),
+            DecodedLine::Invalid(source) => write!(f, "{source}"),
+        }
+    }
+}
+
+/// A line of a program, as it is about to be executed.
+pub enum Line<'a> {
+    Comment,
+    /// A line that is yet to be decoded.
+    Source(&'a str),
+    Decoded(&'a Instruction),
+}
+
+#[derive(Default)]
+pub struct Program {
+    /// The source code of each line, which may be empty if the program was loaded without it.
+    source: Box<[String]>,
+    functions: Functions,
+    decoded: Option<Box<[DecodedLine]>>,
+    checksum: u64,
+}
 
 impl Program {
-    pub fn new(program: Box<[String]>) -> Self {
-        Self(program)
+    pub fn new(program: Box<[String]>, functions: Functions) -> Self {
+        let checksum = checksum(&program);
+
+        Self {
+            source: program,
+            functions,
+            decoded: None,
+            checksum,
+        }
+    }
+
+    /// Creates a program of lines that have already been decoded.
+    ///
+    /// The source code is used when presenting lines to the user, and is disassembled from the lines if not provided.
+    pub fn decoded(
+        lines: Box<[DecodedLine]>,
+        source: Option<Box<[String]>>,
+        functions: Functions,
+        checksum: u64,
+    ) -> Self {
+        let source = source.unwrap_or_else(|| lines.iter().map(DecodedLine::to_string).collect());
+
+        Self {
+            source,
+            functions,
+            decoded: Some(lines),
+            checksum,
+        }
+    }
+
+    /// Returns the name of the function starting on the line, if any.
+    pub fn function(&self, line: Word) -> Option<Arc<str>> {
+        self.functions.get(&(line as usize)).cloned()
     }
 
+    /// Returns the source code of the one-indexed line.
     pub fn get(&self, index: usize) -> Result<&str, InterpreterError> {
         if index == 0 {
             return Err(InterpreterError::InvalidProgramCounter(index));
         }
 
         let line = self
-            .0
+            .source
             .get(index - 1)
             .ok_or(InterpreterError::InvalidProgramCounter(index))?;
 
         Ok(line)
     }
+
+    /// Returns the one-indexed line in the form it is executed in.
+    pub fn line(&self, index: usize) -> Result<Line<'_>, InterpreterError> {
+        let Some(decoded) = &self.decoded else {
+            let source = self.get(index)?;
+            if source.starts_with(COMMENT) {
+                return Ok(Line::Comment);
+            }
+
+            return Ok(Line::Source(source));
+        };
+
+        let line = index
+            .checked_sub(1)
+            .and_then(|index| decoded.get(index))
+            .ok_or(InterpreterError::InvalidProgramCounter(index))?;
+
+        let line = match line {
+            DecodedLine::Comment => Line::Comment,
+            DecodedLine::Instruction(instruction) => Line::Decoded(instruction),
+            DecodedLine::Invalid(source) => Line::Source(source),
+        };
+        Ok(line)
+    }
+
+    /// Replaces the source code of the one-indexed line, which is decoded when it is executed.
+    ///
+    /// Any lines decoded in advance are discarded, such that every line is decoded from its source code.
+    pub fn replace(&mut self, index: usize, source: String) -> Result<(), InterpreterError> {
+        let line = index
+            .checked_sub(1)
+            .and_then(|index| self.source.get_mut(index))
+            .ok_or(InterpreterError::InvalidProgramCounter(index))?;
+        *line = source;
+
+        self.decoded = None;
+        self.checksum = checksum(&self.source);
+
+        Ok(())
+    }
+
+    /// Returns every line of the program in decoded form, where the lines that cannot be decoded are kept as is.
+    pub(crate) fn decoded_lines(&self) -> Vec<DecodedLine> {
+        match &self.decoded {
+            Some(decoded) => decoded.to_vec(),
+            None => self
+                .source
+                .iter()
+                .map(|line| DecodedLine::new(line))
+                .collect(),
+        }
+    }
+
+    /// Returns the names of the functions of the program, by the line they start on.
+    pub fn functions(&self) -> &Functions {
+        &self.functions
+    }
+
+    /// Returns the amount of lines in the program.
+    pub fn len(&self) -> usize {
+        self.source.len()
+    }
+
+    /// Returns a checksum of the program, which differs between programs that behave differently.
+    pub fn checksum(&self) -> u64 {
+        self.checksum
+    }
+
+    /// Returns true if the one-indexed line is part of the program.
+    pub fn contains(&self, line: Word) -> bool {
+        line != 0 && line as usize <= self.len()
+    }
+}
+
+/// Computes the checksum of the source code of a program.
+fn checksum(program: &[String]) -> u64 {
+    let bytes = program
+        .iter()
+        .flat_map(|line| line.as_bytes().iter().chain(b"\n"));
+
+    fnv1a(bytes)
 }
@@ -0,0 +1,480 @@
+//! An interactive session, which executes each entered line on the same interpreter.
+//!
+//! Entered lines are preprocessed with the functions and constants defined so far, such that functions can be
+//! called by name. The definitions are only preprocessed again when a function or constant is added, or a file
+//! is loaded. A call executes the function until it returns, while any other instruction is executed on its own.
+//! The registers, flags and stack are kept between lines, until the session is reset.
+
+use crate::{
+    constant::{Word, COMMENT, REPL_CALL_LIMIT, REPL_HELP, REPL_INITIAL},
+    error::{ArgumentError, InterpreterError},
+    preprocess::{expand_data_section, expand_definitions, expand_line, Constants},
+    program::{Functions, Program},
+    stepping::Status,
+    Interpreter,
+};
+use std::{
+    fmt::Write as _,
+    io::{BufRead, Write},
+    path::Path,
+};
+
+/// The prefix of commands, which are handled by the session rather than executed.
+const COMMAND_PREFIX: char = ':';
+
+pub struct Repl {
+    interpreter: Interpreter,
+    /// The lines of the functions and constants defined so far, before they were preprocessed.
+    source: Vec<String>,
+    /// The constants defined so far, which entered lines are preprocessed with along with the loaded functions.
+    constants: Constants,
+    /// The lines of the function currently being defined, starting with the line that names it.
+    definition: Option<Vec<String>>,
+}
+
+impl Repl {
+    /// Creates a session that executes lines on the interpreter.
+    pub fn new(interpreter: Interpreter) -> Self {
+        let mut repl = Self {
+            interpreter,
+            source: Vec::new(),
+            constants: Constants::new(),
+            definition: None,
+        };
+        repl.start(Box::default(), Functions::new());
+
+        repl
+    }
+
+    /// Gets the interpreter the lines are executed on.
+    pub fn interpreter(&self) -> &Interpreter {
+        &self.interpreter
+    }
+
+    /// Returns true if a function is being defined, such that entered lines are added to it.
+    pub fn is_defining(&self) -> bool {
+        self.definition.is_some()
+    }
+
+    /// Reads lines from the input until it is exhausted or the session is quit, and writes the result of each line
+    /// to the output.
+    ///
+    /// # Errors
+    /// Returns an error if the input could not be read, or the output could not be written.
+    pub fn run(&mut self, mut input: impl BufRead, mut output: impl Write) -> std::io::Result<()> {
+        writeln!(output, "{REPL_INITIAL}")?;
+
+        loop {
+            let prompt = if self.is_defining() { "... " } else { "> " };
+            write!(output, "{prompt}")?;
+            output.flush()?;
+
+            let mut line = String::new();
+            if input.read_line(&mut line)? == 0 {
+                break;
+            }
+
+            let line = line.trim_end();
+            if matches!(line.trim(), ":quit" | ":q") {
+                break;
+            }
+
+            match self.eval(line) {
+                Ok(response) => write!(output, "{response}")?,
+                Err(err) => writeln!(output, "error: {err}")?,
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Evaluates an entered line, and returns the text to show for it.
+    ///
+    /// # Errors
+    /// Returns an error if the line could not be preprocessed, decoded or executed, or a file could not be loaded.
+    pub fn eval(&mut self, line: &str) -> Result<String, InterpreterError> {
+        if let Some(definition) = &mut self.definition {
+            if !line.trim().is_empty() {
+                definition.push(line.to_string());
+                return Ok(String::new());
+            }
+
+            return self.define();
+        }
+
+        let trim = line.trim();
+        if trim.starts_with("fn ") {
+            self.definition = Some(vec![trim.to_string()]);
+            return Ok(String::new());
+        }
+
+        match trim.strip_prefix(COMMAND_PREFIX) {
+            Some(command) => self.command(command),
+            None if trim.is_empty() => Ok(String::new()),
+            None => self.execute(trim),
+        }
+    }
+
+    fn command(&mut self, command: &str) -> Result<String, InterpreterError> {
+        let mut command_iter = command.split_ascii_whitespace();
+
+        let mut response = String::new();
+        match command_iter.next().unwrap_or_default() {
+            "help" | "h" => {
+                response = format!(
+                    "{REPL_HELP}A call is stopped with an error if it does not return within \
+                     {REPL_CALL_LIMIT} instructions.\n"
+                );
+            }
+            "registers" | "r" => response = self.interpreter.registers_dump(),
+            "flags" | "f" => response = self.interpreter.flags_dump(),
+            "stack" | "s" => response = self.interpreter.stack_dump(),
+            "load" | "l" => {
+                let file_name = command_iter.next().unwrap_or_default();
+                response = self.load(Path::new(file_name))?;
+            }
+            "reset" => {
+                self.interpreter.reset();
+                self.source.clear();
+                self.constants.clear();
+                self.start(Box::default(), Functions::new());
+            }
+            unknown => {
+                let _ = writeln!(response, "unknown command: '{COMMAND_PREFIX}{unknown}'");
+            }
+        }
+
+        Ok(response)
+    }
+
+    /// Executes the line, where a call executes until the called function returns.
+    ///
+    /// A call that does not return within [`REPL_CALL_LIMIT`] instructions is stopped, such that the session
+    /// remains responsive.
+    fn execute(&mut self, line: &str) -> Result<String, InterpreterError> {
+        // declarations are kept, such that later lines can use them
+        if line.starts_with("const ") {
+            let mut source = self.source.clone();
+            source.push(line.to_string());
+            self.expand(source)?;
+            return Ok(String::new());
+        }
+
+        let program = &mut self.interpreter.program;
+        let line = expand_line(line, program.functions(), &self.constants)
+            .map_err(InterpreterError::PreProcess)?;
+        // the entered line is placed in the second to last line, after the definitions
+        let line_number = program.len() - 1;
+        program.replace(line_number, line)?;
+
+        let start = Word::try_from(line_number)
+            .map_err(|_| InterpreterError::InvalidProgramCounter(line_number))?;
+        self.interpreter.start_at(start);
+
+        let mut status = self.interpreter.step();
+        if status == Status::Running && !self.interpreter.call_stack.is_empty() {
+            let limit = self
+                .interpreter
+                .instructions_executed()
+                .saturating_add(REPL_CALL_LIMIT);
+            status = self
+                .interpreter
+                .run_until(|i| i.call_stack.is_empty() || i.instructions_executed() >= limit);
+
+            if status == Status::Running && !self.interpreter.call_stack.is_empty() {
+                let line_number = self.interpreter.pc();
+                return Err(InterpreterError::InstructionLimitReached(
+                    line_number,
+                    REPL_CALL_LIMIT,
+                ));
+            }
+        }
+
+        match status {
+            Status::Error(err) => Err(err),
+            Status::Stopped => Ok("stopped\n".to_string()),
+            Status::Running | Status::Breakpoint(_) => Ok(String::new()),
+        }
+    }
+
+    /// Completes the definition of the function, which is kept if it can be preprocessed with the other definitions.
+    fn define(&mut self) -> Result<String, InterpreterError> {
+        let definition = self.definition.take().unwrap_or_default();
+        let name = definition
+            .first()
+            .and_then(|line| line.split_ascii_whitespace().nth(1))
+            .map(|name| name.trim_end_matches(':').to_string())
+            .unwrap_or_default();
+
+        let mut source = self.source.clone();
+        source.extend(definition);
+        self.expand(source)?;
+
+        Ok(format!("defined function '{name}'\n"))
+    }
+
+    /// Replaces the definitions with the functions and constants of the file.
+    fn load(&mut self, file_name: &Path) -> Result<String, InterpreterError> {
+        let source_code = std::fs::read_to_string(file_name).map_err(|err| {
+            let err = match err.kind() {
+                std::io::ErrorKind::NotFound => ArgumentError::FileNotFound(file_name.into()),
+                std::io::ErrorKind::InvalidData => ArgumentError::ProgramFileInvalidEncoding,
+                _ => ArgumentError::UnknownProgramFileIssue(err.to_string()),
+            };
+            InterpreterError::Argument(err)
+        })?;
+
+        // the data section is expanded now, as it has to be at the end of the program
+        let source_code =
+            expand_data_section(&source_code).map_err(InterpreterError::PreProcess)?;
+        self.expand(source_code.lines().map(str::to_string).collect())?;
+
+        Ok(format!(
+            "loaded {} functions from '{}'\n",
+            self.interpreter.program.functions().len(),
+            file_name.display()
+        ))
+    }
+
+    /// Preprocesses the definitions, which replace the current ones if they could be preprocessed.
+    fn expand(&mut self, source: Vec<String>) -> Result<(), InterpreterError> {
+        let (program, functions, constants) =
+            expand_definitions(source.join("\n")).map_err(InterpreterError::PreProcess)?;
+
+        self.source = source;
+        self.constants = constants;
+        self.start(program, functions);
+
+        Ok(())
+    }
+
+    /// Loads the preprocessed definitions, followed by the line that entered lines are placed in.
+    fn start(&mut self, definitions: Box<[String]>, functions: Functions) {
+        let mut lines = definitions.into_vec();
+        // calls return to the line after the entered one, so it has to be part of the program
+        lines.extend([COMMENT.to_string(), COMMENT.to_string()]);
+
+        self.interpreter
+            .start(Program::new(lines.into(), functions));
+    }
+}
+
+#[cfg(test)]
+mod session {
+    use super::Repl;
+    use crate::{
+        constant::{Byte, Word, REPL_CALL_LIMIT, REPL_HELP},
+        error::{ExecuteError, InterpreterError, PreProcessError},
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    fn eval_all(repl: &mut Repl, lines: &[&str]) -> Result<String, InterpreterError> {
+        let mut responses = String::new();
+        for line in lines {
+            responses.push_str(&repl.eval(line)?);
+        }
+
+        Ok(responses)
+    }
+
+    #[test]
+    fn state_is_kept_between_lines() -> Result<(), InterpreterError> {
+        let mut repl = Repl::new(Interpreter::new_test());
+
+        eval_all(
+            &mut repl,
+            &["setb ra 5", "addb ra 3", "pshb ra", "cmpb ra 8"],
+        )?;
+        let i = repl.interpreter();
+
+        assert_eq!(i.registers.get::<Byte>(Register::A), 8);
+        assert_eq!(i.stack(), [8]);
+        assert!(i.flags().zero);
+
+        Ok(())
+    }
+
+    #[test]
+    fn defined_function_is_called() -> Result<(), InterpreterError> {
+        let mut repl = Repl::new(Interpreter::new_test());
+
+        let responses = eval_all(
+            &mut repl,
+            &[
+                "fn inc_ra:",
+                "  addb ra 1",
+                "  ret",
+                "",
+                "call inc_ra",
+                "call inc_ra",
+            ],
+        )?;
+        let i = repl.interpreter();
+
+        assert_eq!(responses, "defined function 'inc_ra'\n");
+        assert_eq!(i.registers.get::<Word>(Register::A), 2);
+        assert_eq!(i.stack_pointer(), 0);
+        assert!(!repl.is_defining());
+
+        Ok(())
+    }
+
+    #[test]
+    fn constants_are_kept() -> Result<(), InterpreterError> {
+        let mut repl = Repl::new(Interpreter::new_test());
+
+        eval_all(&mut repl, &["const LIMIT: byte = 200", "setb rb LIMIT"])?;
+
+        assert_eq!(repl.interpreter().registers.get::<Byte>(Register::B), 200);
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_definition_is_discarded() -> Result<(), InterpreterError> {
+        let mut repl = Repl::new(Interpreter::new_test());
+        eval_all(&mut repl, &["fn inc_ra:", "ret", ""])?;
+        let expected = Err(InterpreterError::PreProcess(
+            PreProcessError::DuplicateFunctionName("inc_ra".to_string()),
+        ));
+
+        let actual = eval_all(&mut repl, &["fn inc_ra:", "ret", ""]);
+
+        assert_eq!(actual, expected);
+        assert!(!repl.is_defining());
+
+        Ok(())
+    }
+
+    #[test]
+    fn call_that_does_not_return_error() -> Result<(), InterpreterError> {
+        let mut repl = Repl::new(Interpreter::new_test());
+        eval_all(&mut repl, &["fn spin:", "jmp 2", ""])?;
+        let expected = Err(InterpreterError::InstructionLimitReached(
+            2,
+            REPL_CALL_LIMIT,
+        ));
+
+        let actual = repl.eval("call spin");
+
+        assert_eq!(actual, expected);
+        assert_eq!(repl.eval("setb ra 1"), Ok(String::new()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn execute_error() {
+        let mut repl = Repl::new(Interpreter::new_test());
+        let expected = Err(InterpreterError::Execute(1, ExecuteError::StackUnderflow));
+
+        let actual = repl.eval("popb ra");
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn registers_and_flags() -> Result<(), InterpreterError> {
+        let mut repl = Repl::new(Interpreter::new_test());
+        eval_all(&mut repl, &["setb rh 255", "cmpb 1 1"])?;
+
+        let registers = repl.eval(":registers")?;
+        let flags = repl.eval(":f")?;
+
+        assert_eq!(
+            registers.lines().last(),
+            Some("rh: 0x00000000000000ff (255)")
+        );
+        assert_eq!(flags, "sign=0 overflow=0 zero=1\n");
+
+        Ok(())
+    }
+
+    #[test]
+    fn stack() -> Result<(), InterpreterError> {
+        let mut repl = Repl::new(Interpreter::new_test());
+        eval_all(&mut repl, &["pshb 1", "pshb 2"])?;
+        let expected = [
+            "stack pointer: 2",
+            "       1: 0x02 (2)",
+            "       0: 0x01 (1)",
+            "",
+        ]
+        .join("\n");
+
+        let actual = repl.eval(":stack")?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn reset() -> Result<(), InterpreterError> {
+        let mut repl = Repl::new(Interpreter::new_test());
+        eval_all(
+            &mut repl,
+            &["fn noop:", "ret", "", "setb ra 1", "pshb 1", ":reset"],
+        )?;
+        let expected = Err(InterpreterError::PreProcess(
+            PreProcessError::UndefinedFunctionCalled("noop".to_string()),
+        ));
+
+        let actual = repl.eval("call noop");
+        let i = repl.interpreter();
+
+        assert_eq!(actual, expected);
+        assert_eq!(i.registers.get::<Word>(Register::A), 0);
+        assert_eq!(i.stack_pointer(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn help_states_call_limit() -> Result<(), InterpreterError> {
+        let mut repl = Repl::new(Interpreter::new_test());
+        let expected = format!("within {REPL_CALL_LIMIT} instructions.\n");
+
+        let response = repl.eval(":help")?;
+
+        assert!(response.starts_with(REPL_HELP));
+        assert!(response.ends_with(&expected));
+
+        Ok(())
+    }
+
+    #[test]
+    fn load_file() -> Result<(), InterpreterError> {
+        let mut repl = Repl::new(Interpreter::new_test());
+
+        let response = repl.eval(":load program.kasm")?;
+
+        assert_eq!(response, "loaded 1 functions from 'program.kasm'\n");
+
+        Ok(())
+    }
+
+    #[test]
+    fn run_until_quit() -> Result<(), std::io::Error> {
+        let mut repl = Repl::new(Interpreter::new_test());
+        let input = ["setb ra 1", ":unknown", "popb ra", ":q", "setb ra 2"].join("\n");
+        let mut output = Vec::new();
+        let expected = [
+            "You are running an interactive session.",
+            "Use the ':help'/':h' command for more information.",
+            "> > unknown command: ':unknown'",
+            "> error: failed to execute line 1: a stack underflow occured",
+            "> ",
+        ]
+        .join("\n");
+
+        repl.run(input.as_bytes(), &mut output)?;
+
+        assert_eq!(String::from_utf8_lossy(&output), expected);
+        assert_eq!(repl.interpreter().registers.get::<Byte>(Register::A), 1);
+
+        Ok(())
+    }
+}
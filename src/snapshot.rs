@@ -0,0 +1,344 @@
+//! Serialization of the full machine state, such that execution can be resumed later.
+//!
+//! All values are stored in little endian, in the following order:
+//! - the magic bytes and the format version
+//! - the checksum of the program the snapshot was taken of
+//! - the registers, the flags, the program counter and whether the program is running
+//! - the amount of executed instructions
+//! - the amount of bytes on the stack followed by the bytes themselves
+//! - the amount of active calls followed by the call site, entry and stack pointer of each
+
+use crate::{
+    call_stack::CallFrame,
+    constant::{Byte, Word},
+    error::SnapshotError,
+    flags::Flags,
+    register::Register,
+    Interpreter,
+};
+
+const MAGIC: &[u8; 4] = b"KSNP";
+const VERSION: Byte = 2;
+
+const SIGN: Byte = 0b001;
+const OVERFLOW: Byte = 0b010;
+const ZERO: Byte = 0b100;
+
+impl Interpreter {
+    /// Serializes the state of the interpreter, including the used portion of the stack.
+    pub fn snapshot(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(64 + self.stack.sp());
+
+        bytes.extend_from_slice(MAGIC);
+        bytes.push(VERSION);
+        bytes.extend_from_slice(&self.program.checksum().to_le_bytes());
+
+        for register in self.registers {
+            bytes.extend_from_slice(&register.to_le_bytes());
+        }
+
+        let mut flags = 0;
+        if self.flags.sign {
+            flags |= SIGN;
+        }
+        if self.flags.overflow {
+            flags |= OVERFLOW;
+        }
+        if self.flags.zero {
+            flags |= ZERO;
+        }
+        bytes.push(flags);
+
+        bytes.extend_from_slice(&self.program_counter.to_le_bytes());
+        bytes.push(Byte::from(self.running));
+        bytes.extend_from_slice(&self.config.instructions_executed.to_le_bytes());
+
+        let stack = self.stack.contents();
+        bytes.extend_from_slice(&(stack.len() as Word).to_le_bytes());
+        bytes.extend_from_slice(stack);
+
+        bytes.extend_from_slice(&(self.call_stack.len() as Word).to_le_bytes());
+        for frame in &self.call_stack {
+            bytes.extend_from_slice(&(frame.call_site as Word).to_le_bytes());
+            bytes.extend_from_slice(&frame.entry.to_le_bytes());
+            bytes.extend_from_slice(&(frame.stack_pointer as Word).to_le_bytes());
+        }
+
+        bytes
+    }
+
+    /// Restores the state of the interpreter from a snapshot.
+    ///
+    /// The program the snapshot was taken of must already be loaded.
+    ///
+    /// # Errors
+    /// Returns an error if the snapshot is malformed, was taken of another program, or does not fit in the stack.
+    /// The interpreter is left untouched in case of an error.
+    pub fn restore(&mut self, snapshot: &[u8]) -> Result<(), SnapshotError> {
+        let mut reader = Reader(snapshot);
+
+        if reader.take(MAGIC.len())? != MAGIC {
+            return Err(SnapshotError::InvalidMagic);
+        }
+
+        let version = reader.byte()?;
+        if version != VERSION {
+            return Err(SnapshotError::UnsupportedVersion(version));
+        }
+
+        if reader.word()? != self.program.checksum() {
+            return Err(SnapshotError::ProgramMismatch);
+        }
+
+        let mut registers = [0; Register::VARIANT_COUNT];
+        for register in registers.iter_mut() {
+            *register = reader.word()?;
+        }
+
+        let flags = reader.byte()?;
+        let program_counter = reader.word()?;
+        let running = reader.byte()? != 0;
+        let instructions_executed = reader.word()?;
+
+        let stack_length = reader.word()?;
+        let stack = reader.take(stack_length as usize)?;
+
+        let call_stack_length = reader.word()?;
+        let mut call_stack = Vec::new();
+        for _ in 0..call_stack_length {
+            let call_site = reader.word()? as usize;
+            let entry = reader.word()?;
+            let stack_pointer = reader.word()? as usize;
+
+            call_stack.push(CallFrame {
+                function: self.program.function(entry),
+                call_site,
+                entry,
+                stack_pointer,
+            });
+        }
+
+        if !reader.0.is_empty() {
+            return Err(SnapshotError::TrailingBytes);
+        }
+
+        self.stack.restore(stack)?;
+        self.registers = registers;
+        self.flags = Flags {
+            sign: flags & SIGN != 0,
+            overflow: flags & OVERFLOW != 0,
+            zero: flags & ZERO != 0,
+        };
+        self.program_counter = program_counter;
+        self.running = running;
+        self.config.instructions_executed = instructions_executed;
+        self.call_stack = call_stack;
+        self.history.clear();
+
+        Ok(())
+    }
+}
+
+/// Reads values from the front of a snapshot.
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, amount: usize) -> Result<&'a [u8], SnapshotError> {
+        let (taken, rest) = self
+            .0
+            .split_at_checked(amount)
+            .ok_or(SnapshotError::Truncated)?;
+        self.0 = rest;
+
+        Ok(taken)
+    }
+
+    fn byte(&mut self) -> Result<Byte, SnapshotError> {
+        let [byte] = self.take(1)? else {
+            unreachable!("exactly one byte is taken");
+        };
+
+        Ok(*byte)
+    }
+
+    fn word(&mut self) -> Result<Word, SnapshotError> {
+        let bytes = self.take(size_of::<Word>())?;
+        let bytes = bytes.try_into().expect("exactly eight bytes are taken");
+
+        Ok(Word::from_le_bytes(bytes))
+    }
+}
+
+#[cfg(test)]
+mod restore {
+    use crate::{
+        constant::{Byte, Word},
+        error::{InterpreterError, SnapshotError},
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter, Status,
+    };
+
+    const PROGRAM: &str = "pshw 7\nsetb ra 200\naddb ra 100\npshb ra\nstop";
+
+    #[test]
+    fn round_trip_resumes_execution() -> Result<(), InterpreterError> {
+        let mut original = Interpreter::new_test();
+        original.load(PROGRAM)?;
+        original.run_for(3);
+        let snapshot = original.snapshot();
+
+        let mut resumed = Interpreter::new_test();
+        resumed.load(PROGRAM)?;
+        resumed
+            .restore(&snapshot)
+            .expect("snapshot should be valid");
+
+        assert_eq!(resumed.registers, original.registers);
+        assert_eq!(resumed.flags, original.flags);
+        assert_eq!(resumed.program_counter, 4);
+        assert_eq!(resumed.stack.contents(), original.stack.contents());
+        assert_eq!(resumed.config.instructions_executed, 3);
+
+        assert_eq!(resumed.run_until(|_| false), Status::Stopped);
+        assert_eq!(resumed.stack.pop::<Byte>().unwrap(), 44);
+        assert_eq!(resumed.stack.pop::<Word>().unwrap(), 7);
+        assert_eq!(resumed.registers.get::<Byte>(Register::A), 44);
+
+        Ok(())
+    }
+
+    #[test]
+    fn call_stack_is_restored() -> Result<(), InterpreterError> {
+        let program = ["call inc_ra", "stop", "fn inc_ra:", "addb ra 1", "ret"].join("\n");
+        let mut original = Interpreter::new_test();
+        original.load(&program)?;
+        original.run_for(2);
+        let snapshot = original.snapshot();
+
+        let mut resumed = Interpreter::new_test();
+        resumed.load(&program)?;
+        resumed
+            .restore(&snapshot)
+            .expect("snapshot should be valid");
+
+        assert_eq!(resumed.call_stack, original.call_stack);
+        assert_eq!(resumed.run_until(|_| false), Status::Stopped);
+        assert_eq!(resumed.registers.get::<Byte>(Register::A), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn flags_are_restored() -> Result<(), InterpreterError> {
+        let mut original = Interpreter::new_test();
+        original.load(PROGRAM)?;
+        original.run_for(3);
+        let snapshot = original.snapshot();
+
+        let mut resumed = Interpreter::new_test();
+        resumed.load(PROGRAM)?;
+        resumed
+            .restore(&snapshot)
+            .expect("snapshot should be valid");
+
+        assert!(resumed.flags.overflow);
+        assert!(!resumed.flags.zero);
+        assert!(!resumed.flags.sign);
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_magic() -> Result<(), InterpreterError> {
+        let mut i = Interpreter::new_test();
+        i.load(PROGRAM)?;
+        let mut snapshot = i.snapshot();
+        snapshot.splice(..1, [b'X']);
+
+        let actual = i.restore(&snapshot);
+
+        assert_eq!(actual, Err(SnapshotError::InvalidMagic));
+
+        Ok(())
+    }
+
+    #[test]
+    fn unsupported_version() -> Result<(), InterpreterError> {
+        let mut i = Interpreter::new_test();
+        i.load(PROGRAM)?;
+        let mut snapshot = i.snapshot();
+        snapshot.splice(4..5, [99]);
+
+        let actual = i.restore(&snapshot);
+
+        assert_eq!(actual, Err(SnapshotError::UnsupportedVersion(99)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn truncated() -> Result<(), InterpreterError> {
+        let mut i = Interpreter::new_test();
+        i.load(PROGRAM)?;
+        i.run_for(1);
+        let mut snapshot = i.snapshot();
+        snapshot.pop();
+
+        let actual = i.restore(&snapshot);
+
+        assert_eq!(actual, Err(SnapshotError::Truncated));
+
+        Ok(())
+    }
+
+    #[test]
+    fn trailing_bytes() -> Result<(), InterpreterError> {
+        let mut i = Interpreter::new_test();
+        i.load(PROGRAM)?;
+        let mut snapshot = i.snapshot();
+        snapshot.push(0);
+
+        let actual = i.restore(&snapshot);
+
+        assert_eq!(actual, Err(SnapshotError::TrailingBytes));
+
+        Ok(())
+    }
+
+    #[test]
+    fn program_mismatch() -> Result<(), InterpreterError> {
+        let mut original = Interpreter::new_test();
+        original.load(PROGRAM)?;
+        let snapshot = original.snapshot();
+
+        let mut other = Interpreter::new_test();
+        other.load("stop")?;
+        let actual = other.restore(&snapshot);
+
+        assert_eq!(actual, Err(SnapshotError::ProgramMismatch));
+
+        Ok(())
+    }
+
+    #[test]
+    fn stack_too_small() -> Result<(), InterpreterError> {
+        let mut original = Interpreter::new_test();
+        original.load(PROGRAM)?;
+        original.run_for(1);
+        let snapshot = original.snapshot();
+
+        let mut small = Interpreter::builder()
+            .stack_size(4)
+            .output(Vec::new())
+            .build();
+        small.load(PROGRAM)?;
+        small.registers.set(Register::A, 1);
+        let actual = small.restore(&snapshot);
+
+        assert_eq!(actual, Err(SnapshotError::StackTooSmall(8, 4)));
+        assert_eq!(small.registers.get::<Word>(Register::A), 1);
+
+        Ok(())
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
     constant::{Byte, Word},
-    error::ExecuteError,
+    error::{ExecuteError, SnapshotError},
     utils::{FromBytes, ToBytes},
 };
 
@@ -23,6 +23,27 @@ impl Stack {
         self.pointer as usize
     }
 
+    /// Replaces the bytes on the stack, and moves the stack pointer to the end of them.
+    ///
+    /// # Errors
+    /// Returns an error if the bytes do not fit in the stack, in which case the stack is untouched.
+    pub fn restore(&mut self, bytes: &[Byte]) -> Result<(), SnapshotError> {
+        let size = self.bytes.len();
+        let destination = self
+            .bytes
+            .get_mut(..bytes.len())
+            .ok_or(SnapshotError::StackTooSmall(bytes.len(), size))?;
+        destination.copy_from_slice(bytes);
+        self.pointer = bytes.len() as Word;
+
+        Ok(())
+    }
+
+    /// Gets the bytes currently on the stack, from the bottom of the stack up to the stack pointer.
+    pub fn contents(&self) -> &[Byte] {
+        self.bytes.get(..self.sp()).unwrap_or_default()
+    }
+
     #[expect(
         clippy::indexing_slicing,
         reason = "the indexing in the for loop is guarded by the condition at the start of the function"
@@ -94,6 +115,77 @@ impl Stack {
 
         Ok(slice)
     }
+
+    /// Moves the stack pointer to the given byte position.
+    ///
+    /// Bytes above the new stack pointer are left as is, meaning they can be
+    /// observed again if the stack pointer is moved back up.
+    pub fn set_sp(&mut self, pointer: Word) -> Result<(), ExecuteError> {
+        if pointer as usize > self.bytes.len() {
+            return Err(ExecuteError::StackOverflow);
+        }
+
+        self.pointer = pointer;
+
+        Ok(())
+    }
+
+    /// Reads the value whose topmost byte lies `offset` bytes below the stack pointer.
+    ///
+    /// An offset of zero reads the value on top of the stack, without popping it.
+    #[expect(
+        clippy::indexing_slicing,
+        reason = "
+            the slicing is safe due to the checked subtractions when computing the bounds
+            they ensure that the requested bytes are below the stack pointer
+        "
+    )]
+    pub fn load<T>(&self, offset: Word) -> Result<T, ExecuteError>
+    where
+        T: FromBytes,
+    {
+        let (lower_bound, upper_bound) = self.bounds::<T>(offset)?;
+
+        let bytes = &self.bytes[lower_bound..upper_bound];
+        let value = T::from_bytes(bytes);
+
+        Ok(value)
+    }
+
+    /// Overwrites the value whose topmost byte lies `offset` bytes below the stack pointer.
+    ///
+    /// The stack pointer is not affected.
+    #[expect(
+        clippy::indexing_slicing,
+        reason = "
+            the slicing is safe due to the checked subtractions when computing the bounds
+            they ensure that the requested bytes are below the stack pointer
+        "
+    )]
+    pub fn store<T>(&mut self, offset: Word, value: T) -> Result<(), ExecuteError>
+    where
+        T: ToBytes,
+    {
+        let (lower_bound, upper_bound) = self.bounds::<T>(offset)?;
+
+        self.bytes[lower_bound..upper_bound].copy_from_slice(&value.to_bytes());
+
+        Ok(())
+    }
+
+    /// Computes the byte range of a value of type `T`, whose topmost byte lies
+    /// `offset` bytes below the stack pointer.
+    fn bounds<T>(&self, offset: Word) -> Result<(usize, usize), ExecuteError> {
+        let upper_bound = self
+            .sp()
+            .checked_sub(offset as usize)
+            .ok_or(ExecuteError::StackUnderflow)?;
+        let lower_bound = upper_bound
+            .checked_sub(size_of::<T>())
+            .ok_or(ExecuteError::StackUnderflow)?;
+
+        Ok((lower_bound, upper_bound))
+    }
 }
 
 #[expect(
@@ -559,3 +651,139 @@ mod slice {
         }
     }
 }
+
+#[cfg(test)]
+mod set_sp {
+    use crate::{
+        constant::{Byte, Word, TEST_STACK_SIZE},
+        error::ExecuteError,
+        stack::Stack,
+    };
+
+    #[test]
+    fn stack_overflow() {
+        let mut s = Stack::new(TEST_STACK_SIZE);
+        let expected = Err(ExecuteError::StackOverflow);
+
+        let actual = s.set_sp(TEST_STACK_SIZE as Word + 1);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn upper_bound() -> Result<(), ExecuteError> {
+        let mut s = Stack::new(TEST_STACK_SIZE);
+
+        s.set_sp(TEST_STACK_SIZE as Word)?;
+
+        assert_eq!(s.sp(), TEST_STACK_SIZE);
+
+        Ok(())
+    }
+
+    #[test]
+    fn preserves_bytes_above_pointer() -> Result<(), ExecuteError> {
+        let mut s = Stack::new(TEST_STACK_SIZE);
+        s.push(Byte::MAX)?;
+
+        s.set_sp(0)?;
+        s.set_sp(1)?;
+        let actual = s.pop::<Byte>()?;
+
+        assert_eq!(actual, Byte::MAX);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod load {
+    use crate::{
+        constant::{Byte, Quarter, Word, TEST_STACK_SIZE},
+        error::ExecuteError,
+        stack::Stack,
+    };
+
+    #[test]
+    fn stack_underflow_empty_stack() {
+        let s = Stack::new(TEST_STACK_SIZE);
+        let expected = Err(ExecuteError::StackUnderflow);
+
+        let actual = s.load::<Byte>(0);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn stack_underflow_offset_beyond_pointer() -> Result<(), ExecuteError> {
+        let mut s = Stack::new(TEST_STACK_SIZE);
+        s.push(Word::MAX)?;
+        let expected = Err(ExecuteError::StackUnderflow);
+
+        let actual = s.load::<Byte>(Word::MAX);
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn top_of_stack() -> Result<(), ExecuteError> {
+        let mut s = Stack::new(TEST_STACK_SIZE);
+        s.push::<Quarter>(1)?;
+        s.push::<Quarter>(2)?;
+
+        let actual = s.load::<Quarter>(0)?;
+
+        assert_eq!(actual, 2);
+        assert_eq!(s.sp(), 4);
+
+        Ok(())
+    }
+
+    #[test]
+    fn below_top_of_stack() -> Result<(), ExecuteError> {
+        let mut s = Stack::new(TEST_STACK_SIZE);
+        s.push::<Quarter>(1)?;
+        s.push::<Quarter>(2)?;
+
+        let actual = s.load::<Quarter>(2)?;
+
+        assert_eq!(actual, 1);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod store {
+    use crate::{
+        constant::{Byte, Half, TEST_STACK_SIZE},
+        error::ExecuteError,
+        stack::Stack,
+    };
+
+    #[test]
+    fn stack_underflow() {
+        let mut s = Stack::new(TEST_STACK_SIZE);
+        let expected = Err(ExecuteError::StackUnderflow);
+
+        let actual = s.store::<Byte>(0, Byte::MAX);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn below_top_of_stack() -> Result<(), ExecuteError> {
+        let mut s = Stack::new(TEST_STACK_SIZE);
+        s.push::<Half>(1)?;
+        s.push::<Half>(2)?;
+
+        s.store::<Half>(4, Half::MAX)?;
+
+        assert_eq!(s.pop::<Half>()?, 2);
+        assert_eq!(s.pop::<Half>()?, Half::MAX);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,645 @@
+//! Static analysis of the depth of the stack, such that overflows and underflows can be found without running a program.
+//!
+//! The depth before each instruction is computed over the control flow graph, relative to the start of the function it
+//! belongs to, where the return address has already been pushed. Instructions before the first function start with an
+//! empty stack. As returning requires the stack pointer to be the same as at the start of the function, a call does not
+//! change the depth of the caller once it returns.
+
+use crate::{
+    cfg::{ControlFlowGraph, EdgeKind},
+    constant::Word,
+    instruction::Instruction,
+    operand::Operand,
+    Interpreter,
+};
+use std::{collections::BTreeMap, fmt::Display};
+
+/// The range of the amount of bytes that may be on the stack, where a missing bound is unbounded.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Depth {
+    pub min: Option<i64>,
+    pub max: Option<i64>,
+}
+
+impl Depth {
+    const UNKNOWN: Depth = Depth {
+        min: None,
+        max: None,
+    };
+
+    fn exact(depth: i64) -> Self {
+        Self {
+            min: Some(depth),
+            max: Some(depth),
+        }
+    }
+
+    fn add(self, change: i64) -> Self {
+        Self {
+            min: self.min.map(|min| min.saturating_add(change)),
+            max: self.max.map(|max| max.saturating_add(change)),
+        }
+    }
+
+    /// Returns the range that contains both ranges.
+    fn join(self, other: Self) -> Self {
+        let min = match (self.min, other.min) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            _ => None,
+        };
+        let max = match (self.max, other.max) {
+            (Some(a), Some(b)) => Some(a.max(b)),
+            _ => None,
+        };
+
+        Self { min, max }
+    }
+
+    /// Removes the bounds that were extended by joining, which ensures loops that change the depth converge.
+    fn widen(self, joined: Self) -> Self {
+        Self {
+            min: self.min.filter(|_| joined.min == self.min),
+            max: self.max.filter(|_| joined.max == self.max),
+        }
+    }
+}
+
+impl Display for Depth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.min, self.max) {
+            (Some(min), Some(max)) if min == max => write!(f, "{min}"),
+            (Some(min), Some(max)) => write!(f, "{min} to {max}"),
+            (Some(min), None) => write!(f, "{min} or more"),
+            (None, Some(max)) => write!(f, "{max} or less"),
+            (None, None) => write!(f, "unknown"),
+        }
+    }
+}
+
+/// A path of the program that fails due to the stack, or leaves it in an unexpected state.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StackDiagnostic {
+    pub line: usize,
+    pub message: String,
+}
+
+impl Display for StackDiagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+/// The depth of the stack before each instruction, and the maximum depth of the program and each of its functions.
+///
+/// A maximum depth is unknown if the stack grows in a loop or by recursion, or if it is changed by setting the
+/// stack pointer or calling a host function.
+pub struct StackAnalysis {
+    graph: ControlFlowGraph,
+    depths: BTreeMap<usize, Depth>,
+    /// The maximum depth of each function including the functions it calls, by the index of the function in the graph.
+    maximums: Vec<Option<Option<Word>>>,
+    maximum: Option<Word>,
+    diagnostics: Vec<StackDiagnostic>,
+}
+
+impl Interpreter {
+    /// Analyses the depth of the stack of the loaded program.
+    pub fn stack_analysis(&self) -> StackAnalysis {
+        StackAnalysis::new(self.control_flow_graph())
+    }
+}
+
+impl StackAnalysis {
+    fn new(graph: ControlFlowGraph) -> Self {
+        let mut analysis = Self {
+            maximums: vec![None; graph.functions().len()],
+            graph,
+            depths: BTreeMap::new(),
+            maximum: Some(0),
+            diagnostics: Vec::new(),
+        };
+
+        let mut analysing = Vec::new();
+        for function in 0..analysis.graph.functions().len() {
+            analysis.function_maximum(function, &mut analysing);
+        }
+        analysis.maximum = analysis.analyse(None, &mut analysing);
+        analysis
+            .diagnostics
+            .sort_by_key(|diagnostic| diagnostic.line);
+
+        analysis
+    }
+
+    /// Returns the depth of the stack before the instruction on the line, relative to the start of its function.
+    ///
+    /// Returns none if the line is not an instruction that is reachable from the start of its function.
+    pub fn depth(&self, line: usize) -> Option<Depth> {
+        self.depths.get(&line).copied()
+    }
+
+    /// Returns the maximum depth of the stack from the start of the program, if it is known.
+    pub fn maximum(&self) -> Option<Word> {
+        self.maximum
+    }
+
+    /// Returns the names of the functions and their maximum depth, relative to their start.
+    pub fn functions(&self) -> impl Iterator<Item = (&str, Option<Word>)> {
+        self.graph
+            .functions()
+            .iter()
+            .zip(&self.maximums)
+            .map(|(function, maximum)| (function.name.as_str(), maximum.flatten()))
+    }
+
+    pub fn diagnostics(&self) -> &[StackDiagnostic] {
+        &self.diagnostics
+    }
+
+    /// Gets the instructions of the program with the depth of the stack before each of them.
+    pub fn listing(&self) -> StackListing<'_> {
+        StackListing(self)
+    }
+
+    /// Returns the maximum depth of the function, which is analysed if it has not been yet.
+    ///
+    /// The depth of recursive functions is unbounded, so it is unknown.
+    fn function_maximum(&mut self, function: usize, analysing: &mut Vec<usize>) -> Option<Word> {
+        if let Some(maximum) = self.maximums.get(function).copied().flatten() {
+            return maximum;
+        }
+        if analysing.contains(&function) {
+            return None;
+        }
+
+        analysing.push(function);
+        let maximum = self.analyse(Some(function), analysing);
+        analysing.pop();
+
+        if let Some(entry) = self.maximums.get_mut(function) {
+            *entry = Some(maximum);
+        }
+
+        maximum
+    }
+
+    /// Computes the depth before each instruction of the function, or of the instructions before the first
+    /// function if none is given, and returns the maximum depth.
+    fn analyse(&mut self, function: Option<usize>, analysing: &mut Vec<usize>) -> Option<Word> {
+        let blocks = self.graph.blocks();
+        let Some(entry) = blocks.iter().position(|block| block.function == function) else {
+            return Some(0);
+        };
+
+        let mut entries: Vec<Option<Depth>> = vec![None; blocks.len()];
+        if let Some(depth) = entries.get_mut(entry) {
+            *depth = Some(Depth::exact(0));
+        }
+
+        let mut pending = vec![entry];
+        while let Some(block) = pending.pop() {
+            let Some(depth) = entries.get(block).copied().flatten() else {
+                continue;
+            };
+
+            let exit = self.exit_depth(block, depth);
+            for successor in self.successors(block) {
+                let Some(current) = entries.get_mut(successor) else {
+                    continue;
+                };
+
+                let updated = match *current {
+                    None => exit,
+                    Some(previous) => {
+                        let joined = previous.join(exit);
+                        if joined == previous {
+                            continue;
+                        }
+
+                        // any loop contains an edge to an earlier block, so widening there ensures convergence
+                        if successor <= block {
+                            previous.widen(joined)
+                        } else {
+                            joined
+                        }
+                    }
+                };
+                *current = Some(updated);
+                pending.push(successor);
+            }
+        }
+
+        let mut maximum = Some(0);
+        for (block, depth) in entries.into_iter().enumerate() {
+            if let Some(depth) = depth {
+                let peak = self.check(block, depth, function, analysing);
+                maximum = maximum.zip(peak).map(|(maximum, peak)| maximum.max(peak));
+            }
+        }
+
+        maximum.map(|maximum| maximum.max(0) as Word)
+    }
+
+    /// Returns the depth after the last instruction of the block.
+    fn exit_depth(&self, block: usize, mut depth: Depth) -> Depth {
+        let Some(block) = self.graph.blocks().get(block) else {
+            return depth;
+        };
+
+        for (_, instruction) in &block.instructions {
+            depth = match instruction {
+                // the called function returns with the stack as it was before the call
+                Instruction::Call(_) => depth,
+                _ => match instruction.stack_effect() {
+                    Some(effect) => depth.add(effect.change),
+                    None => Depth::UNKNOWN,
+                },
+            };
+        }
+
+        depth
+    }
+
+    /// Records the depth before each instruction of the block and reports the instructions that fail
+    /// due to it, and returns the maximum depth reached by the block.
+    fn check(
+        &mut self,
+        block: usize,
+        mut depth: Depth,
+        function: Option<usize>,
+        analysing: &mut Vec<usize>,
+    ) -> Option<i64> {
+        let Some(instructions) = self
+            .graph
+            .blocks()
+            .get(block)
+            .map(|block| block.instructions.clone())
+        else {
+            return depth.max;
+        };
+        let called = self.called_function(block);
+
+        let mut peak = depth.max;
+        for (line, instruction) in instructions {
+            self.depths.insert(line, depth);
+
+            let mut reached = depth;
+            match &instruction {
+                Instruction::Call(destination) => {
+                    let callee = match (destination, called) {
+                        (Operand::Immediate(_), Some(called)) => {
+                            self.function_maximum(called, analysing)
+                        }
+                        _ => None,
+                    };
+                    reached.max = depth
+                        .max
+                        .zip(callee)
+                        .map(|(max, callee)| max + size_of::<Word>() as i64 + callee as i64);
+                }
+                Instruction::Return if function.is_some() => {
+                    if depth != Depth::exact(0) {
+                        self.diagnostics.push(StackDiagnostic {
+                            line,
+                            message: format!(
+                                "returns with a depth of {depth} bytes, but the return address is only on top at a depth of 0"
+                            ),
+                        });
+                    }
+                }
+                _ => match instruction.stack_effect() {
+                    Some(effect) => {
+                        let underflows = match function {
+                            // values below the return address belong to the caller, so only popping is an issue
+                            Some(_) => {
+                                effect.change < 0
+                                    && depth.min.is_some_and(|min| min + effect.change < 0)
+                            }
+                            None => {
+                                effect.required > 0
+                                    && depth.min.is_some_and(|min| min < effect.required as i64)
+                            }
+                        };
+                        if underflows {
+                            let message = match function {
+                                Some(_) => "can pop more than the function pushed, which removes its return address",
+                                None => "can access more bytes than were pushed, which underflows the stack",
+                            };
+                            self.diagnostics.push(StackDiagnostic {
+                                line,
+                                message: message.to_string(),
+                            });
+                        }
+
+                        depth = depth.add(effect.change);
+                        reached = depth;
+                    }
+                    None => {
+                        depth = Depth::UNKNOWN;
+                        reached = depth;
+                    }
+                },
+            }
+
+            peak = peak.zip(reached.max).map(|(peak, max)| peak.max(max));
+        }
+
+        peak
+    }
+
+    /// Returns the function called at the end of the block, if it is a call to the start of a function.
+    fn called_function(&self, block: usize) -> Option<usize> {
+        let edge = self
+            .graph
+            .edges()
+            .iter()
+            .find(|edge| edge.from == block && edge.kind == EdgeKind::Call)?;
+
+        self.graph.blocks().get(edge.to)?.function
+    }
+
+    /// Returns the blocks that follow the block within the same function, where a call is followed by
+    /// the block it returns to.
+    fn successors(&self, block: usize) -> Vec<usize> {
+        let blocks = self.graph.blocks();
+        let edges = self.graph.edges();
+
+        let mut successors: Vec<usize> = edges
+            .iter()
+            .filter(|edge| {
+                edge.from == block
+                    && matches!(
+                        edge.kind,
+                        EdgeKind::FallThrough | EdgeKind::Jump(_, _) | EdgeKind::JumpTable(_)
+                    )
+            })
+            .map(|edge| edge.to)
+            .collect();
+
+        // the block returned to is the first one after the call, among those the called function returns to
+        if let (Some(called), Some(end)) = (
+            self.called_function(block),
+            blocks.get(block).map(|block| block.end()),
+        ) {
+            let returned_to = edges
+                .iter()
+                .filter(|edge| {
+                    edge.kind == EdgeKind::Return
+                        && blocks
+                            .get(edge.from)
+                            .is_some_and(|from| from.function == Some(called))
+                })
+                .filter_map(|edge| Some((blocks.get(edge.to)?.start(), edge.to)))
+                .filter(|(start, _)| *start > end)
+                .min();
+            successors.extend(returned_to.map(|(_, to)| to));
+        }
+
+        successors
+    }
+}
+
+fn write_maximum(f: &mut std::fmt::Formatter<'_>, maximum: Option<Word>) -> std::fmt::Result {
+    match maximum {
+        Some(maximum) => writeln!(f, "{maximum} bytes"),
+        None => writeln!(f, "unknown"),
+    }
+}
+
+impl Display for StackAnalysis {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "maximum depth: ")?;
+        write_maximum(f, self.maximum)?;
+
+        for (name, maximum) in self.functions() {
+            write!(f, "function {name}: ")?;
+            write_maximum(f, maximum)?;
+        }
+
+        for diagnostic in &self.diagnostics {
+            writeln!(f, "{diagnostic}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The instructions of a program with the depth of the stack before each of them, relative to the start of their
+/// function. Instructions that cannot be reached have no depth.
+pub struct StackListing<'a>(&'a StackAnalysis);
+
+impl Display for StackListing<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let instructions = self
+            .0
+            .graph
+            .blocks()
+            .iter()
+            .flat_map(|block| &block.instructions);
+
+        for (line, instruction) in instructions {
+            let depth = match self.0.depth(*line) {
+                Some(depth) => depth.to_string(),
+                None => "-".to_string(),
+            };
+            writeln!(f, "{line:>5}  {depth:<12} {instruction}")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod analysis {
+    use super::{Depth, StackDiagnostic};
+    use crate::{error::InterpreterError, Interpreter};
+
+    fn analyse(source: &[&str]) -> Result<Interpreter, InterpreterError> {
+        let mut i = Interpreter::new_test();
+        i.load(&source.join("\n"))?;
+
+        Ok(i)
+    }
+
+    #[test]
+    fn depth_of_each_instruction() -> Result<(), InterpreterError> {
+        let i = analyse(&["pshb 1", "pshw 2", "popb ra", "stop"])?;
+
+        let analysis = i.stack_analysis();
+
+        assert_eq!(analysis.depth(1), Some(Depth::exact(0)));
+        assert_eq!(analysis.depth(2), Some(Depth::exact(1)));
+        assert_eq!(analysis.depth(3), Some(Depth::exact(9)));
+        assert_eq!(analysis.depth(4), Some(Depth::exact(8)));
+        assert_eq!(analysis.maximum(), Some(9));
+        assert!(analysis.diagnostics().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn branches_are_joined() -> Result<(), InterpreterError> {
+        let i = analyse(&["cmpb ra 0", "jiz +2", "pshq 1", "pshb 1", "stop"])?;
+
+        let analysis = i.stack_analysis();
+
+        let expected = Depth {
+            min: Some(0),
+            max: Some(2),
+        };
+        assert_eq!(analysis.depth(4), Some(expected));
+        assert_eq!(analysis.maximum(), Some(3));
+
+        Ok(())
+    }
+
+    #[test]
+    fn calls_include_the_depth_of_the_function() -> Result<(), InterpreterError> {
+        let source = [
+            "pshw 1",
+            "call push_twice",
+            "stop",
+            "fn push_twice:",
+            "pshh 1",
+            "pshh 2",
+            "poph ra",
+            "poph rb",
+            "ret",
+        ];
+        let i = analyse(&source)?;
+
+        let analysis = i.stack_analysis();
+        let functions: Vec<(&str, Option<u64>)> = analysis.functions().collect();
+
+        assert_eq!(functions, [("push_twice", Some(8))]);
+        assert_eq!(analysis.maximum(), Some(24));
+        assert_eq!(analysis.depth(3), Some(Depth::exact(8)));
+        assert!(analysis.diagnostics().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn underflow() -> Result<(), InterpreterError> {
+        let i = analyse(&["pshb 1", "popq ra", "stop"])?;
+        let expected = vec![StackDiagnostic {
+            line: 2,
+            message: "can access more bytes than were pushed, which underflows the stack"
+                .to_string(),
+        }];
+
+        let analysis = i.stack_analysis();
+
+        assert_eq!(analysis.diagnostics(), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn unbalanced_function() -> Result<(), InterpreterError> {
+        let source = [
+            "call leaves_value",
+            "call pops_address",
+            "stop",
+            "fn leaves_value:",
+            "pshb 1",
+            "ret",
+            "fn pops_address:",
+            "popb ra",
+            "pshb ra",
+            "ret",
+        ];
+        let i = analyse(&source)?;
+        let expected = vec![
+            StackDiagnostic {
+                line: 6,
+                message:
+                    "returns with a depth of 1 bytes, but the return address is only on top at a depth of 0"
+                        .to_string(),
+            },
+            StackDiagnostic {
+                line: 8,
+                message: "can pop more than the function pushed, which removes its return address"
+                    .to_string(),
+            },
+        ];
+
+        let analysis = i.stack_analysis();
+
+        assert_eq!(analysis.diagnostics(), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn growing_loop_is_unbounded() -> Result<(), InterpreterError> {
+        let i = analyse(&["pshb 1", "jmp 1"])?;
+
+        let analysis = i.stack_analysis();
+
+        let expected = Depth {
+            min: Some(0),
+            max: None,
+        };
+        assert_eq!(analysis.depth(1), Some(expected));
+        assert_eq!(analysis.maximum(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn recursion_is_unbounded() -> Result<(), InterpreterError> {
+        let i = analyse(&["call recurse", "stop", "fn recurse:", "call recurse", "ret"])?;
+
+        let analysis = i.stack_analysis();
+
+        assert_eq!(analysis.maximum(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn report() -> Result<(), InterpreterError> {
+        let source = [
+            "pshq 1",
+            "call unbalanced",
+            "stop",
+            "fn unbalanced:",
+            "pshw 1",
+            "ret",
+        ];
+        let i = analyse(&source)?;
+        let expected = [
+            "maximum depth: 18 bytes",
+            "function unbalanced: 8 bytes",
+            "line 6: returns with a depth of 8 bytes, but the return address is only on top at a depth of 0",
+            "",
+        ]
+        .join("\n");
+
+        let actual = i.stack_analysis().to_string();
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn example_program_grows_in_loop() -> Result<(), InterpreterError> {
+        let source = std::fs::read_to_string("program.kasm").expect("example program should exist");
+        let mut i = Interpreter::new_test();
+        i.load(&source)?;
+        let expected = Depth {
+            min: Some(3),
+            max: None,
+        };
+
+        let analysis = i.stack_analysis();
+
+        assert_eq!(analysis.depth(17), Some(expected));
+        assert_eq!(analysis.maximum(), None);
+        assert!(analysis.diagnostics().is_empty());
+
+        Ok(())
+    }
+}
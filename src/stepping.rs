@@ -0,0 +1,335 @@
+use crate::{
+    constant::Word, flags::Flags, preprocess::preprocess, program::Program, register::Register,
+    Interpreter, InterpreterError,
+};
+
+/// The state of the interpreter after a step-wise execution of the loaded program.
+#[derive(Debug, PartialEq)]
+pub enum Status {
+    /// The program has not stopped, and execution can be continued.
+    Running,
+
+    /// The program has been stopped by a stop instruction.
+    Stopped,
+
+    /// Execution was paused before executing the line of a breakpoint.
+    Breakpoint(usize),
+
+    /// An error occured, and the program counter remains at the line that caused it.
+    Error(InterpreterError),
+}
+
+impl Interpreter {
+    /// Preprocesses the source code and loads it as the program, such that it can be executed step-wise.
+    ///
+    /// Execution starts from the first line of the program, while the registers, flags and stack are left as is.
+    ///
+    /// # Errors
+    /// Returns an error if the source code could not be preprocessed.
+    pub fn load(&mut self, source_code: &str) -> Result<(), InterpreterError> {
+        let (final_source_code, functions) =
+            preprocess(source_code).map_err(InterpreterError::PreProcess)?;
+
+        self.start(Program::new(final_source_code, functions));
+
+        Ok(())
+    }
+
+    /// Replaces the loaded program, such that execution starts from its first line.
+    pub(crate) fn start(&mut self, program: Program) {
+        self.program = program;
+        self.history.clear();
+        self.start_at(1);
+    }
+
+    /// Continues execution from the line, as if the loaded program was started there.
+    pub(crate) fn start_at(&mut self, line: Word) {
+        self.program_counter = line;
+        self.running = true;
+        self.call_stack.clear();
+        self.config.deadline = None;
+    }
+
+    /// Clears the registers, flags and stack, and unloads the program along with its breakpoints.
+    pub fn reset(&mut self) {
+        self.registers = [0; Register::VARIANT_COUNT];
+        self.flags = Flags::new();
+        self.stack
+            .restore(&[])
+            .expect("an empty stack should always fit");
+        self.config.instructions_executed = 0;
+        self.breakpoints.clear();
+        self.start(Program::default());
+    }
+
+    /// Executes the next line of the loaded program.
+    ///
+    /// Breakpoints are not considered, as the line is executed regardless.
+    pub fn step(&mut self) -> Status {
+        if !self.running {
+            return Status::Stopped;
+        }
+
+        match self.advance() {
+            Ok(()) if self.running => Status::Running,
+            Ok(()) => Status::Stopped,
+            Err(err) => Status::Error(err),
+        }
+    }
+
+    /// Executes at most the given amount of instructions of the loaded program.
+    ///
+    /// Execution pauses early if the program stops, an error occurs or a breakpoint is reached.
+    pub fn run_for(&mut self, instructions: u64) -> Status {
+        if instructions == 0 {
+            return self.status();
+        }
+
+        let target = self
+            .config
+            .instructions_executed
+            .saturating_add(instructions);
+        self.run_until(|i| i.config.instructions_executed >= target)
+    }
+
+    /// Executes the loaded program until the predicate holds, which is checked after every line.
+    ///
+    /// Execution pauses early if the program stops, an error occurs or a breakpoint is reached.
+    /// The line at the current program counter is always executed, which allows resuming from a breakpoint.
+    pub fn run_until<P>(&mut self, mut predicate: P) -> Status
+    where
+        P: FnMut(&Interpreter) -> bool,
+    {
+        let mut first = true;
+
+        loop {
+            if !first && self.running && self.breakpoints.contains(&self.pc()) {
+                return Status::Breakpoint(self.pc());
+            }
+            first = false;
+
+            let status = self.step();
+            if status != Status::Running || predicate(self) {
+                return status;
+            }
+        }
+    }
+
+    /// Adds a breakpoint, such that execution pauses before the line is executed.
+    pub fn add_breakpoint(&mut self, line: usize) {
+        self.breakpoints.insert(line);
+    }
+
+    /// Removes a breakpoint, returning true if the breakpoint existed.
+    pub fn remove_breakpoint(&mut self, line: usize) -> bool {
+        self.breakpoints.remove(&line)
+    }
+
+    fn status(&self) -> Status {
+        if self.running {
+            Status::Running
+        } else {
+            Status::Stopped
+        }
+    }
+}
+
+#[cfg(test)]
+mod step {
+    use super::Status;
+    use crate::{
+        constant::Byte,
+        error::{ExecuteError, InterpreterError},
+        register::Register,
+        registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn executes_one_line_at_a_time() -> Result<(), InterpreterError> {
+        let mut i = Interpreter::new_test();
+        i.load(&["setb ra 1", "setb ra 2", "stop"].join("\n"))?;
+
+        assert_eq!(i.step(), Status::Running);
+        assert_eq!(i.registers.get::<Byte>(Register::A), 1);
+        assert_eq!(i.step(), Status::Running);
+        assert_eq!(i.registers.get::<Byte>(Register::A), 2);
+        assert_eq!(i.step(), Status::Stopped);
+        assert_eq!(i.step(), Status::Stopped);
+        assert_eq!(i.config.instructions_executed, 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn error_leaves_program_counter_at_line() -> Result<(), InterpreterError> {
+        let mut i = Interpreter::new_test();
+        i.load(&["setb ra 1", "popb rb", "stop"].join("\n"))?;
+        let expected = Status::Error(InterpreterError::Execute(2, ExecuteError::StackUnderflow));
+
+        i.step();
+        let actual = i.step();
+
+        assert_eq!(actual, expected);
+        assert_eq!(i.program_counter, 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn nothing_loaded() {
+        let mut i = Interpreter::new_test();
+        let expected = Status::Error(InterpreterError::InvalidProgramCounter(1));
+
+        let actual = i.step();
+
+        assert_eq!(actual, expected);
+    }
+}
+
+#[cfg(test)]
+mod run_for {
+    use super::Status;
+    use crate::{
+        constant::Byte, error::InterpreterError, register::Register, registers::RegisterOperations,
+        Interpreter,
+    };
+
+    #[test]
+    fn pauses_after_amount_of_instructions() -> Result<(), InterpreterError> {
+        let mut i = Interpreter::new_test();
+        i.load(&["addb ra 1", "jmp 1"].join("\n"))?;
+
+        let status = i.run_for(10);
+
+        assert_eq!(status, Status::Running);
+        assert_eq!(i.config.instructions_executed, 10);
+        assert_eq!(i.registers.get::<Byte>(Register::A), 5);
+
+        Ok(())
+    }
+
+    #[test]
+    fn stops_early() -> Result<(), InterpreterError> {
+        let mut i = Interpreter::new_test();
+        i.load(&["setb ra 1", "stop"].join("\n"))?;
+
+        let status = i.run_for(10);
+
+        assert_eq!(status, Status::Stopped);
+        assert_eq!(i.config.instructions_executed, 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn zero_instructions() -> Result<(), InterpreterError> {
+        let mut i = Interpreter::new_test();
+        i.load("stop")?;
+
+        let status = i.run_for(0);
+
+        assert_eq!(status, Status::Running);
+        assert_eq!(i.config.instructions_executed, 0);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod run_until {
+    use super::Status;
+    use crate::{
+        constant::Byte, error::InterpreterError, register::Register, registers::RegisterOperations,
+        Interpreter,
+    };
+    use std::time::Duration;
+
+    #[test]
+    fn pauses_when_predicate_holds() -> Result<(), InterpreterError> {
+        let mut i = Interpreter::new_test();
+        i.load(&["addb ra 1", "jmp 1"].join("\n"))?;
+
+        let status = i.run_until(|i| i.register(Register::A) == 3);
+
+        assert_eq!(status, Status::Running);
+        assert_eq!(i.registers.get::<Byte>(Register::A), 3);
+        assert_eq!(i.program_counter, 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn pauses_at_breakpoint_and_resumes() -> Result<(), InterpreterError> {
+        let mut i = Interpreter::new_test();
+        i.load(&["setb ra 1", "setb rb 2", "setb rc 3", "stop"].join("\n"))?;
+        i.add_breakpoint(2);
+
+        let first = i.run_until(|_| false);
+        let second = i.run_until(|_| false);
+
+        assert_eq!(first, Status::Breakpoint(2));
+        assert_eq!(second, Status::Stopped);
+        assert_eq!(i.registers.get::<Byte>(Register::C), 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn removed_breakpoint_is_ignored() -> Result<(), InterpreterError> {
+        let mut i = Interpreter::new_test();
+        i.load(&["setb ra 1", "setb rb 2", "stop"].join("\n"))?;
+        i.add_breakpoint(2);
+
+        assert!(i.remove_breakpoint(2));
+        assert!(!i.remove_breakpoint(2));
+        assert_eq!(i.run_until(|_| false), Status::Stopped);
+
+        Ok(())
+    }
+
+    #[test]
+    fn reload_starts_from_first_line() -> Result<(), InterpreterError> {
+        let mut i = Interpreter::new_test();
+        i.load(&["addb ra 1", "stop"].join("\n"))?;
+        i.run_until(|_| false);
+
+        i.load(&["addb ra 1", "stop"].join("\n"))?;
+        let status = i.run_until(|_| false);
+
+        assert_eq!(status, Status::Stopped);
+        assert_eq!(i.registers.get::<Byte>(Register::A), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn instruction_limit_is_applied() -> Result<(), InterpreterError> {
+        let mut i = Interpreter::new_test();
+        i.config.max_instructions = Some(10);
+        i.load("jmp 1")?;
+        let expected = Status::Error(InterpreterError::InstructionLimitReached(1, 10));
+
+        let actual = i.run_until(|_| false);
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn timeout_is_applied() -> Result<(), InterpreterError> {
+        let mut i = Interpreter::new_test();
+        i.config.timeout = Some(Duration::ZERO);
+        i.load("jmp 1")?;
+
+        let actual = i.run_until(|_| false);
+
+        assert!(matches!(
+            actual,
+            Status::Error(InterpreterError::Timeout(1, _))
+        ));
+
+        Ok(())
+    }
+}
@@ -1,13 +1,47 @@
 use crate::constant::{Byte, Half, Quarter, Word};
 
 pub trait BitOps {
+    /// The amount of bits in the binary representation.
+    const BITS: u32;
+
     fn bit_and(self, rhs: Self) -> Self;
     fn bit_or(self, rhs: Self) -> Self;
     fn bit_xor(self, rhs: Self) -> Self;
     fn bit_not(self) -> Self;
+
+    /// Returns the amount of ones in the binary representation.
+    fn bit_count_ones(self) -> Self;
+
+    /// Returns the amount of leading zeros in the binary representation.
+    fn bit_leading_zeros(self) -> Self;
+
+    /// Returns the amount of trailing zeros in the binary representation.
+    fn bit_trailing_zeros(self) -> Self;
+
+    /// Reverses the byte order.
+    fn bit_swap_bytes(self) -> Self;
+
+    /// Returns a value with only the bit at the index set, or `None` if the index is out of bounds.
+    fn bit_mask(index: u32) -> Option<Self>
+    where
+        Self: Sized;
+
+    /// Returns a value with the `length` bits starting at `start` set, or `None` if the
+    /// bit field is out of bounds.
+    fn bit_field_mask(start: u32, length: u32) -> Option<Self>
+    where
+        Self: Sized;
+
+    /// Shifts the value to the right, where shifting every bit out results in zero.
+    fn bit_shift_right(self, amount: u32) -> Self;
+
+    /// Shifts the value to the left, where shifting every bit out results in zero.
+    fn bit_shift_left(self, amount: u32) -> Self;
 }
 
 impl BitOps for Byte {
+    const BITS: u32 = Byte::BITS;
+
     fn bit_and(self, rhs: Self) -> Self {
         self & rhs
     }
@@ -23,9 +57,48 @@ impl BitOps for Byte {
     fn bit_not(self) -> Self {
         !self
     }
+
+    fn bit_count_ones(self) -> Self {
+        self.count_ones() as Byte
+    }
+
+    fn bit_leading_zeros(self) -> Self {
+        self.leading_zeros() as Byte
+    }
+
+    fn bit_trailing_zeros(self) -> Self {
+        self.trailing_zeros() as Byte
+    }
+
+    fn bit_swap_bytes(self) -> Self {
+        self.swap_bytes()
+    }
+
+    fn bit_mask(index: u32) -> Option<Self> {
+        (index < Byte::BITS).then(|| 1 << index)
+    }
+
+    fn bit_field_mask(start: u32, length: u32) -> Option<Self> {
+        if start.checked_add(length)? > Byte::BITS {
+            return None;
+        }
+
+        let ones = Byte::MAX.checked_shr(Byte::BITS - length).unwrap_or(0);
+        Some(ones.bit_shift_left(start))
+    }
+
+    fn bit_shift_right(self, amount: u32) -> Self {
+        self.checked_shr(amount).unwrap_or(0)
+    }
+
+    fn bit_shift_left(self, amount: u32) -> Self {
+        self.checked_shl(amount).unwrap_or(0)
+    }
 }
 
 impl BitOps for Quarter {
+    const BITS: u32 = Quarter::BITS;
+
     fn bit_and(self, rhs: Self) -> Self {
         self & rhs
     }
@@ -41,9 +114,50 @@ impl BitOps for Quarter {
     fn bit_not(self) -> Self {
         !self
     }
+
+    fn bit_count_ones(self) -> Self {
+        self.count_ones() as Quarter
+    }
+
+    fn bit_leading_zeros(self) -> Self {
+        self.leading_zeros() as Quarter
+    }
+
+    fn bit_trailing_zeros(self) -> Self {
+        self.trailing_zeros() as Quarter
+    }
+
+    fn bit_swap_bytes(self) -> Self {
+        self.swap_bytes()
+    }
+
+    fn bit_mask(index: u32) -> Option<Self> {
+        (index < Quarter::BITS).then(|| 1 << index)
+    }
+
+    fn bit_field_mask(start: u32, length: u32) -> Option<Self> {
+        if start.checked_add(length)? > Quarter::BITS {
+            return None;
+        }
+
+        let ones = Quarter::MAX
+            .checked_shr(Quarter::BITS - length)
+            .unwrap_or(0);
+        Some(ones.bit_shift_left(start))
+    }
+
+    fn bit_shift_right(self, amount: u32) -> Self {
+        self.checked_shr(amount).unwrap_or(0)
+    }
+
+    fn bit_shift_left(self, amount: u32) -> Self {
+        self.checked_shl(amount).unwrap_or(0)
+    }
 }
 
 impl BitOps for Half {
+    const BITS: u32 = Half::BITS;
+
     fn bit_and(self, rhs: Self) -> Self {
         self & rhs
     }
@@ -59,9 +173,48 @@ impl BitOps for Half {
     fn bit_not(self) -> Self {
         !self
     }
+
+    fn bit_count_ones(self) -> Self {
+        self.count_ones() as Half
+    }
+
+    fn bit_leading_zeros(self) -> Self {
+        self.leading_zeros() as Half
+    }
+
+    fn bit_trailing_zeros(self) -> Self {
+        self.trailing_zeros() as Half
+    }
+
+    fn bit_swap_bytes(self) -> Self {
+        self.swap_bytes()
+    }
+
+    fn bit_mask(index: u32) -> Option<Self> {
+        (index < Half::BITS).then(|| 1 << index)
+    }
+
+    fn bit_field_mask(start: u32, length: u32) -> Option<Self> {
+        if start.checked_add(length)? > Half::BITS {
+            return None;
+        }
+
+        let ones = Half::MAX.checked_shr(Half::BITS - length).unwrap_or(0);
+        Some(ones.bit_shift_left(start))
+    }
+
+    fn bit_shift_right(self, amount: u32) -> Self {
+        self.checked_shr(amount).unwrap_or(0)
+    }
+
+    fn bit_shift_left(self, amount: u32) -> Self {
+        self.checked_shl(amount).unwrap_or(0)
+    }
 }
 
 impl BitOps for Word {
+    const BITS: u32 = Word::BITS;
+
     fn bit_and(self, rhs: Self) -> Self {
         self & rhs
     }
@@ -77,4 +230,88 @@ impl BitOps for Word {
     fn bit_not(self) -> Self {
         !self
     }
+
+    fn bit_count_ones(self) -> Self {
+        self.count_ones() as Word
+    }
+
+    fn bit_leading_zeros(self) -> Self {
+        self.leading_zeros() as Word
+    }
+
+    fn bit_trailing_zeros(self) -> Self {
+        self.trailing_zeros() as Word
+    }
+
+    fn bit_swap_bytes(self) -> Self {
+        self.swap_bytes()
+    }
+
+    fn bit_mask(index: u32) -> Option<Self> {
+        (index < Word::BITS).then(|| 1 << index)
+    }
+
+    fn bit_field_mask(start: u32, length: u32) -> Option<Self> {
+        if start.checked_add(length)? > Word::BITS {
+            return None;
+        }
+
+        let ones = Word::MAX.checked_shr(Word::BITS - length).unwrap_or(0);
+        Some(ones.bit_shift_left(start))
+    }
+
+    fn bit_shift_right(self, amount: u32) -> Self {
+        self.checked_shr(amount).unwrap_or(0)
+    }
+
+    fn bit_shift_left(self, amount: u32) -> Self {
+        self.checked_shl(amount).unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod bit_field_mask {
+    use super::BitOps;
+    use crate::constant::{Byte, Word};
+
+    #[test]
+    fn empty_field() {
+        assert_eq!(Byte::bit_field_mask(3, 0), Some(0));
+    }
+
+    #[test]
+    fn full_field() {
+        assert_eq!(Word::bit_field_mask(0, Word::BITS), Some(Word::MAX));
+    }
+
+    #[test]
+    fn field_in_middle() {
+        assert_eq!(Byte::bit_field_mask(2, 3), Some(0b0001_1100));
+    }
+
+    #[test]
+    fn field_out_of_bounds() {
+        assert_eq!(Byte::bit_field_mask(6, 3), None);
+    }
+
+    #[test]
+    fn start_out_of_bounds() {
+        assert_eq!(Byte::bit_field_mask(u32::MAX, 1), None);
+    }
+}
+
+#[cfg(test)]
+mod bit_mask {
+    use super::BitOps;
+    use crate::constant::{Byte, Half};
+
+    #[test]
+    fn highest_bit() {
+        assert_eq!(Half::bit_mask(Half::BITS - 1), Some(1 << 31));
+    }
+
+    #[test]
+    fn index_out_of_bounds() {
+        assert_eq!(Byte::bit_mask(Byte::BITS), None);
+    }
 }
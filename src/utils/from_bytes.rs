@@ -5,6 +5,7 @@
 
 use crate::constant::{Byte, Half, Quarter, Word};
 
+/// A value that can be read from its little endian bytes, such as from the stack.
 pub trait FromBytes {
     fn from_bytes(slice: &[Byte]) -> Self;
 }
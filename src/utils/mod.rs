@@ -1,17 +1,21 @@
 pub use arithmetic::Arithmetic;
 pub use bitwise::BitWise;
+pub use checksum::fnv1a;
 pub use from_bytes::FromBytes;
 pub use setable::Setable;
+pub use sign_extend::SignExtend;
 pub use to_bytes::ToBytes;
 pub use to_word::ToWord;
-pub use writer::Writer;
+pub use writer::{OutputWriter, Writer};
 
 mod arithmetic;
 mod bit_ops;
 mod bitwise;
+mod checksum;
 mod from_bytes;
 mod overflow;
 mod setable;
+mod sign_extend;
 mod to_bytes;
 mod to_word;
 mod writer;
@@ -20,6 +20,12 @@ pub trait Overflow {
     fn overflow_rem(self, rhs: Self) -> (Self, bool)
     where
         Self: Sized;
+
+    /// Multiplies without discarding any bits of the product, which is returned as the
+    /// low and high part respectively.
+    fn overflow_widening_mul(self, rhs: Self) -> (Self, Self)
+    where
+        Self: Sized;
 }
 
 impl Overflow for Byte {
@@ -42,6 +48,11 @@ impl Overflow for Byte {
     fn overflow_rem(self, rhs: Self) -> (Self, bool) {
         self.overflowing_rem(rhs)
     }
+
+    fn overflow_widening_mul(self, rhs: Self) -> (Self, Self) {
+        let product = Quarter::from(self) * Quarter::from(rhs);
+        (product as Byte, (product >> Byte::BITS) as Byte)
+    }
 }
 
 impl Overflow for Quarter {
@@ -64,6 +75,11 @@ impl Overflow for Quarter {
     fn overflow_rem(self, rhs: Self) -> (Self, bool) {
         self.overflowing_rem(rhs)
     }
+
+    fn overflow_widening_mul(self, rhs: Self) -> (Self, Self) {
+        let product = Half::from(self) * Half::from(rhs);
+        (product as Quarter, (product >> Quarter::BITS) as Quarter)
+    }
 }
 
 impl Overflow for Half {
@@ -86,6 +102,11 @@ impl Overflow for Half {
     fn overflow_rem(self, rhs: Self) -> (Self, bool) {
         self.overflowing_rem(rhs)
     }
+
+    fn overflow_widening_mul(self, rhs: Self) -> (Self, Self) {
+        let product = Word::from(self) * Word::from(rhs);
+        (product as Half, (product >> Half::BITS) as Half)
+    }
 }
 
 impl Overflow for Word {
@@ -108,4 +129,52 @@ impl Overflow for Word {
     fn overflow_rem(self, rhs: Self) -> (Self, bool) {
         self.overflowing_rem(rhs)
     }
+
+    fn overflow_widening_mul(self, rhs: Self) -> (Self, Self) {
+        let product = u128::from(self) * u128::from(rhs);
+        (product as Word, (product >> Word::BITS) as Word)
+    }
+}
+
+#[cfg(test)]
+mod overflow_widening_mul {
+    use super::Overflow;
+    use crate::constant::{Byte, Half, Quarter, Word};
+
+    #[test]
+    fn product_fits_in_low_part() {
+        assert_eq!(Byte::overflow_widening_mul(5, 10), (50, 0));
+    }
+
+    #[test]
+    fn maximum_byte() {
+        assert_eq!(
+            Byte::MAX.overflow_widening_mul(Byte::MAX),
+            (1, Byte::MAX - 1)
+        );
+    }
+
+    #[test]
+    fn maximum_quarter() {
+        assert_eq!(
+            Quarter::MAX.overflow_widening_mul(Quarter::MAX),
+            (1, Quarter::MAX - 1)
+        );
+    }
+
+    #[test]
+    fn maximum_half() {
+        assert_eq!(
+            Half::MAX.overflow_widening_mul(Half::MAX),
+            (1, Half::MAX - 1)
+        );
+    }
+
+    #[test]
+    fn maximum_word() {
+        assert_eq!(
+            Word::MAX.overflow_widening_mul(Word::MAX),
+            (1, Word::MAX - 1)
+        );
+    }
 }
@@ -0,0 +1,58 @@
+use crate::constant::{Byte, Half, Quarter, SignedWord, Word};
+
+pub trait SignExtend {
+    /// Interprets the value as two's complement and extends it to a signed word.
+    fn sign_extend(self) -> SignedWord;
+}
+
+impl SignExtend for Byte {
+    fn sign_extend(self) -> SignedWord {
+        SignedWord::from(self.cast_signed())
+    }
+}
+
+impl SignExtend for Quarter {
+    fn sign_extend(self) -> SignedWord {
+        SignedWord::from(self.cast_signed())
+    }
+}
+
+impl SignExtend for Half {
+    fn sign_extend(self) -> SignedWord {
+        SignedWord::from(self.cast_signed())
+    }
+}
+
+impl SignExtend for Word {
+    fn sign_extend(self) -> SignedWord {
+        self.cast_signed()
+    }
+}
+
+#[cfg(test)]
+mod regression {
+    use super::SignExtend;
+    use crate::constant::{Byte, Half, Quarter, Word};
+
+    #[test]
+    fn positive_is_unchanged() {
+        assert_eq!(Byte::sign_extend(127), 127);
+        assert_eq!(Quarter::sign_extend(127), 127);
+        assert_eq!(Half::sign_extend(127), 127);
+        assert_eq!(Word::sign_extend(127), 127);
+    }
+
+    #[test]
+    fn maximum_is_negative_one() {
+        assert_eq!(Byte::MAX.sign_extend(), -1);
+        assert_eq!(Quarter::MAX.sign_extend(), -1);
+        assert_eq!(Half::MAX.sign_extend(), -1);
+        assert_eq!(Word::MAX.sign_extend(), -1);
+    }
+
+    #[test]
+    fn sign_bit_only_is_minimum() {
+        assert_eq!(Byte::sign_extend(0x80), i64::from(i8::MIN));
+        assert_eq!(Quarter::sign_extend(0x8000), i64::from(i16::MIN));
+    }
+}
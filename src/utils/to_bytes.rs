@@ -1,5 +1,6 @@
 use crate::constant::{Byte, Half, Quarter, Word};
 
+/// A value that can be written as its little endian bytes, such as onto the stack.
 pub trait ToBytes {
     fn to_bytes(self) -> Box<[u8]>;
 }
@@ -1,7 +1,6 @@
 use crate::constant::{
     Byte, Half, Quarter, SignedByte, SignedHalf, SignedQuarter, SignedWord, Word,
 };
-use std::mem;
 
 pub trait ToWord {
     fn to_word(self) -> Word;
@@ -32,57 +31,26 @@ impl ToWord for Word {
 }
 
 impl ToWord for SignedByte {
-    #[expect(
-        unsafe_code,
-        reason = "
-                this is the only way to reinterpted the underlying bits into a new type, 
-                while also maintaining the same numerical value if reinterpretted back to the original type
-            "
-    )]
     fn to_word(self) -> Word {
-        let byte: Byte = unsafe { mem::transmute(self) };
-        Word::from(byte)
+        Word::from(self.cast_unsigned())
     }
 }
 
 impl ToWord for SignedQuarter {
-    #[expect(
-        unsafe_code,
-        reason = "
-                this is the only way to reinterpted the underlying bits into a new type, 
-                while also maintaining the same numerical value if reinterpretted back to the original type
-            "
-    )]
     fn to_word(self) -> Word {
-        let quarter: Quarter = unsafe { mem::transmute(self) };
-        Word::from(quarter)
+        Word::from(self.cast_unsigned())
     }
 }
 
 impl ToWord for SignedHalf {
-    #[expect(
-        unsafe_code,
-        reason = "
-                this is the only way to reinterpted the underlying bits into a new type, 
-                while also maintaining the same numerical value if reinterpretted back to the original type
-            "
-    )]
     fn to_word(self) -> Word {
-        let half: Half = unsafe { mem::transmute(self) };
-        Word::from(half)
+        Word::from(self.cast_unsigned())
     }
 }
 
 impl ToWord for SignedWord {
-    #[expect(
-        unsafe_code,
-        reason = "
-                this is the only way to reinterpted the underlying bits into a new type, 
-                while also maintaining the same numerical value if reinterpretted back to the original type
-            "
-    )]
     fn to_word(self) -> Word {
-        unsafe { mem::transmute(self) }
+        self.cast_unsigned()
     }
 }
 
@@ -1,8 +1,28 @@
 use std::{
     fs::File,
-    io::{Stdout, Write},
+    io::{Stderr, Stdout, Write},
 };
 
+/// Wraps any output sink, such that it can be used as the output of the interpreter.
+pub struct OutputWriter<W>(pub W);
+
+impl<W: Write> Write for OutputWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.0.flush()
+    }
+}
+
+impl<W: Write> Writer for OutputWriter<W> {
+    #[cfg(test)]
+    fn get_buffer(&self) -> Option<String> {
+        None
+    }
+}
+
 pub trait Writer: Write {
     #[cfg(test)]
     fn get_buffer(&self) -> Option<String>;
@@ -15,6 +35,13 @@ impl Writer for Stdout {
     }
 }
 
+impl Writer for Stderr {
+    #[cfg(test)]
+    fn get_buffer(&self) -> Option<String> {
+        None
+    }
+}
+
 impl Writer for Vec<u8> {
     #[cfg(test)]
     fn get_buffer(&self) -> Option<String> {